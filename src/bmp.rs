@@ -1,49 +1,275 @@
-use std::io::Cursor;
-use std::ops::{BitAnd, Div};
-use bmp::{Image, Pixel};
+use std::ops::Div;
+use crate::bmp::halftone::dot_power;
+use crate::bmp::image::{decode, Bitmap};
+use crate::gcode::{write_blocks, Block};
 
-pub(crate) fn bmp_to_gcode(bmp_contents: &[u8], dpi: u16) -> String {
-    let mut cursor = Cursor::new(bmp_contents);
+mod image;
+mod pnm;
+mod halftone;
 
-    match bmp::from_reader(&mut cursor) {
-        Ok(image) => process_image(image, dpi),
-        _ => "".into()
+pub use crate::bmp::image::{Colour, ImageError};
+pub use crate::bmp::halftone::{DotShape, Halftone};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScanDirection {
+    Horizontal,
+    Vertical,
+    Angle(f64)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GreyscaleMode {
+    Average,
+    Rec601,
+    Rec709,
+    Red,
+    Green,
+    Blue
+}
+
+#[derive(Debug, Clone)]
+pub enum RasterMode {
+    Greyscale,
+    Halftone(Halftone)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Anchor {
+    BottomLeft,
+    BottomRight,
+    TopLeft,
+    TopRight,
+    Centre
+}
+
+#[derive(Debug, Clone)]
+pub struct ColourPass {
+    pub colour: Colour,
+    pub tolerance: u8,
+    pub power: u8
+}
+
+#[derive(Debug, Clone)]
+pub struct RasterOptions {
+    pub dpi: u16,
+    pub direction: ScanDirection,
+    pub bidirectional: bool,
+    pub mode: RasterMode,
+    pub greyscale: GreyscaleMode,
+    pub colour_passes: Vec<ColourPass>,
+    pub anchor: Anchor,
+    pub offset_x: f32,
+    pub offset_y: f32
+}
+
+struct Sample {
+    x: f32,
+    y: f32,
+    power: u8
+}
+
+impl ColourPass {
+    pub(crate) fn matches(&self, pixel: &Colour) -> bool {
+        let within = |a: u8, b: u8| a.abs_diff(b) <= self.tolerance;
+
+        within(pixel.r, self.colour.r) && within(pixel.g, self.colour.g) && within(pixel.b, self.colour.b)
     }
 }
 
-fn process_image(bitmap: Image, dpi: u16) -> String {
-    let width = bitmap.get_width();
-    let height = bitmap.get_height();
+impl RasterOptions {
+    pub fn new(dpi: u16) -> RasterOptions {
+        RasterOptions {
+            dpi,
+            direction: ScanDirection::Horizontal,
+            bidirectional: true,
+            mode: RasterMode::Greyscale,
+            greyscale: GreyscaleMode::Average,
+            colour_passes: vec![],
+            anchor: Anchor::BottomLeft,
+            offset_x: 0.0,
+            offset_y: 0.0
+        }
+    }
+}
+
+pub(crate) fn bmp_to_gcode(image_contents: &[u8], options: &RasterOptions) -> Result<String, ImageError> {
+    bmp_to_blocks(image_contents, options).map(|blocks| write_blocks(&blocks))
+}
+
+pub(crate) fn bmp_to_blocks(image_contents: &[u8], options: &RasterOptions) -> Result<Vec<Block>, ImageError> {
+    decode(image_contents).map(|bitmap| process_image(bitmap, options))
+}
 
-    let mm_per_pixel: f32 = 25.4 / dpi as f32;
+fn process_image(bitmap: Bitmap, options: &RasterOptions) -> Vec<Block> {
+    let pixel = |x: u32, y: u32| bitmap.get_pixel(x, y);
 
-    let mut last_power: u8 = 0;
+    if !options.colour_passes.is_empty() {
+        return options.colour_passes.iter()
+            .flat_map(|pass| raster_pass(&bitmap, options, &|x, y| if pass.matches(&pixel(x, y)) { pass.power } else { 0 }))
+            .collect();
+    }
 
-    let mut gcode = String::new();
+    match &options.mode {
+        RasterMode::Greyscale => raster_pass(&bitmap, options, &|x, y| 255 - to_greyscale(&pixel(x, y), options.greyscale)),
 
-    for (x, y) in bitmap.coordinates() {
-        let x = if y.bitand(1) == 1 { width - 1 - x} else { x };
-        let power = 255 - to_greyscale(&bitmap.get_pixel(x, y));
+        RasterMode::Halftone(halftone) => {
+            let mm_per_pixel = 25.4 / options.dpi as f64;
 
-        if power == last_power && ((x != 0 && x != width - 1) || last_power == 0) {
-            continue;
+            raster_pass(&bitmap, options, &|x, y| dot_power(&bitmap, mm_per_pixel, halftone, options.greyscale, x, y))
         }
+    }
+}
+
+fn raster_pass(bitmap: &Bitmap, options: &RasterOptions, power: &dyn Fn(u32, u32) -> u8) -> Vec<Block> {
+    let mut blocks = vec![];
+
+    for line in scan_lines(bitmap, options, power) {
+        // Every line is reached with the laser off, otherwise the step over from the last line would burn
+        let mut last_power: u8 = 0;
+
+        let last_index = line.len().saturating_sub(1);
+
+        for (i, sample) in line.iter().enumerate() {
+            let line_end = i == 0 || i == last_index;
 
-        let mm_x = x as f32 * mm_per_pixel;
-        let mm_y = (height - 1 - y) as f32 * mm_per_pixel;
+            if sample.power == last_power && (!line_end || last_power == 0) {
+                continue;
+            }
 
-        if last_power == 0 {
-            gcode += format!("M05 F2000 X{:.2} Y{:.2}\n", mm_x, mm_y).as_str();
-        } else {
-            gcode += format!("M03 F1000 X{:.2} Y{:.2} S{}\n", mm_x, mm_y, last_power).as_str();
+            blocks.push(match last_power {
+                0 => Block::travel(sample.x as f64, sample.y as f64),
+                power => Block::burn(sample.x as f64, sample.y as f64, power)
+            });
+
+            last_power = sample.power;
         }
+    }
+
+    blocks
+}
+
+fn scan_lines(bitmap: &Bitmap, options: &RasterOptions, power: &dyn Fn(u32, u32) -> u8) -> Vec<Vec<Sample>> {
+    let width = bitmap.get_width();
+    let height = bitmap.get_height();
+
+    let mm_per_pixel: f32 = 25.4 / options.dpi as f32;
 
-        last_power = power;
+    let sample = |x: u32, y: u32| Sample {
+        x: x as f32 * mm_per_pixel,
+        y: (height - 1 - y) as f32 * mm_per_pixel,
+        power: power(x, y)
+    };
+
+    let mut lines: Vec<Vec<Sample>> = match options.direction {
+        ScanDirection::Horizontal => (0..height)
+            .map(|y| (0..width).map(|x| sample(x, y)).collect())
+            .collect(),
+
+        ScanDirection::Vertical => (0..width)
+            .map(|x| (0..height).rev().map(|y| sample(x, y)).collect())
+            .collect(),
+
+        ScanDirection::Angle(angle) => angled_scan_lines(bitmap, mm_per_pixel, angle, power)
+    };
+
+    if options.bidirectional {
+        lines.iter_mut()
+            .skip(1)
+            .step_by(2)
+            .for_each(|line| line.reverse());
     }
 
-    gcode
+    let (dx, dy) = placement(width, height, mm_per_pixel, options);
+
+    lines.iter_mut()
+        .flatten()
+        .for_each(|sample| {
+            sample.x += dx;
+            sample.y += dy;
+        });
+
+    lines
+}
+
+fn placement(width: u32, height: u32, mm_per_pixel: f32, options: &RasterOptions) -> (f32, f32) {
+    let span_x = (width - 1) as f32 * mm_per_pixel;
+    let span_y = (height - 1) as f32 * mm_per_pixel;
+
+    let (anchor_x, anchor_y) = match options.anchor {
+        Anchor::BottomLeft => (0.0, 0.0),
+        Anchor::BottomRight => (span_x, 0.0),
+        Anchor::TopLeft => (0.0, span_y),
+        Anchor::TopRight => (span_x, span_y),
+        Anchor::Centre => (span_x / 2.0, span_y / 2.0)
+    };
+
+    (options.offset_x - anchor_x, options.offset_y - anchor_y)
+}
+
+fn angled_scan_lines(bitmap: &Bitmap, mm_per_pixel: f32, angle: f64, power: &dyn Fn(u32, u32) -> u8) -> Vec<Vec<Sample>> {
+    let width = bitmap.get_width() as f64;
+    let height = bitmap.get_height() as f64;
+    let spacing = mm_per_pixel as f64;
+
+    let (sin, cos) = angle.to_radians().sin_cos();
+
+    // Lines run along (cos, sin) and are stepped along (sin, -cos), so an angle of 0 matches a horizontal scan
+    let corners = [
+        (0.0, 0.0),
+        ((width - 1.0) * spacing, 0.0),
+        (0.0, (height - 1.0) * spacing),
+        ((width - 1.0) * spacing, (height - 1.0) * spacing)
+    ];
+
+    let along = |(x, y): (f64, f64)| x * cos + y * sin;
+    let across = |(x, y): (f64, f64)| x * sin - y * cos;
+
+    let s_min = corners.iter().copied().map(along).fold(f64::MAX, f64::min);
+    let s_max = corners.iter().copied().map(along).fold(f64::MIN, f64::max);
+    let t_min = corners.iter().copied().map(across).fold(f64::MAX, f64::min);
+    let t_max = corners.iter().copied().map(across).fold(f64::MIN, f64::max);
+
+    let sample_count = ((s_max - s_min) / spacing).round() as i64;
+    let line_count = ((t_max - t_min) / spacing).round() as i64;
+
+    (0..=line_count)
+        .map(|l| {
+            let t = t_min + l as f64 * spacing;
+
+            (0..=sample_count)
+                .filter_map(|i| {
+                    let s = s_min + i as f64 * spacing;
+                    let x = s * cos + t * sin;
+                    let y = s * sin - t * cos;
+
+                    let px = (x / spacing).round();
+                    let py = height - 1.0 - (y / spacing).round();
+
+                    if px < 0.0 || py < 0.0 || px >= width || py >= height {
+                        return None;
+                    }
+
+                    Some(Sample {
+                        x: x as f32,
+                        y: y as f32,
+                        power: power(px as u32, py as u32)
+                    })
+                })
+                .collect::<Vec<Sample>>()
+        })
+        .filter(|line| !line.is_empty())
+        .collect()
 }
 
-fn to_greyscale(pixel: &Pixel) -> u8 {
-    (pixel.r as u16 + pixel.g as u16 + pixel.b as u16).div(3) as u8
+fn to_greyscale(pixel: &Colour, mode: GreyscaleMode) -> u8 {
+    let weighted = |r: f32, g: f32, b: f32| (pixel.r as f32 * r + pixel.g as f32 * g + pixel.b as f32 * b).round() as u8;
+
+    match mode {
+        GreyscaleMode::Average => (pixel.r as u16 + pixel.g as u16 + pixel.b as u16).div(3) as u8,
+        GreyscaleMode::Rec601 => weighted(0.299, 0.587, 0.114),
+        GreyscaleMode::Rec709 => weighted(0.2126, 0.7152, 0.0722),
+        GreyscaleMode::Red => pixel.r,
+        GreyscaleMode::Green => pixel.g,
+        GreyscaleMode::Blue => pixel.b
+    }
 }
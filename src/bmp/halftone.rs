@@ -0,0 +1,73 @@
+use std::f64::consts::PI;
+use crate::bmp::{to_greyscale, GreyscaleMode};
+use crate::bmp::image::Bitmap;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DotShape {
+    Round,
+    Line,
+    Ellipse
+}
+
+#[derive(Debug, Clone)]
+pub struct Halftone {
+    pub cell_size: f64,
+    pub angle: f64,
+    pub shape: DotShape,
+    pub power: u8
+}
+
+const ELLIPSE_RATIO: f64 = 0.6;
+
+impl Halftone {
+    pub fn new(cell_size: f64, angle: f64, shape: DotShape) -> Halftone {
+        Halftone {cell_size, angle, shape, power: 255}
+    }
+}
+
+pub fn dot_power(bitmap: &Bitmap, mm_per_pixel: f64, halftone: &Halftone, greyscale: GreyscaleMode, x: u32, y: u32) -> u8 {
+    let height = bitmap.get_height();
+    let (sin, cos) = halftone.angle.to_radians().sin_cos();
+
+    let mm_x = x as f64 * mm_per_pixel;
+    let mm_y = (height - 1 - y) as f64 * mm_per_pixel;
+
+    // Position within the rotated screen, measured in cells
+    let u = (mm_x * cos + mm_y * sin) / halftone.cell_size;
+    let v = (mm_y * cos - mm_x * sin) / halftone.cell_size;
+
+    let (cell_u, cell_v) = (u.floor() + 0.5, v.floor() + 0.5);
+
+    let centre_x = (cell_u * cos - cell_v * sin) * halftone.cell_size / mm_per_pixel;
+    let centre_y = (cell_u * sin + cell_v * cos) * halftone.cell_size / mm_per_pixel;
+
+    let pixel_x = centre_x.round().clamp(0.0, (bitmap.get_width() - 1) as f64) as u32;
+    let pixel_y = height - 1 - centre_y.round().clamp(0.0, (height - 1) as f64) as u32;
+
+    let darkness = (255 - to_greyscale(&bitmap.get_pixel(pixel_x, pixel_y), greyscale)) as f64 / 255.0;
+
+    if covered(u - cell_u, v - cell_v, darkness, halftone.shape) {
+        halftone.power
+    } else {
+        0
+    }
+}
+
+fn covered(u: f64, v: f64, darkness: f64, shape: DotShape) -> bool {
+    match shape {
+        DotShape::Line => v.abs() < darkness / 2.0,
+        DotShape::Round => euclidean_dot(u, v, darkness, 1.0),
+        DotShape::Ellipse => euclidean_dot(u, v, darkness, ELLIPSE_RATIO)
+    }
+}
+
+fn euclidean_dot(u: f64, v: f64, darkness: f64, ratio: f64) -> bool {
+    let distance = |u: f64, v: f64| u.powi(2) + (v / ratio).powi(2);
+
+    // Past half coverage the dots merge, so grow white dots from the cell corners instead
+    if darkness <= 0.5 {
+        distance(u, v) < darkness / (PI * ratio)
+    } else {
+        distance(0.5 - u.abs(), 0.5 - v.abs()) >= (1.0 - darkness) / (PI * ratio)
+    }
+}
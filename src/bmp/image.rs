@@ -0,0 +1,118 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::Cursor;
+use png::{Decoder, Transformations, ColorType};
+use crate::bmp::pnm;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Colour {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8
+}
+
+pub struct Bitmap {
+    width: u32,
+    height: u32,
+    pixels: Vec<Colour>
+}
+
+#[derive(Debug)]
+pub enum ImageError {
+    UnsupportedFormat,
+    Decode(String)
+}
+
+impl Colour {
+    pub fn grey(value: u8) -> Colour {
+        Colour {r: value, g: value, b: value}
+    }
+
+    pub fn over_white(r: u8, g: u8, b: u8, alpha: u8) -> Colour {
+        let blend = |c: u8| ((c as u16 * alpha as u16 + 255 * (255 - alpha as u16)) / 255) as u8;
+
+        Colour {r: blend(r), g: blend(g), b: blend(b)}
+    }
+}
+
+impl Bitmap {
+    pub fn new(width: u32, height: u32, pixels: Vec<Colour>) -> Bitmap {
+        assert_eq!(pixels.len(), width as usize * height as usize);
+
+        Bitmap {width, height, pixels}
+    }
+
+    pub fn get_width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn get_height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn get_pixel(&self, x: u32, y: u32) -> Colour {
+        self.pixels[(y * self.width + x) as usize]
+    }
+}
+
+impl Display for ImageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImageError::UnsupportedFormat => write!(f, "Unsupported image format"),
+            ImageError::Decode(e) => write!(f, "Unable to decode image: {}", e)
+        }
+    }
+}
+
+impl Error for ImageError {}
+
+pub fn decode(contents: &[u8]) -> Result<Bitmap, ImageError> {
+    match contents {
+        [b'B', b'M', ..] => decode_bmp(contents),
+        [0x89, b'P', b'N', b'G', ..] => decode_png(contents),
+        [b'P', b'1'..=b'6', ..] => pnm::decode(contents),
+        _ => Err(ImageError::UnsupportedFormat)
+    }
+}
+
+fn decode_bmp(contents: &[u8]) -> Result<Bitmap, ImageError> {
+    let image = bmp::from_reader(&mut Cursor::new(contents))
+        .map_err(|e| ImageError::Decode(e.to_string()))?;
+
+    let pixels = image.coordinates()
+        .map(|(x, y)| image.get_pixel(x, y))
+        .map(|p| Colour {r: p.r, g: p.g, b: p.b})
+        .collect();
+
+    Ok(Bitmap::new(image.get_width(), image.get_height(), pixels))
+}
+
+fn decode_png(contents: &[u8]) -> Result<Bitmap, ImageError> {
+    let mut decoder = Decoder::new(Cursor::new(contents));
+    decoder.set_transformations(Transformations::normalize_to_color8());
+
+    let mut reader = decoder.read_info()
+        .map_err(|e| ImageError::Decode(e.to_string()))?;
+
+    let mut buffer = vec![0; reader.output_buffer_size().ok_or(ImageError::Decode("image too large".into()))?];
+
+    let info = reader.next_frame(&mut buffer)
+        .map_err(|e| ImageError::Decode(e.to_string()))?;
+
+    let pixels = buffer[..info.line_size * info.height as usize]
+        .chunks(info.line_size)
+        .flat_map(|line| {
+            let samples = info.color_type.samples();
+            line[..info.width as usize * samples].chunks(samples)
+        })
+        .map(|p| match info.color_type {
+            ColorType::Grayscale => Colour::grey(p[0]),
+            ColorType::GrayscaleAlpha => Colour::over_white(p[0], p[0], p[0], p[1]),
+            ColorType::Rgb => Colour {r: p[0], g: p[1], b: p[2]},
+            ColorType::Rgba => Colour::over_white(p[0], p[1], p[2], p[3]),
+            ColorType::Indexed => unreachable!("palettes are expanded by the decoder")
+        })
+        .collect();
+
+    Ok(Bitmap::new(info.width, info.height, pixels))
+}
@@ -0,0 +1,177 @@
+use crate::bmp::image::{Bitmap, Colour, ImageError};
+
+// The header alone sets the image size, so it is bounded before anything is read into memory
+const MAX_PIXELS: u64 = 1 << 26;
+
+struct Header {
+    format: u8,
+    width: u32,
+    height: u32,
+    max_value: u32
+}
+
+pub fn decode(contents: &[u8]) -> Result<Bitmap, ImageError> {
+    let mut position = 2;
+    let format = contents[1];
+
+    let mut next_value = || read_header_value(contents, &mut position);
+
+    let width = next_value()?;
+    let height = next_value()?;
+    let max_value = match format {
+        b'1' | b'4' => 1,
+        _ => next_value()?
+    };
+
+    if width == 0 || height == 0 || max_value == 0 || max_value > 65535 {
+        return Err(ImageError::Decode("invalid netpbm header".into()));
+    }
+
+    if width as u64 * height as u64 > MAX_PIXELS {
+        return Err(too_large());
+    }
+
+    let header = Header {format, width, height, max_value};
+
+    // Binary rasters start after exactly one whitespace character following the header
+    let raster = &contents[(position + 1).min(contents.len())..];
+
+    let pixels = match format {
+        b'1' => decode_ascii_bits(&contents[position..], &header)?,
+        b'2' | b'3' => decode_ascii_samples(&contents[position..], &header)?,
+        b'4' => decode_binary_bits(raster, &header)?,
+        _ => decode_binary_samples(raster, &header)?
+    };
+
+    Ok(Bitmap::new(width, height, pixels))
+}
+
+fn read_header_value(contents: &[u8], position: &mut usize) -> Result<u32, ImageError> {
+    skip_whitespace(contents, position);
+
+    let start = *position;
+
+    while *position < contents.len() && contents[*position].is_ascii_digit() {
+        *position += 1;
+    }
+
+    std::str::from_utf8(&contents[start..*position])
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .ok_or(ImageError::Decode("invalid netpbm header".into()))
+}
+
+fn skip_whitespace(contents: &[u8], position: &mut usize) {
+    while *position < contents.len() {
+        match contents[*position] {
+            b'#' => {
+                while *position < contents.len() && contents[*position] != b'\n' {
+                    *position += 1;
+                }
+            },
+
+            c if c.is_ascii_whitespace() => *position += 1,
+
+            _ => break
+        }
+    }
+}
+
+fn pixel_count(header: &Header) -> usize {
+    header.width as usize * header.height as usize
+}
+
+fn sample_count(header: &Header, bytes_per_sample: usize) -> Result<usize, ImageError> {
+    pixel_count(header)
+        .checked_mul(samples_per_pixel(header))
+        .and_then(|count| count.checked_mul(bytes_per_sample))
+        .ok_or_else(too_large)
+}
+
+fn too_large() -> ImageError {
+    ImageError::Decode("image too large".into())
+}
+
+fn samples_per_pixel(header: &Header) -> usize {
+    match header.format {
+        b'3' | b'6' => 3,
+        _ => 1
+    }
+}
+
+fn to_colour(samples: &[u32], header: &Header) -> Colour {
+    let scale = |s: u32| (s.min(header.max_value) * 255 / header.max_value) as u8;
+
+    match samples {
+        [r, g, b] => Colour {r: scale(*r), g: scale(*g), b: scale(*b)},
+        _ => Colour::grey(scale(samples[0]))
+    }
+}
+
+fn bit_to_colour(bit: bool) -> Colour {
+    // A set bit is black in PBM
+    Colour::grey(if bit { 0 } else { 255 })
+}
+
+fn decode_ascii_bits(contents: &[u8], header: &Header) -> Result<Vec<Colour>, ImageError> {
+    let pixels: Vec<Colour> = contents.iter()
+        .filter(|c| **c == b'0' || **c == b'1')
+        .take(pixel_count(header))
+        .map(|c| bit_to_colour(*c == b'1'))
+        .collect();
+
+    if pixels.len() < pixel_count(header) {
+        return Err(ImageError::Decode("truncated PBM raster".into()));
+    }
+
+    Ok(pixels)
+}
+
+fn decode_ascii_samples(contents: &[u8], header: &Header) -> Result<Vec<Colour>, ImageError> {
+    let samples = samples_per_pixel(header);
+    let count = sample_count(header, 1)?;
+
+    let values = std::str::from_utf8(contents)
+        .map_err(|_| ImageError::Decode("invalid netpbm raster".into()))?
+        .split_ascii_whitespace()
+        .take(count)
+        .map(|v| v.parse::<u32>())
+        .collect::<Result<Vec<u32>, _>>()
+        .map_err(|_| ImageError::Decode("invalid netpbm raster".into()))?;
+
+    if values.len() < count {
+        return Err(ImageError::Decode("truncated netpbm raster".into()));
+    }
+
+    Ok(values.chunks(samples).map(|p| to_colour(p, header)).collect())
+}
+
+fn decode_binary_bits(contents: &[u8], header: &Header) -> Result<Vec<Colour>, ImageError> {
+    let row_length = (header.width as usize).div_ceil(8);
+
+    if contents.len() < row_length.checked_mul(header.height as usize).ok_or_else(too_large)? {
+        return Err(ImageError::Decode("truncated PBM raster".into()));
+    }
+
+    Ok(contents.chunks(row_length)
+        .take(header.height as usize)
+        .flat_map(|row| (0..header.width as usize).map(move |x| row[x / 8] & (0x80 >> (x % 8)) != 0))
+        .map(bit_to_colour)
+        .collect())
+}
+
+fn decode_binary_samples(contents: &[u8], header: &Header) -> Result<Vec<Colour>, ImageError> {
+    let bytes_per_sample = if header.max_value > 255 { 2 } else { 1 };
+    let samples = samples_per_pixel(header);
+
+    if contents.len() < sample_count(header, bytes_per_sample)? {
+        return Err(ImageError::Decode("truncated netpbm raster".into()));
+    }
+
+    let values: Vec<u32> = contents.chunks(bytes_per_sample)
+        .take(sample_count(header, 1)?)
+        .map(|s| s.iter().fold(0, |value, byte| (value << 8) | *byte as u32))
+        .collect();
+
+    Ok(values.chunks(samples).map(|p| to_colour(p, header)).collect())
+}
@@ -1,40 +1,240 @@
-use crate::dxf::parser::parse;
+use crate::dxf::fill::hatch;
+use crate::dxf::hatch::Hatch;
+use std::io::{self, BufRead};
+use crate::dxf::parser::{parse, parse_reader, Drawing};
+use crate::dxf::point::point_blocks;
+use crate::dxf::vertex::Vertex;
+use crate::dxf::writer::{write_dxf, Layer};
 use crate::dxf::polyline::{glue_polylines, PolyLine};
+use crate::gcode::{write_blocks, Block};
 
-mod vertex;
-mod polyline;
+pub(crate) mod vertex;
+pub(crate) mod polyline;
 mod spline;
 mod parser;
 mod circle;
 mod arc;
 mod bulge;
+pub(crate) mod fill;
+mod hatch;
+pub(crate) mod ellipse;
+mod nurbs;
+mod text;
+mod font;
+pub(crate) mod point;
+mod units;
+mod binary;
+mod tokenizer;
+mod writer;
 
-fn generate_gcode(lines: &Vec<PolyLine>) -> String {
-    lines.iter()
-        .map(polyline_to_gcode)
-        .collect()
+pub use crate::dxf::fill::{FillOptions, FillRule};
+pub use crate::dxf::point::{Drill, PointAction, Pulse};
+pub use crate::dxf::units::{OutputUnits, Unit};
+
+// Solid hatches are filled with the given options, in output units, while pattern hatches keep their own lines
+#[derive(Debug, Clone)]
+pub enum HatchMode {
+    Ignore,
+    Boundary,
+    Fill(FillOptions)
 }
 
-fn polyline_to_gcode(line: &PolyLine) -> String {
-    let mut gcode = String::new();
-    let mut gcode_end = String::new();
+#[derive(Debug, Clone)]
+pub struct DxfOptions {
+    pub fill: Option<FillOptions>,
+    pub outline: bool,
+    pub hatches: HatchMode,
+    pub points: PointAction,
+    pub units: Option<Unit>,
+    pub output_units: OutputUnits,
+    pub unit_command: bool
+}
+
+impl Default for DxfOptions {
+    fn default() -> DxfOptions {
+        DxfOptions {
+            fill: None,
+            outline: true,
+            hatches: HatchMode::Ignore,
+            points: PointAction::default(),
+            units: None,
+            output_units: OutputUnits::Millimetres,
+            unit_command: false
+        }
+    }
+}
+
+struct Operation {
+    layer: &'static str,
+    colour: u8,
+    lines: Vec<PolyLine>,
+    points: Vec<Vertex>
+}
+
+impl Operation {
+    fn lines(layer: &'static str, colour: u8, lines: Vec<PolyLine>) -> Operation {
+        Operation {layer, colour, lines, points: vec![]}
+    }
+}
+
+pub(crate) fn generate_gcode_with_power(lines: &[PolyLine], power: u8) -> String {
+    write_blocks(&lines.iter().flat_map(|line| polyline_blocks(line, power)).collect::<Vec<Block>>())
+}
+
+fn polyline_blocks(line: &PolyLine, power: u8) -> Vec<Block> {
+    let mut blocks = vec![];
     let mut iterator = line.vertices.iter();
 
-    if let Some(first_line) = iterator.next() {
-        gcode += format!("M05 F2000 X{:.2} Y{:.2}\n", first_line.x(), first_line.y()).as_str();
+    if let Some(first) = iterator.next() {
+        blocks.push(Block::travel(first.x(), first.y()));
+        blocks.extend(iterator.map(|vertex| Block::burn(vertex.x(), vertex.y(), power)));
 
         if line.closed {
-            gcode_end += format!("M03 F1000 X{:.2} Y{:.2} S255\n", first_line.x(), first_line.y()).as_str();
+            blocks.push(Block::burn(first.x(), first.y(), power));
         }
     }
 
-    for line in iterator {
-        gcode += format!("M03 F1000 X{:.2} Y{:.2} S255\n", line.x(), line.y()).as_str();
+    blocks
+}
+
+pub(crate) fn dxf_to_gcode(dxf_contents: &str, options: &DxfOptions) -> String {
+    drawing_to_gcode(parse(dxf_contents, options.units), options)
+}
+
+pub(crate) fn dxf_to_dxf(dxf_contents: &str, options: &DxfOptions) -> String {
+    drawing_to_dxf(parse(dxf_contents, options.units), options)
+}
+
+pub(crate) fn dxf_reader_to_dxf(reader: impl BufRead, options: &DxfOptions) -> io::Result<String> {
+    parse_reader(reader, options.units).map(|drawing| drawing_to_dxf(drawing, options))
+}
+
+pub(crate) fn dxf_reader_to_gcode(reader: impl BufRead, options: &DxfOptions) -> io::Result<String> {
+    parse_reader(reader, options.units).map(|drawing| drawing_to_gcode(drawing, options))
+}
+
+pub(crate) fn dxf_to_blocks(dxf_contents: &str, options: &DxfOptions) -> Vec<Block> {
+    drawing_to_blocks(parse(dxf_contents, options.units), options)
+}
+
+fn drawing_to_gcode(drawing: Drawing, options: &DxfOptions) -> String {
+    let mut blocks = vec![];
+
+    if options.unit_command {
+        blocks.push(options.output_units.command());
     }
 
-    gcode + gcode_end.as_str()
+    blocks.extend(drawing_to_blocks(drawing, options));
+
+    write_blocks(&blocks)
 }
 
-pub(crate) fn dxf_to_gcode(dxf_contents: &str) -> String {
-    generate_gcode(&glue_polylines(parse(&dxf_contents)))
+fn drawing_to_blocks(drawing: Drawing, options: &DxfOptions) -> Vec<Block> {
+    let mut blocks = vec![];
+
+    for operation in plan_operations(drawing, options) {
+        blocks.extend(operation.lines.iter().flat_map(|line| polyline_blocks(line, 255)));
+        blocks.extend(point_blocks(&operation.points, &options.points));
+    }
+
+    options.output_units.convert(&mut blocks);
+
+    blocks
+}
+
+fn drawing_to_dxf(drawing: Drawing, options: &DxfOptions) -> String {
+    let operations = plan_operations(drawing, options);
+    let travel = travel_moves(&operations);
+
+    let mut layers: Vec<Layer> = operations.iter()
+        .filter(|operation| !operation.lines.is_empty() || !operation.points.is_empty())
+        .map(|operation| Layer {
+            name: operation.layer,
+            colour: operation.colour,
+            lines: &operation.lines,
+            points: &operation.points
+        })
+        .collect();
+
+    layers.push(Layer {name: "TRAVEL", colour: 8, lines: &travel, points: &[]});
+
+    write_dxf(&layers, options.output_units)
+}
+
+// The paths each operation will cut, in the order they are sent to the machine
+fn plan_operations(drawing: Drawing, options: &DxfOptions) -> Vec<Operation> {
+    let drawing = scale_to_output(drawing, options);
+    let mut lines = glue_polylines(drawing.lines);
+    let mut operations = vec![];
+
+    match &options.hatches {
+        HatchMode::Ignore => {},
+
+        HatchMode::Boundary => {
+            lines.extend(drawing.hatches.into_iter().flat_map(Hatch::into_boundaries));
+        },
+
+        HatchMode::Fill(solid) => {
+            operations.push(Operation::lines("HATCH", 3, drawing.hatches.iter().flat_map(|h| h.fill(solid)).collect()));
+        }
+    }
+
+    if let Some(fill) = &options.fill {
+        let contours: Vec<PolyLine> = lines.iter()
+            .filter(|line| line.is_closed())
+            .cloned()
+            .collect();
+
+        operations.push(Operation::lines("FILL", 4, hatch(&contours, fill)));
+    }
+
+    if options.points != PointAction::Ignore {
+        operations.push(Operation {layer: "POINTS", colour: 1, lines: vec![], points: drawing.points});
+    }
+
+    if options.outline {
+        operations.push(Operation::lines("OUTLINE", 7, lines));
+    }
+
+    operations
+}
+
+// Moves with the laser off between paths, starting and ending at the origin like the generated program
+fn travel_moves(operations: &[Operation]) -> Vec<PolyLine> {
+    let origin = Vertex {x: 0.0, y: 0.0};
+    let mut position = origin;
+    let mut targets = vec![];
+
+    for operation in operations {
+        for line in operation.lines.iter() {
+            if let (Some(first), Some(last)) = (line.vertices.first(), line.vertices.last()) {
+                targets.push((*first, if line.closed { *first } else { *last }));
+            }
+        }
+
+        targets.extend(operation.points.iter().map(|point| (*point, *point)));
+    }
+
+    targets.push((origin, origin));
+
+    targets.into_iter()
+        .filter_map(|(start, end)| {
+            let travel = (position.distance_to(&start) > 0.001).then(|| PolyLine {vertices: vec![position, start], closed: false});
+            position = end;
+
+            travel
+        })
+        .collect()
+}
+
+fn scale_to_output(drawing: Drawing, options: &DxfOptions) -> Drawing {
+    // Drawings without units have always been treated as millimetres
+    let units = drawing.units.unwrap_or(Unit::Millimetres);
+    let factor = units.millimetres() / options.output_units.millimetres();
+
+    if factor == 1.0 {
+        drawing
+    } else {
+        drawing.scale(factor)
+    }
 }
@@ -11,12 +11,13 @@ pub struct Arc {
 }
 
 impl Arc {
-    pub fn into_polyline(self) -> PolyLine {
+    // Scale is the size of a drawing unit in millimetres, which sets how finely the arc is divided
+    pub fn into_polyline(self, scale: f64) -> PolyLine {
         let mut points = vec![];
         let angle_size = self.end_angle - self.start_angle;
 
-        let circumference = (2.0 * PI * self.radius) * (angle_size.abs() / (2.0 * PI));
-        let resolution = ((circumference * 1.5) as i64).min(25).max(6);
+        let circumference = (2.0 * PI * self.radius * scale) * (angle_size.abs() / (2.0 * PI));
+        let resolution = ((circumference * 1.5) as i64).clamp(6, 25);
         let step = 1.0 / (resolution as f64);
 
         for s in 0..(resolution + 1) {
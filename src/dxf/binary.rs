@@ -0,0 +1,140 @@
+use std::borrow::Cow;
+use std::io::{self, BufRead, Chain, Cursor, ErrorKind, Read};
+use crate::dxf::tokenizer::{group_code, DataPair, Tokenizer};
+
+const SENTINEL: &[u8] = b"AutoCAD Binary DXF\r\n\x1a\0";
+
+pub type Peeked<R> = Chain<Cursor<Vec<u8>>, R>;
+
+enum ValueType {
+    String,
+    Double,
+    Int16,
+    Int32,
+    Int64,
+    Bool,
+    Chunk
+}
+
+pub struct BinaryTokenizer<R: BufRead> {
+    reader: R,
+    wide_codes: bool,
+    error: Option<io::Error>
+}
+
+// A single fill_buf can come back short of the sentinel, so its bytes (and the two after, which
+// BinaryTokenizer::new looks at) are read in full and handed back in front of the rest of the file
+pub fn detect<R: BufRead>(mut reader: R) -> io::Result<(bool, Peeked<R>)> {
+    let mut start = vec![];
+    reader.by_ref().take(SENTINEL.len() as u64 + 2).read_to_end(&mut start)?;
+
+    Ok((start.starts_with(SENTINEL), Cursor::new(start).chain(reader)))
+}
+
+impl<R: BufRead> BinaryTokenizer<R> {
+    pub fn new(mut reader: R) -> io::Result<BinaryTokenizer<R>> {
+        reader.read_exact(&mut [0; SENTINEL.len()])?;
+
+        // R12 writes single byte group codes, so the first SECTION string starts one byte earlier
+        let wide_codes = reader.fill_buf()?.get(1) == Some(&0);
+
+        Ok(BinaryTokenizer {reader, wide_codes, error: None})
+    }
+
+    fn bytes<const N: usize>(&mut self) -> io::Result<[u8; N]> {
+        let mut bytes = [0; N];
+        self.reader.read_exact(&mut bytes)?;
+
+        Ok(bytes)
+    }
+
+    fn string(&mut self) -> io::Result<String> {
+        let mut bytes = vec![];
+        self.reader.read_until(0, &mut bytes)?;
+
+        if bytes.pop() != Some(0) {
+            return Err(ErrorKind::UnexpectedEof.into());
+        }
+
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    fn code(&mut self) -> io::Result<Option<u16>> {
+        if self.reader.fill_buf()?.is_empty() {
+            return Ok(None);
+        }
+
+        let code = match self.wide_codes {
+            true => u16::from_le_bytes(self.bytes()?),
+
+            false => match self.bytes::<1>()? {
+                [255] => u16::from_le_bytes(self.bytes()?),
+                [c] => c as u16
+            }
+        };
+
+        Ok(Some(code))
+    }
+
+    fn value(&mut self, code: u16) -> io::Result<String> {
+        Ok(match value_type(code) {
+            ValueType::String => self.string()?,
+            ValueType::Double => f64::from_le_bytes(self.bytes()?).to_string(),
+            ValueType::Int16 => i16::from_le_bytes(self.bytes()?).to_string(),
+            ValueType::Int32 => i32::from_le_bytes(self.bytes()?).to_string(),
+            ValueType::Int64 => i64::from_le_bytes(self.bytes()?).to_string(),
+            ValueType::Bool => u8::from_le_bytes(self.bytes()?).to_string(),
+
+            ValueType::Chunk => {
+                let [length] = self.bytes()?;
+                let mut chunk = vec![0; length as usize];
+                self.reader.read_exact(&mut chunk)?;
+
+                chunk.iter().map(|byte| format!("{:02X}", byte)).collect()
+            }
+        })
+    }
+
+    fn pair(&mut self) -> io::Result<Option<DataPair<'static>>> {
+        match self.code()? {
+            Some(code) => Ok(Some((group_code(code), Cow::Owned(self.value(code)?)))),
+            None => Ok(None)
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for BinaryTokenizer<R> {
+    type Item = DataPair<'static>;
+
+    fn next(&mut self) -> Option<DataPair<'static>> {
+        match self.pair() {
+            Ok(pair) => pair,
+
+            // A truncated file ends the stream just like a truncated ASCII file does
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => None,
+
+            Err(e) => {
+                self.error = Some(e);
+                None
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Tokenizer<'static> for BinaryTokenizer<R> {
+    fn take_error(&mut self) -> Option<io::Error> {
+        self.error.take()
+    }
+}
+
+fn value_type(code: u16) -> ValueType {
+    match code {
+        10..=59 | 110..=149 | 210..=239 | 460..=469 | 1010..=1059 => ValueType::Double,
+        60..=79 | 170..=179 | 270..=289 | 370..=389 | 400..=409 | 1060..=1070 => ValueType::Int16,
+        90..=99 | 420..=429 | 440..=459 | 1071 => ValueType::Int32,
+        160..=169 => ValueType::Int64,
+        290..=299 => ValueType::Bool,
+        310..=319 | 1004 => ValueType::Chunk,
+        _ => ValueType::String
+    }
+}
@@ -42,18 +42,17 @@ fn get_bulge_arc(start_point: &Vertex, end_point: &Vertex, bulge: f64) -> Arc {
 }
 
 
-pub fn explode_bulged_vertices(vertices: Vec<VertexWithBulge>) -> Vec<Vertex> {
+pub fn explode_bulged_vertices(vertices: Vec<VertexWithBulge>, scale: f64) -> Vec<Vertex> {
     let mut new_vertices = vec![];
 
-    let mut iterator = vertices.iter();
     let mut last_vertex: Option<&VertexWithBulge> = None;
 
-    while let Some(vertex) = iterator.next() {
+    for vertex in vertices.iter() {
         match (last_vertex, vertex) {
             (Some(l), r) if l.bulge != 0.0 => {
                 let a = get_bulge_arc(&l.vertex, &r.vertex, l.bulge);
-                new_vertices.push(l.vertex.clone());
-                new_vertices.append(&mut a.into_polyline().vertices);
+                new_vertices.push(l.vertex);
+                new_vertices.append(&mut a.into_polyline(scale).vertices);
                 last_vertex = Some(vertex);
             },
 
@@ -62,19 +61,19 @@ pub fn explode_bulged_vertices(vertices: Vec<VertexWithBulge>) -> Vec<Vertex> {
             },
 
             _ => {
-                new_vertices.push(last_vertex.unwrap().vertex.clone());
+                new_vertices.push(last_vertex.unwrap().vertex);
                 last_vertex = Some(vertex);
             }
         }
     }
 
     if let Some(ll) = last_vertex {
-        new_vertices.push(ll.vertex.clone());
+        new_vertices.push(ll.vertex);
 
         if ll.bulge != 0.0 {
             let first = vertices.first().unwrap();
             let a = get_bulge_arc(&ll.vertex, &first.vertex, ll.bulge);
-            new_vertices.append(&mut a.into_polyline().vertices);
+            new_vertices.append(&mut a.into_polyline(scale).vertices);
         }
     }
 
@@ -9,11 +9,11 @@ pub struct Circle {
 }
 
 impl Circle {
-    pub fn into_polyline(self) -> PolyLine {
+    pub fn into_polyline(self, scale: f64) -> PolyLine {
         let mut points = vec![];
 
-        let circumference: f64 = 2.0 * PI * self.radius;
-        let resolution = 360.0 / circumference.max(6.0).min(180.0);
+        let circumference: f64 = 2.0 * PI * self.radius * scale;
+        let resolution = 360.0 / circumference.clamp(6.0, 180.0);
 
         for i in (0..360).step_by(resolution as usize) {
             let rad = (i as f64).to_radians();
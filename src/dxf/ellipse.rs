@@ -0,0 +1,37 @@
+use std::f64::consts::PI;
+use crate::dxf::polyline::PolyLine;
+use crate::dxf::vertex::Vertex;
+
+#[derive(Debug, Clone)]
+pub struct Ellipse {
+    pub centre: Vertex,
+    pub major_axis: Vertex,
+    pub ratio: f64,
+    pub start_parameter: f64,
+    pub end_parameter: f64
+}
+
+impl Ellipse {
+    pub fn into_polyline(self, scale: f64) -> PolyLine {
+        let mut points = vec![];
+        let sweep = self.end_parameter - self.start_parameter;
+
+        let major_radius = self.major_axis.magnitude();
+        let minor_axis = self.major_axis.normal().with_magnitude(-major_radius * self.ratio);
+
+        let circumference = PI * major_radius * scale * (1.0 + self.ratio) * (sweep.abs() / (2.0 * PI));
+        let resolution = ((circumference * 1.5) as i64).clamp(12, 90);
+        let step = sweep / resolution as f64;
+
+        for s in 0..(resolution + 1) {
+            let (sin, cos) = (self.start_parameter + s as f64 * step).sin_cos();
+
+            points.push(Vertex {
+                x: self.centre.x + self.major_axis.x * cos + minor_axis.x * sin,
+                y: self.centre.y + self.major_axis.y * cos + minor_axis.y * sin
+            });
+        }
+
+        PolyLine {vertices: points, closed: false}
+    }
+}
@@ -0,0 +1,145 @@
+use crate::dxf::polyline::PolyLine;
+use crate::dxf::vertex::Vertex;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FillRule {
+    EvenOdd,
+    NonZero
+}
+
+#[derive(Debug, Clone)]
+pub struct FillOptions {
+    pub spacing: f64,
+    pub angle: f64,
+    pub rule: FillRule,
+    pub cross_hatch: bool,
+    pub bidirectional: bool
+}
+
+struct Crossing {
+    x: f64,
+    winding: i32
+}
+
+impl FillOptions {
+    pub fn new(spacing: f64) -> FillOptions {
+        FillOptions {
+            spacing,
+            angle: 0.0,
+            rule: FillRule::EvenOdd,
+            cross_hatch: false,
+            bidirectional: true
+        }
+    }
+}
+
+pub fn hatch(contours: &[PolyLine], options: &FillOptions) -> Vec<PolyLine> {
+    let mut lines = hatch_at_angle(contours, options, options.angle);
+
+    if options.cross_hatch {
+        lines.append(&mut hatch_at_angle(contours, options, options.angle + 90.0));
+    }
+
+    lines
+}
+
+fn hatch_at_angle(contours: &[PolyLine], options: &FillOptions, angle: f64) -> Vec<PolyLine> {
+    let rotation = angle.to_radians();
+
+    // Rotate the contours so the hatch lines become horizontal scanlines
+    let edges: Vec<(Vertex, Vertex)> = contours.iter()
+        .flat_map(contour_edges)
+        .map(|(a, b)| (a.rotate(-rotation), b.rotate(-rotation)))
+        .collect();
+
+    if edges.is_empty() {
+        return vec![];
+    }
+
+    let y_min = edges.iter().map(|(a, b)| a.y.min(b.y)).fold(f64::MAX, f64::min);
+    let y_max = edges.iter().map(|(a, b)| a.y.max(b.y)).fold(f64::MIN, f64::max);
+
+    scan_rows(y_min, y_max, options, rotation, |y| scanline_spans(&edges, y, options.rule))
+}
+
+pub(crate) fn scan_rows(y_min: f64, y_max: f64, options: &FillOptions, rotation: f64, spans_at: impl Fn(f64) -> Vec<(f64, f64)>) -> Vec<PolyLine> {
+    if options.spacing <= 0.0 {
+        return vec![];
+    }
+
+    let mut lines = vec![];
+    let mut reversed = false;
+
+    for row in (y_min / options.spacing).floor() as i64..=(y_max / options.spacing).ceil() as i64 {
+        let y = (row as f64 + 0.5) * options.spacing;
+        let mut spans = spans_at(y);
+
+        if spans.is_empty() {
+            continue;
+        }
+
+        if reversed {
+            spans.reverse();
+            spans.iter_mut().for_each(|span| *span = (span.1, span.0));
+        }
+
+        reversed = options.bidirectional && !reversed;
+
+        lines.extend(spans.into_iter().map(|(start, end)| PolyLine {
+            vertices: vec![
+                Vertex {x: start, y}.rotate(rotation),
+                Vertex {x: end, y}.rotate(rotation)
+            ],
+            closed: false
+        }));
+    }
+
+    lines
+}
+
+pub(crate) fn contour_edges(contour: &PolyLine) -> Vec<(Vertex, Vertex)> {
+    let vertices = &contour.vertices;
+
+    if vertices.len() < 3 {
+        return vec![];
+    }
+
+    vertices.iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| (*a, *b))
+        .collect()
+}
+
+pub(crate) fn scanline_spans(edges: &[(Vertex, Vertex)], y: f64, rule: FillRule) -> Vec<(f64, f64)> {
+    let mut crossings: Vec<Crossing> = edges.iter()
+        .filter(|(a, b)| (a.y <= y) != (b.y <= y))
+        .map(|(a, b)| Crossing {
+            x: a.x + (y - a.y) * (b.x - a.x) / (b.y - a.y),
+            winding: if b.y > a.y { 1 } else { -1 }
+        })
+        .collect();
+
+    crossings.sort_by(|a, b| a.x.total_cmp(&b.x));
+
+    let is_inside = |winding: i32| match rule {
+        FillRule::EvenOdd => winding % 2 != 0,
+        FillRule::NonZero => winding != 0
+    };
+
+    let mut spans = vec![];
+    let mut winding = 0;
+    let mut start = 0.0;
+
+    for crossing in crossings {
+        let was_inside = is_inside(winding);
+        winding += crossing.winding;
+
+        match (was_inside, is_inside(winding)) {
+            (false, true) => start = crossing.x,
+            (true, false) if crossing.x - start > 1e-9 => spans.push((start, crossing.x)),
+            _ => ()
+        }
+    }
+
+    spans
+}
@@ -0,0 +1,119 @@
+pub const CAP_HEIGHT: f64 = 6.0;
+pub const DESCENT: f64 = 2.0;
+pub const LETTER_SPACING: f64 = 2.0;
+
+pub struct Glyph {
+    pub width: i8,
+    pub strokes: &'static [&'static [(i8, i8)]]
+}
+
+impl Glyph {
+    pub fn advance(&self) -> f64 {
+        self.width as f64 + LETTER_SPACING
+    }
+}
+
+// A single stroke font on a grid with the baseline at 0, capitals 6 units high and descenders down to -2
+pub fn glyph(character: char) -> Glyph {
+    match character {
+        ' ' => Glyph {width: 2, strokes: &[]},
+        '!' => Glyph {width: 2, strokes: &[&[(1, 6), (1, 2)], &[(1, 1), (1, 0)]]},
+        '"' => Glyph {width: 2, strokes: &[&[(0, 6), (0, 4)], &[(2, 6), (2, 4)]]},
+        '#' => Glyph {width: 4, strokes: &[&[(1, 0), (1, 6)], &[(3, 0), (3, 6)], &[(0, 2), (4, 2)], &[(0, 4), (4, 4)]]},
+        '$' => Glyph {width: 4, strokes: &[&[(4, 5), (3, 6), (1, 6), (0, 5), (0, 4), (1, 3), (3, 3), (4, 2), (4, 1), (3, 0), (1, 0), (0, 1)], &[(2, 7), (2, -1)]]},
+        '%' => Glyph {width: 4, strokes: &[&[(0, 0), (4, 6)], &[(0, 6), (1, 6), (1, 5), (0, 5), (0, 6)], &[(3, 1), (4, 1), (4, 0), (3, 0), (3, 1)]]},
+        '&' => Glyph {width: 4, strokes: &[&[(4, 0), (1, 4), (1, 5), (2, 6), (3, 5), (3, 4), (0, 2), (0, 1), (1, 0), (2, 0), (4, 2)]]},
+        '\'' => Glyph {width: 0, strokes: &[&[(0, 6), (0, 4)]]},
+        '(' => Glyph {width: 2, strokes: &[&[(2, 7), (0, 5), (0, 1), (2, -1)]]},
+        ')' => Glyph {width: 2, strokes: &[&[(0, 7), (2, 5), (2, 1), (0, -1)]]},
+        '*' => Glyph {width: 4, strokes: &[&[(2, 5), (2, 1)], &[(0, 4), (4, 2)], &[(0, 2), (4, 4)]]},
+        '+' => Glyph {width: 4, strokes: &[&[(2, 5), (2, 1)], &[(0, 3), (4, 3)]]},
+        ',' => Glyph {width: 1, strokes: &[&[(1, 1), (1, 0), (0, -1)]]},
+        '-' => Glyph {width: 4, strokes: &[&[(0, 3), (4, 3)]]},
+        '.' => Glyph {width: 0, strokes: &[&[(0, 1), (0, 0)]]},
+        '/' => Glyph {width: 4, strokes: &[&[(0, 0), (4, 6)]]},
+        '0' => Glyph {width: 4, strokes: &[&[(1, 0), (0, 1), (0, 5), (1, 6), (3, 6), (4, 5), (4, 1), (3, 0), (1, 0)], &[(0, 1), (4, 5)]]},
+        '1' => Glyph {width: 4, strokes: &[&[(1, 5), (2, 6), (2, 0)], &[(1, 0), (3, 0)]]},
+        '2' => Glyph {width: 4, strokes: &[&[(0, 5), (1, 6), (3, 6), (4, 5), (4, 4), (0, 0), (4, 0)]]},
+        '3' => Glyph {width: 4, strokes: &[&[(0, 5), (1, 6), (3, 6), (4, 5), (4, 4), (3, 3), (4, 2), (4, 1), (3, 0), (1, 0), (0, 1)], &[(1, 3), (3, 3)]]},
+        '4' => Glyph {width: 4, strokes: &[&[(3, 0), (3, 6), (0, 2), (4, 2)]]},
+        '5' => Glyph {width: 4, strokes: &[&[(4, 6), (0, 6), (0, 3), (3, 3), (4, 2), (4, 1), (3, 0), (1, 0), (0, 1)]]},
+        '6' => Glyph {width: 4, strokes: &[&[(4, 5), (3, 6), (1, 6), (0, 5), (0, 1), (1, 0), (3, 0), (4, 1), (4, 2), (3, 3), (0, 3)]]},
+        '7' => Glyph {width: 4, strokes: &[&[(0, 6), (4, 6), (1, 0)]]},
+        '8' => Glyph {width: 4, strokes: &[&[(1, 3), (0, 4), (0, 5), (1, 6), (3, 6), (4, 5), (4, 4), (3, 3), (1, 3), (0, 2), (0, 1), (1, 0), (3, 0), (4, 1), (4, 2), (3, 3)]]},
+        '9' => Glyph {width: 4, strokes: &[&[(0, 1), (1, 0), (3, 0), (4, 1), (4, 5), (3, 6), (1, 6), (0, 5), (0, 4), (1, 3), (4, 3)]]},
+        ':' => Glyph {width: 0, strokes: &[&[(0, 4), (0, 3)], &[(0, 1), (0, 0)]]},
+        ';' => Glyph {width: 1, strokes: &[&[(1, 4), (1, 3)], &[(1, 1), (1, 0), (0, -1)]]},
+        '<' => Glyph {width: 4, strokes: &[&[(4, 6), (0, 3), (4, 0)]]},
+        '=' => Glyph {width: 4, strokes: &[&[(0, 4), (4, 4)], &[(0, 2), (4, 2)]]},
+        '>' => Glyph {width: 4, strokes: &[&[(0, 6), (4, 3), (0, 0)]]},
+        '?' => Glyph {width: 4, strokes: &[&[(0, 5), (1, 6), (3, 6), (4, 5), (4, 4), (2, 3), (2, 2)], &[(2, 1), (2, 0)]]},
+        '@' => Glyph {width: 4, strokes: &[&[(3, 2), (2, 2), (1, 3), (2, 4), (3, 4), (3, 2), (4, 2), (4, 5), (3, 6), (1, 6), (0, 5), (0, 1), (1, 0), (4, 0)]]},
+        'A' => Glyph {width: 4, strokes: &[&[(0, 0), (2, 6), (4, 0)], &[(1, 3), (3, 3)]]},
+        'B' => Glyph {width: 4, strokes: &[&[(0, 0), (0, 6), (3, 6), (4, 5), (4, 4), (3, 3), (0, 3)], &[(3, 3), (4, 2), (4, 1), (3, 0), (0, 0)]]},
+        'C' => Glyph {width: 4, strokes: &[&[(4, 5), (3, 6), (1, 6), (0, 5), (0, 1), (1, 0), (3, 0), (4, 1)]]},
+        'D' => Glyph {width: 4, strokes: &[&[(0, 0), (0, 6), (2, 6), (4, 4), (4, 2), (2, 0), (0, 0)]]},
+        'E' => Glyph {width: 4, strokes: &[&[(4, 6), (0, 6), (0, 0), (4, 0)], &[(0, 3), (3, 3)]]},
+        'F' => Glyph {width: 4, strokes: &[&[(4, 6), (0, 6), (0, 0)], &[(0, 3), (3, 3)]]},
+        'G' => Glyph {width: 4, strokes: &[&[(4, 5), (3, 6), (1, 6), (0, 5), (0, 1), (1, 0), (3, 0), (4, 1), (4, 3), (2, 3)]]},
+        'H' => Glyph {width: 4, strokes: &[&[(0, 0), (0, 6)], &[(4, 0), (4, 6)], &[(0, 3), (4, 3)]]},
+        'I' => Glyph {width: 2, strokes: &[&[(0, 6), (2, 6)], &[(1, 6), (1, 0)], &[(0, 0), (2, 0)]]},
+        'J' => Glyph {width: 4, strokes: &[&[(4, 6), (4, 1), (3, 0), (1, 0), (0, 1)]]},
+        'K' => Glyph {width: 4, strokes: &[&[(0, 0), (0, 6)], &[(4, 6), (0, 2)], &[(1, 3), (4, 0)]]},
+        'L' => Glyph {width: 4, strokes: &[&[(0, 6), (0, 0), (4, 0)]]},
+        'M' => Glyph {width: 4, strokes: &[&[(0, 0), (0, 6), (2, 3), (4, 6), (4, 0)]]},
+        'N' => Glyph {width: 4, strokes: &[&[(0, 0), (0, 6), (4, 0), (4, 6)]]},
+        'O' => Glyph {width: 4, strokes: &[&[(1, 0), (0, 1), (0, 5), (1, 6), (3, 6), (4, 5), (4, 1), (3, 0), (1, 0)]]},
+        'P' => Glyph {width: 4, strokes: &[&[(0, 0), (0, 6), (3, 6), (4, 5), (4, 4), (3, 3), (0, 3)]]},
+        'Q' => Glyph {width: 4, strokes: &[&[(1, 0), (0, 1), (0, 5), (1, 6), (3, 6), (4, 5), (4, 1), (3, 0), (1, 0)], &[(2, 2), (4, 0)]]},
+        'R' => Glyph {width: 4, strokes: &[&[(0, 0), (0, 6), (3, 6), (4, 5), (4, 4), (3, 3), (0, 3)], &[(2, 3), (4, 0)]]},
+        'S' => Glyph {width: 4, strokes: &[&[(4, 5), (3, 6), (1, 6), (0, 5), (0, 4), (1, 3), (3, 3), (4, 2), (4, 1), (3, 0), (1, 0), (0, 1)]]},
+        'T' => Glyph {width: 4, strokes: &[&[(0, 6), (4, 6)], &[(2, 6), (2, 0)]]},
+        'U' => Glyph {width: 4, strokes: &[&[(0, 6), (0, 1), (1, 0), (3, 0), (4, 1), (4, 6)]]},
+        'V' => Glyph {width: 4, strokes: &[&[(0, 6), (2, 0), (4, 6)]]},
+        'W' => Glyph {width: 4, strokes: &[&[(0, 6), (1, 0), (2, 4), (3, 0), (4, 6)]]},
+        'X' => Glyph {width: 4, strokes: &[&[(0, 0), (4, 6)], &[(0, 6), (4, 0)]]},
+        'Y' => Glyph {width: 4, strokes: &[&[(0, 6), (2, 3), (4, 6)], &[(2, 3), (2, 0)]]},
+        'Z' => Glyph {width: 4, strokes: &[&[(0, 6), (4, 6), (0, 0), (4, 0)]]},
+        '[' => Glyph {width: 2, strokes: &[&[(2, 7), (0, 7), (0, -1), (2, -1)]]},
+        '\\' => Glyph {width: 4, strokes: &[&[(0, 6), (4, 0)]]},
+        ']' => Glyph {width: 2, strokes: &[&[(0, 7), (2, 7), (2, -1), (0, -1)]]},
+        '^' => Glyph {width: 4, strokes: &[&[(0, 4), (2, 6), (4, 4)]]},
+        '_' => Glyph {width: 4, strokes: &[&[(0, -1), (4, -1)]]},
+        '`' => Glyph {width: 1, strokes: &[&[(0, 6), (1, 5)]]},
+        'a' => Glyph {width: 3, strokes: &[&[(3, 4), (3, 0)], &[(3, 3), (2, 4), (1, 4), (0, 3), (0, 1), (1, 0), (2, 0), (3, 1)]]},
+        'b' => Glyph {width: 3, strokes: &[&[(0, 6), (0, 0)], &[(0, 3), (1, 4), (2, 4), (3, 3), (3, 1), (2, 0), (1, 0), (0, 1)]]},
+        'c' => Glyph {width: 3, strokes: &[&[(3, 3), (2, 4), (1, 4), (0, 3), (0, 1), (1, 0), (2, 0), (3, 1)]]},
+        'd' => Glyph {width: 3, strokes: &[&[(3, 6), (3, 0)], &[(3, 3), (2, 4), (1, 4), (0, 3), (0, 1), (1, 0), (2, 0), (3, 1)]]},
+        'e' => Glyph {width: 3, strokes: &[&[(0, 2), (3, 2), (3, 3), (2, 4), (1, 4), (0, 3), (0, 1), (1, 0), (3, 0)]]},
+        'f' => Glyph {width: 3, strokes: &[&[(3, 6), (2, 6), (1, 5), (1, 0)], &[(0, 4), (2, 4)]]},
+        'g' => Glyph {width: 3, strokes: &[&[(3, 4), (3, -1), (2, -2), (1, -2), (0, -1)], &[(3, 3), (2, 4), (1, 4), (0, 3), (0, 1), (1, 0), (2, 0), (3, 1)]]},
+        'h' => Glyph {width: 3, strokes: &[&[(0, 6), (0, 0)], &[(0, 3), (1, 4), (2, 4), (3, 3), (3, 0)]]},
+        'i' => Glyph {width: 2, strokes: &[&[(1, 4), (1, 0)], &[(1, 6), (1, 5)]]},
+        'j' => Glyph {width: 2, strokes: &[&[(2, 4), (2, -1), (1, -2), (0, -2)], &[(2, 6), (2, 5)]]},
+        'k' => Glyph {width: 3, strokes: &[&[(0, 6), (0, 0)], &[(3, 4), (0, 1)], &[(1, 2), (3, 0)]]},
+        'l' => Glyph {width: 2, strokes: &[&[(1, 6), (1, 1), (2, 0)]]},
+        'm' => Glyph {width: 4, strokes: &[&[(0, 4), (0, 0)], &[(0, 3), (1, 4), (2, 3), (2, 0)], &[(2, 3), (3, 4), (4, 3), (4, 0)]]},
+        'n' => Glyph {width: 3, strokes: &[&[(0, 4), (0, 0)], &[(0, 3), (1, 4), (2, 4), (3, 3), (3, 0)]]},
+        'o' => Glyph {width: 3, strokes: &[&[(1, 0), (0, 1), (0, 3), (1, 4), (2, 4), (3, 3), (3, 1), (2, 0), (1, 0)]]},
+        'p' => Glyph {width: 3, strokes: &[&[(0, 4), (0, -2)], &[(0, 3), (1, 4), (2, 4), (3, 3), (3, 1), (2, 0), (1, 0), (0, 1)]]},
+        'q' => Glyph {width: 3, strokes: &[&[(3, 4), (3, -2)], &[(3, 3), (2, 4), (1, 4), (0, 3), (0, 1), (1, 0), (2, 0), (3, 1)]]},
+        'r' => Glyph {width: 3, strokes: &[&[(0, 4), (0, 0)], &[(0, 2), (2, 4), (3, 4)]]},
+        's' => Glyph {width: 3, strokes: &[&[(3, 3), (2, 4), (1, 4), (0, 3), (1, 2), (2, 2), (3, 1), (2, 0), (1, 0), (0, 1)]]},
+        't' => Glyph {width: 3, strokes: &[&[(1, 6), (1, 1), (2, 0), (3, 0)], &[(0, 4), (2, 4)]]},
+        'u' => Glyph {width: 3, strokes: &[&[(0, 4), (0, 1), (1, 0), (2, 0), (3, 1)], &[(3, 4), (3, 0)]]},
+        'v' => Glyph {width: 4, strokes: &[&[(0, 4), (2, 0), (4, 4)]]},
+        'w' => Glyph {width: 4, strokes: &[&[(0, 4), (1, 0), (2, 3), (3, 0), (4, 4)]]},
+        'x' => Glyph {width: 3, strokes: &[&[(0, 4), (3, 0)], &[(0, 0), (3, 4)]]},
+        'y' => Glyph {width: 3, strokes: &[&[(0, 4), (0, 1), (1, 0), (2, 0), (3, 1)], &[(3, 4), (3, -1), (2, -2), (1, -2), (0, -1)]]},
+        'z' => Glyph {width: 3, strokes: &[&[(0, 4), (3, 4), (0, 0), (3, 0)]]},
+        '{' => Glyph {width: 3, strokes: &[&[(3, 7), (2, 7), (1, 6), (1, 4), (0, 3), (1, 2), (1, 0), (2, -1), (3, -1)]]},
+        '|' => Glyph {width: 0, strokes: &[&[(0, 7), (0, -1)]]},
+        '}' => Glyph {width: 3, strokes: &[&[(0, 7), (1, 7), (2, 6), (2, 4), (3, 3), (2, 2), (2, 0), (1, -1), (0, -1)]]},
+        '~' => Glyph {width: 4, strokes: &[&[(0, 3), (1, 4), (3, 2), (4, 3)]]},
+        '°' => Glyph {width: 2, strokes: &[&[(0, 6), (2, 6), (2, 4), (0, 4), (0, 6)]]},
+        '±' => Glyph {width: 4, strokes: &[&[(2, 5), (2, 1)], &[(0, 3), (4, 3)], &[(0, 0), (4, 0)]]},
+        'Ø' => Glyph {width: 4, strokes: &[&[(1, 0), (0, 1), (0, 5), (1, 6), (3, 6), (4, 5), (4, 1), (3, 0), (1, 0)], &[(0, 0), (4, 6)]]},
+        _ => glyph('?')
+    }
+}
@@ -0,0 +1,211 @@
+use crate::dxf::fill::{contour_edges, hatch, scanline_spans, FillOptions, FillRule};
+use crate::dxf::polyline::PolyLine;
+use crate::dxf::vertex::Vertex;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IslandStyle {
+    Normal,
+    Outer,
+    Ignore
+}
+
+// One family of parallel lines in a hatch pattern. Each line starts from the base point moved on by
+// the offset, and positive dashes are drawn while negative ones are gaps
+#[derive(Debug, Clone)]
+pub struct PatternLine {
+    pub angle: f64,
+    pub base: Vertex,
+    pub offset: Vertex,
+    pub dashes: Vec<f64>
+}
+
+#[derive(Debug, Clone)]
+pub enum HatchPattern {
+    Solid,
+    Lines(Vec<PatternLine>)
+}
+
+#[derive(Debug, Clone)]
+pub struct Hatch {
+    pub boundaries: Vec<PolyLine>,
+    pub style: IslandStyle,
+    pub pattern: HatchPattern
+}
+
+impl Hatch {
+    pub fn fill(&self, solid: &FillOptions) -> Vec<PolyLine> {
+        let boundaries = self.filled_boundaries();
+
+        match &self.pattern {
+            HatchPattern::Solid => hatch(&boundaries, &FillOptions {rule: FillRule::EvenOdd, ..solid.clone()}),
+
+            HatchPattern::Lines(lines) => lines.iter()
+                .flat_map(|line| line.fill(&boundaries, solid.bidirectional))
+                .collect()
+        }
+    }
+
+    pub fn scale(&self, factor: f64) -> Hatch {
+        let pattern = match &self.pattern {
+            HatchPattern::Solid => HatchPattern::Solid,
+
+            HatchPattern::Lines(lines) => HatchPattern::Lines(lines.iter()
+                .map(|line| PatternLine {
+                    angle: line.angle,
+                    base: line.base.scale(factor),
+                    offset: line.offset.scale(factor),
+                    dashes: line.dashes.iter().map(|dash| dash * factor).collect()
+                })
+                .collect())
+        };
+
+        Hatch {
+            boundaries: self.boundaries.iter().map(|b| b.scale(factor)).collect(),
+            style: self.style,
+            pattern
+        }
+    }
+
+    // Moves the hatch by a placement that turns it by the given angle in degrees and scales its dashes
+    pub fn transform(&self, apply: impl Fn(Vertex) -> Vertex, rotation: f64, factor: f64) -> Hatch {
+        let origin = apply(Vertex {x: 0.0, y: 0.0});
+
+        let pattern = match &self.pattern {
+            HatchPattern::Solid => HatchPattern::Solid,
+
+            HatchPattern::Lines(lines) => HatchPattern::Lines(lines.iter()
+                .map(|line| PatternLine {
+                    angle: line.angle + rotation,
+                    base: apply(line.base),
+                    offset: origin.vector_to(&apply(line.offset)),
+                    dashes: line.dashes.iter().map(|dash| dash * factor).collect()
+                })
+                .collect())
+        };
+
+        Hatch {
+            boundaries: self.boundaries.iter()
+                .map(|boundary| PolyLine {vertices: boundary.vertices.iter().copied().map(&apply).collect(), closed: boundary.closed})
+                .collect(),
+            style: self.style,
+            pattern
+        }
+    }
+
+    pub fn into_boundaries(self) -> Vec<PolyLine> {
+        self.boundaries
+    }
+
+    fn filled_boundaries(&self) -> Vec<PolyLine> {
+        let max_depth = match self.style {
+            IslandStyle::Normal => usize::MAX,
+            IslandStyle::Outer => 1,
+            IslandStyle::Ignore => 0
+        };
+
+        self.boundaries.iter()
+            .filter(|boundary| self.nesting_depth(boundary) <= max_depth)
+            .cloned()
+            .collect()
+    }
+
+    fn nesting_depth(&self, boundary: &PolyLine) -> usize {
+        match boundary.vertices.first() {
+            Some(point) => self.boundaries.iter()
+                .filter(|other| !std::ptr::eq(*other, boundary) && contains(other, point))
+                .count(),
+
+            None => 0
+        }
+    }
+}
+
+impl PatternLine {
+    fn fill(&self, boundaries: &[PolyLine], bidirectional: bool) -> Vec<PolyLine> {
+        let rotation = self.angle.to_radians();
+
+        // In a frame turned to the pattern angle every line is horizontal
+        let base = self.base.rotate(-rotation);
+        let offset = self.offset.rotate(-rotation);
+
+        let edges: Vec<(Vertex, Vertex)> = boundaries.iter()
+            .flat_map(contour_edges)
+            .map(|(a, b)| (a.rotate(-rotation), b.rotate(-rotation)))
+            .collect();
+
+        if edges.is_empty() || offset.y.abs() < 1e-9 {
+            return vec![];
+        }
+
+        let y_min = edges.iter().map(|(a, b)| a.y.min(b.y)).fold(f64::MAX, f64::min);
+        let y_max = edges.iter().map(|(a, b)| a.y.max(b.y)).fold(f64::MIN, f64::max);
+        let (first, last) = ((y_min - base.y) / offset.y, (y_max - base.y) / offset.y);
+
+        let mut lines = vec![];
+        let mut reversed = false;
+
+        for row in first.min(last).floor() as i64..=first.max(last).ceil() as i64 {
+            let y = base.y + row as f64 * offset.y;
+            let start = base.x + row as f64 * offset.x;
+
+            let mut dashes: Vec<(f64, f64)> = scanline_spans(&edges, y, FillRule::EvenOdd).into_iter()
+                .flat_map(|span| self.dashes_within(span, start))
+                .collect();
+
+            if dashes.is_empty() {
+                continue;
+            }
+
+            if reversed {
+                dashes.reverse();
+                dashes.iter_mut().for_each(|dash| *dash = (dash.1, dash.0));
+            }
+
+            reversed = bidirectional && !reversed;
+
+            lines.extend(dashes.into_iter().map(|(a, b)| PolyLine {
+                vertices: vec![Vertex {x: a, y}.rotate(rotation), Vertex {x: b, y}.rotate(rotation)],
+                closed: false
+            }));
+        }
+
+        lines
+    }
+
+    // The drawn parts of a span, with the dash pattern repeating from the start of its line.
+    // Dots have no length, so there is nothing for them to cut
+    fn dashes_within(&self, (from, to): (f64, f64), start: f64) -> Vec<(f64, f64)> {
+        let period: f64 = self.dashes.iter().map(|dash| dash.abs()).sum();
+
+        if period < 1e-9 {
+            return vec![(from, to)];
+        }
+
+        let mut dashes = vec![];
+        let mut position = start + ((from - start) / period).floor() * period;
+
+        while position < to {
+            for dash in self.dashes.iter() {
+                let end = position + dash.abs();
+
+                if *dash > 0.0 && end > from && position < to {
+                    dashes.push((position.max(from), end.min(to)));
+                }
+
+                position = end;
+            }
+        }
+
+        dashes
+    }
+}
+
+fn contains(boundary: &PolyLine, point: &Vertex) -> bool {
+    let vertices = &boundary.vertices;
+
+    vertices.iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .filter(|(a, b)| (a.y <= point.y) != (b.y <= point.y))
+        .filter(|(a, b)| point.x < a.x + (point.y - a.y) * (b.x - a.x) / (b.y - a.y))
+        .count() % 2 == 1
+}
@@ -0,0 +1,70 @@
+use crate::dxf::polyline::PolyLine;
+use crate::dxf::vertex::Vertex;
+
+#[derive(Debug, Clone)]
+pub struct Nurbs {
+    pub degree: usize,
+    pub knots: Vec<f64>,
+    pub control_points: Vec<Vertex>,
+    pub weights: Vec<f64>
+}
+
+impl Nurbs {
+    pub fn into_polyline(self) -> PolyLine {
+        let count = self.control_points.len();
+
+        if count <= self.degree || self.knots.len() != count + self.degree + 1 {
+            return PolyLine {vertices: self.control_points, closed: false};
+        }
+
+        let start = self.knots[self.degree];
+        let end = self.knots[count];
+        let resolution = (count * 8).clamp(16, 200);
+
+        let vertices = (0..=resolution)
+            .map(|s| self.point_at(start + (end - start) * s as f64 / resolution as f64))
+            .collect();
+
+        PolyLine {vertices, closed: false}
+    }
+
+    fn weight(&self, i: usize) -> f64 {
+        self.weights.get(i).copied().unwrap_or(1.0)
+    }
+
+    fn point_at(&self, t: f64) -> Vertex {
+        let p = self.degree;
+        let count = self.control_points.len();
+
+        let span = (p..count)
+            .rfind(|&i| self.knots[i] <= t && (t < self.knots[i + 1] || i == count - 1))
+            .unwrap_or(p);
+
+        // De Boor's algorithm in homogeneous coordinates so rational curves are handled too
+        let mut points: Vec<(f64, f64, f64)> = (0..=p)
+            .map(|j| {
+                let i = span - p + j;
+                let w = self.weight(i);
+                (self.control_points[i].x * w, self.control_points[i].y * w, w)
+            })
+            .collect();
+
+        for r in 1..=p {
+            for j in (r..=p).rev() {
+                let i = span - p + j;
+                let denominator = self.knots[i + p + 1 - r] - self.knots[i];
+                let alpha = if denominator == 0.0 { 0.0 } else { (t - self.knots[i]) / denominator };
+
+                points[j] = (
+                    (1.0 - alpha) * points[j - 1].0 + alpha * points[j].0,
+                    (1.0 - alpha) * points[j - 1].1 + alpha * points[j].1,
+                    (1.0 - alpha) * points[j - 1].2 + alpha * points[j].2
+                );
+            }
+        }
+
+        let (x, y, w) = points[p];
+
+        Vertex {x: x / w, y: y / w}
+    }
+}
@@ -1,307 +1,747 @@
-use std::slice;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use std::io::{self, BufRead};
+use std::iter::Peekable;
 use crate::dxf::arc::Arc;
+use crate::dxf::binary::{detect, BinaryTokenizer};
 use crate::dxf::bulge::{explode_bulged_vertices, VertexWithBulge};
 use crate::dxf::circle::Circle;
+use crate::dxf::ellipse::Ellipse;
+use crate::dxf::hatch::{Hatch, HatchPattern, IslandStyle, PatternLine};
+use crate::dxf::nurbs::Nurbs;
 use crate::dxf::polyline::PolyLine;
 use crate::dxf::spline::Spline;
+use crate::dxf::text::{MText, Text};
+use crate::dxf::tokenizer::{DataPair, ReaderTokenizer, StrTokenizer, Tokenizer};
+use crate::dxf::units::Unit;
 use crate::dxf::vertex::{PartialVertex, Vertex};
 
-#[derive(PartialEq)]
-enum LineParserState {
-    Start,
-    End
+const MAX_INSERT_DEPTH: usize = 16;
+
+enum CircleEntity {
+    FullCircle(Circle),
+    Partial(Arc)
 }
 
-#[derive(PartialEq)]
-enum PolylineParserState {
-    Closed,
-    Vertex,
-    Finish
+pub struct Drawing {
+    pub lines: Vec<PolyLine>,
+    pub hatches: Vec<Hatch>,
+    pub points: Vec<Vertex>,
+    pub units: Option<Unit>
 }
 
-#[derive(PartialEq)]
-enum SplineParserState {
-    ControlPointCount,
-    ControlPoint,
+struct Entity<'a> {
+    pairs: Vec<DataPair<'a>>
 }
 
-#[derive(PartialEq)]
-enum CircleParserState {
-    Centre,
-    Radius,
-    Arc,
-    Finish
+// Entities are kept unconverted so each insert can divide curves to suit its own scale
+struct Block<'a> {
+    base: Vertex,
+    entities: Vec<(Cow<'a, str>, Entity<'a>, Vec<Entity<'a>>)>
 }
 
-enum CircleEntity {
-    FullCircle(Circle),
-    Partial(Arc)
+// Block geometry is scaled about the base point, rotated, then moved to the insertion point
+struct Placement {
+    base: Vertex,
+    insertion: Vertex,
+    scale: (f64, f64),
+    rotation: f64
 }
 
-type DataPair<'a> = (&'a str, &'a str);
+struct PairReader<'a> {
+    pairs: Vec<DataPair<'a>>,
+    position: usize
+}
 
-pub fn parse(dxf_contents: &str) -> Vec<PolyLine> {
-    convert(&collect_pairs(dxf_contents))
+impl Drawing {
+    pub fn scale(&self, factor: f64) -> Drawing {
+        Drawing {
+            lines: self.lines.iter().map(|l| l.scale(factor)).collect(),
+            hatches: self.hatches.iter().map(|h| h.scale(factor)).collect(),
+            points: self.points.iter().map(|p| p.scale(factor)).collect(),
+            units: self.units
+        }
+    }
 }
 
-fn collect_pairs(dxf_contents: &str) -> Vec<DataPair> {
-    let mut pairs = vec![];
+impl Placement {
+    fn apply(&self, point: Vertex) -> Vertex {
+        let local = Vertex {x: (point.x - self.base.x) * self.scale.0, y: (point.y - self.base.y) * self.scale.1};
+
+        self.insertion + local.rotate(self.rotation)
+    }
+}
 
-    let mut line_iterator = dxf_contents.lines();
+impl<'a> Entity<'a> {
+    fn read(iterator: &mut Peekable<impl Iterator<Item = DataPair<'a>>>) -> Entity<'a> {
+        let mut pairs = vec![];
 
-    while let Some(line) = line_iterator.next() {
-        match line_iterator.next() {
-            Some(l) => pairs.push((line.trim(), l.trim())),
-            None => continue
+        while let Some(pair) = iterator.next_if(|(code, _)| code != "0") {
+            pairs.push(pair);
         }
+
+        Entity {pairs}
     }
 
-    pairs
-}
+    fn pairs(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.pairs.iter().map(|(code, value)| (code.as_ref(), value.as_ref()))
+    }
 
-fn parse_line(iterator: &mut slice::Iter<DataPair>) -> Option<PolyLine> {
-    let mut vertices = vec![];
-    let mut state = LineParserState::Start;
-    let mut vert = PartialVertex::new();
+    fn value(&self, code: &str) -> Option<&str> {
+        self.pairs().find(|(c, _)| *c == code).map(|(_, value)| value)
+    }
 
-    while let Some(&pair) = iterator.next() {
-        match pair {
-            ("10", x) if state == LineParserState::Start => {
-                vert.x = Some(x.parse().unwrap());
-            },
+    fn f64(&self, code: &str) -> Option<f64> {
+        self.value(code)?.parse().ok()
+    }
 
-            ("20", y) if state == LineParserState::Start => {
-                vert.y = Some(y.parse().unwrap());
-            },
+    fn flags(&self, code: &str) -> u32 {
+        self.value(code).and_then(|v| v.parse().ok()).unwrap_or(0)
+    }
 
-            ("11", x) if state == LineParserState::End => {
-                vert.x = Some(x.parse().unwrap());
-            },
+    // Z coordinates are validated but dropped, projecting the drawing onto the XY plane
+    fn point(&self, index: u8) -> Option<Vertex> {
+        if let Some(z) = self.value(&(30 + index).to_string()) {
+            z.parse::<f64>().ok()?;
+        }
 
-            ("21", y) if state == LineParserState::End => {
-                vert.y = Some(y.parse().unwrap());
-            }
+        Some(Vertex {
+            x: self.f64(&(10 + index).to_string())?,
+            y: self.f64(&(20 + index).to_string())?
+        })
+    }
+}
 
-            _ => continue
-        }
+impl<'a> PairReader<'a> {
+    fn peek(&self) -> Option<(&str, &str)> {
+        self.pairs.get(self.position).map(|(code, value)| (code.as_ref(), value.as_ref()))
+    }
 
-        if let Some(v) = Vertex::from_partial(&vert) {
-            vertices.push(v);
-            vert = PartialVertex::new();
-            state = LineParserState::End;
-        }
+    fn peek_code_after(&self) -> Option<&str> {
+        self.pairs.get(self.position + 1).map(|(code, _)| code.as_ref())
+    }
+
+    fn skip(&mut self) {
+        self.position += 1;
+    }
+
+    fn take(&mut self, code: &str) -> Option<&str> {
+        let (c, value) = self.pairs.get(self.position)?;
 
-        if state == LineParserState::End && vertices.len() == 2 {
-            return Some(PolyLine{vertices, closed: false});
+        if c != code {
+            return None;
         }
+
+        self.position += 1;
+        Some(value)
+    }
+
+    fn take_f64(&mut self, code: &str) -> f64 {
+        self.take(code).and_then(|v| v.parse().ok()).unwrap_or(0.0)
     }
 
-    None
+    fn take_count(&mut self, code: &str) -> usize {
+        self.take(code).and_then(|v| v.parse().ok()).unwrap_or(0)
+    }
+
+    fn take_vertex(&mut self, x_code: &str, y_code: &str) -> Vertex {
+        Vertex {x: self.take_f64(x_code), y: self.take_f64(y_code)}
+    }
 }
 
-fn parse_polyline(iterator: &mut slice::Iter<DataPair>) -> Option<PolyLine> {
+// Units given here take the place of those in the header
+pub fn parse(dxf_contents: &str, units: Option<Unit>) -> Drawing {
+    convert(StrTokenizer::new(dxf_contents), units)
+}
+
+pub fn parse_reader(reader: impl BufRead, units: Option<Unit>) -> io::Result<Drawing> {
+    let (binary, reader) = detect(reader)?;
+
+    if binary {
+        parse_tokens(BinaryTokenizer::new(reader)?, units)
+    } else {
+        parse_tokens(ReaderTokenizer::new(reader), units)
+    }
+}
+
+fn parse_tokens<'a>(mut tokenizer: impl Tokenizer<'a>, units: Option<Unit>) -> io::Result<Drawing> {
+    let drawing = convert(&mut tokenizer, units);
+
+    match tokenizer.take_error() {
+        Some(e) => Err(e),
+        None => Ok(drawing)
+    }
+}
+
+fn parse_line(entity: &Entity) -> Option<PolyLine> {
+    Some(PolyLine {vertices: vec![entity.point(0)?, entity.point(1)?], closed: false})
+}
+
+fn parse_polyline(entity: &Entity, scale: f64) -> Option<PolyLine> {
     let mut vertices: Vec<VertexWithBulge> = vec![];
-    let mut state = PolylineParserState::Closed;
     let mut vert = PartialVertex::new();
-    let mut closed = false;
-    let mut vertices_found :u64 = 0;
 
-    while let Some(&pair) = iterator.next() {
-        match pair {
-            ("70", n) if state == PolylineParserState::Closed => {
-                closed = n == "1";
-                state = PolylineParserState::Vertex;
-            },
+    for (code, value) in entity.pairs() {
+        match code {
+            "10" => vert.x = Some(value.parse().ok()?),
+            "20" => vert.y = Some(value.parse().ok()?),
+            "42" => vertices.last_mut()?.bulge = value.parse().ok()?,
+            _ => continue
+        }
 
-            ("10", x) if state == PolylineParserState::Vertex => {
-                vert.x = Some(x.parse().unwrap());
-            },
+        if let Some(vertex) = Vertex::from_partial(&vert) {
+            vertices.push(VertexWithBulge {vertex, bulge: 0.0});
+            vert = PartialVertex::new();
+        }
+    }
 
-            ("20", y) if state == PolylineParserState::Vertex => {
-                vert.y = Some(y.parse().unwrap());
-            },
+    if vertices.len() < 2 {
+        return None;
+    }
 
-            ("42", b) if state == PolylineParserState::Vertex => {
-                vertices.last_mut().unwrap().bulge = b.parse().unwrap();
-            },
+    Some(PolyLine {vertices: explode_bulged_vertices(vertices, scale), closed: entity.flags("70") & 1 != 0})
+}
 
-            ("0", _) => {
-                state = PolylineParserState::Finish;
-            }
+fn parse_spline(entity: &Entity) -> Option<Spline> {
+    let mut control_points = vec![];
+    let mut vert = PartialVertex::new();
 
+    for (code, value) in entity.pairs() {
+        match code {
+            "10" => vert.x = Some(value.parse().ok()?),
+            "20" => vert.y = Some(value.parse().ok()?),
             _ => continue
         }
 
-        if let Some(v) = Vertex::from_partial(&vert) {
-            vertices.push(VertexWithBulge{ vertex: v, bulge: 0.0 });
+        if let Some(vertex) = Vertex::from_partial(&vert) {
+            control_points.push(vertex);
             vert = PartialVertex::new();
-            vertices_found += 1;
         }
+    }
 
-        if state == PolylineParserState::Finish {
-            if vertices_found > 1 {
-                return Some(PolyLine { vertices: explode_bulged_vertices(vertices), closed });
-            }
+    let expected: usize = entity.value("73")?.parse().ok()?;
 
-            return None;
-        }
+    if expected != control_points.len() || control_points.len() != 4 {
+        return None;
     }
 
-    None
+    Some(Spline {control_points})
 }
 
-fn parse_spline(iterator: &mut slice::Iter<DataPair>) -> Option<Spline> {
-    let mut vertices = vec![];
-    let mut state = SplineParserState::ControlPointCount;
-    let mut vert = PartialVertex::new();
-    let mut control_points_found :u64 = 0;
-    let mut control_points_expected :u64 = 0;
+fn parse_circle(entity: &Entity) -> Option<CircleEntity> {
+    let centre = entity.point(0)?;
+    let radius = entity.f64("40")?;
 
-    while let Some(&pair) = iterator.next() {
+    match (entity.f64("50"), entity.f64("51")) {
+        (Some(start), Some(mut end)) => {
+            if end < start {
+                end += 360.0;
+            }
+
+            Some(CircleEntity::Partial(Arc {
+                centre,
+                radius,
+                start_angle: start.to_radians(),
+                end_angle: end.to_radians()
+            }))
+        },
+
+        _ => Some(CircleEntity::FullCircle(Circle {centre, radius}))
+    }
+}
+
+fn parse_hatch(entity: &Entity, scale: f64) -> Option<Hatch> {
+    let mut reader = PairReader {
+        pairs: entity.pairs.clone(),
+        position: 0
+    };
+
+    let mut boundaries = vec![];
+    let mut pattern_lines = vec![];
+    let mut solid = false;
+    let mut style = IslandStyle::Normal;
+
+    while let Some(pair) = reader.peek() {
         match pair {
-            ("73", n) if state == SplineParserState::ControlPointCount => {
-                control_points_expected = n.parse().unwrap();
-                state = SplineParserState::ControlPoint;
+            ("70", n) => {
+                solid = n == "1";
+                reader.skip();
             },
 
-            ("10", x) if state == SplineParserState::ControlPoint => {
-                vert.x = Some(x.parse().unwrap());
+            ("75", n) => {
+                style = match n {
+                    "1" => IslandStyle::Outer,
+                    "2" => IslandStyle::Ignore,
+                    _ => IslandStyle::Normal
+                };
+                reader.skip();
             },
 
-            ("20", y) if state == SplineParserState::ControlPoint => {
-                vert.y = Some(y.parse().unwrap());
-            }
+            ("91", _) => {
+                for _ in 0..reader.take_count("91") {
+                    if let Some(boundary) = parse_hatch_boundary(&mut reader, scale) {
+                        boundaries.push(boundary);
+                    }
+                }
+            },
 
-            _ => continue
+            ("78", _) => {
+                for _ in 0..reader.take_count("78") {
+                    if let Some(line) = parse_pattern_line(&mut reader) {
+                        pattern_lines.push(line);
+                    }
+                }
+            },
+
+            _ => reader.skip()
         }
+    }
 
-        if let Some(v) = Vertex::from_partial(&vert) {
-            vertices.push(v);
-            vert = PartialVertex::new();
-            control_points_found += 1;
+    if boundaries.is_empty() {
+        return None;
+    }
+
+    Some(Hatch {
+        boundaries,
+        style,
+        pattern: if solid { HatchPattern::Solid } else { HatchPattern::Lines(pattern_lines) }
+    })
+}
+
+fn parse_hatch_boundary(reader: &mut PairReader, scale: f64) -> Option<PolyLine> {
+    let flags: u32 = reader.take("92")?.parse().ok()?;
+    let mut vertices = vec![];
+
+    if flags & 2 != 0 {
+        reader.take("72");
+        reader.take("73");
+
+        let mut bulged = vec![];
+
+        for _ in 0..reader.take_count("93") {
+            let vertex = reader.take_vertex("10", "20");
+            bulged.push(VertexWithBulge {vertex, bulge: reader.take_f64("42")});
         }
 
-        if state == SplineParserState::ControlPoint && control_points_expected == control_points_found {
-            if control_points_found == 4 {
-                return Some(Spline {control_points: vertices });
+        vertices = explode_bulged_vertices(bulged, scale);
+    } else {
+        for _ in 0..reader.take_count("93") {
+            let mut edge = match reader.take("72") {
+                Some("1") => parse_line_edge(reader),
+                Some("2") => parse_arc_edge(reader, scale),
+                Some("3") => parse_ellipse_edge(reader, scale),
+                Some("4") => parse_spline_edge(reader),
+                _ => break
+            };
+
+            if let (Some(last), Some(first)) = (vertices.last(), edge.vertices.first()) {
+                if first.distance_to(last) < 0.001 {
+                    edge.vertices.remove(0);
+                }
             }
 
-            return None;
+            vertices.append(&mut edge.vertices);
         }
     }
 
-    None
+    for _ in 0..reader.take_count("97") {
+        reader.take("330");
+    }
+
+    if vertices.len() < 3 {
+        return None;
+    }
+
+    Some(PolyLine {vertices, closed: true})
 }
 
-fn parse_circle(iterator: &mut slice::Iter<DataPair>) -> Option<CircleEntity> {
-    let mut state = CircleParserState::Centre;
-    let mut vert = PartialVertex::new();
-    let mut radius: Option<f64> = None;
-    let mut start_angle: Option<f64> = None;
-    let mut end_angle: Option<f64> = None;
+fn parse_line_edge(reader: &mut PairReader) -> PolyLine {
+    let start = reader.take_vertex("10", "20");
+    let end = reader.take_vertex("11", "21");
 
-    while let Some(&pair) = iterator.next() {
-        match pair {
-            ("10", x) if state == CircleParserState::Centre => {
-                vert.x = Some(x.parse().unwrap());
-            },
+    PolyLine {vertices: vec![start, end], closed: false}
+}
 
-            ("20", y) if state == CircleParserState::Centre => {
-                vert.y = Some(y.parse().unwrap());
-            },
+fn edge_angles(start: f64, end: f64, counter_clockwise: bool) -> (f64, f64) {
+    // Clockwise edges store their angles mirrored about the X axis
+    if counter_clockwise {
+        (start, if end <= start { end + 360.0 } else { end })
+    } else {
+        (-start, if -end >= -start { -end - 360.0 } else { -end })
+    }
+}
 
-            ("40", r) if state == CircleParserState::Radius => {
-                radius = Some(r.parse().unwrap());
-                state = CircleParserState::Arc;
-            },
+fn parse_arc_edge(reader: &mut PairReader, scale: f64) -> PolyLine {
+    let centre = reader.take_vertex("10", "20");
+    let radius = reader.take_f64("40");
+    let start = reader.take_f64("50");
+    let end = reader.take_f64("51");
+    let (start, end) = edge_angles(start, end, reader.take("73") != Some("0"));
+
+    Arc {
+        centre,
+        radius,
+        start_angle: start.to_radians(),
+        end_angle: end.to_radians()
+    }.into_polyline(scale)
+}
 
-            ("50", r) if state == CircleParserState::Arc => {
-                start_angle = Some(r.parse().unwrap());
-            },
+fn parse_ellipse_edge(reader: &mut PairReader, scale: f64) -> PolyLine {
+    let centre = reader.take_vertex("10", "20");
+    let major_axis = reader.take_vertex("11", "21");
+    let ratio = reader.take_f64("40");
+    let start = reader.take_f64("50");
+    let end = reader.take_f64("51");
+    let (start, end) = edge_angles(start, end, reader.take("73") != Some("0"));
+
+    let parameter = |angle: f64| {
+        let angle = angle.to_radians();
+        let (sin, cos) = angle.sin_cos();
+        let p = (sin / ratio).atan2(cos);
+
+        // Keep whole turns so the sweep direction and size survive the conversion
+        p + ((angle - p) / (2.0 * PI)).round() * 2.0 * PI
+    };
+
+    Ellipse {
+        centre,
+        major_axis,
+        ratio,
+        start_parameter: parameter(start),
+        end_parameter: parameter(end)
+    }.into_polyline(scale)
+}
 
-            ("51", r) if state == CircleParserState::Arc => {
-                end_angle = Some(r.parse().unwrap());
-            },
+fn parse_spline_edge(reader: &mut PairReader) -> PolyLine {
+    let degree = reader.take_count("94");
+    let rational = reader.take("73") == Some("1");
+    reader.take("74");
+    let knot_count = reader.take_count("95");
+    let control_point_count = reader.take_count("96");
 
-            ("0", _) => {
-                state = CircleParserState::Finish;
-            }
+    let knots = (0..knot_count).map(|_| reader.take_f64("40")).collect();
+    let mut control_points = vec![];
+    let mut weights = vec![];
+
+    for _ in 0..control_point_count {
+        control_points.push(reader.take_vertex("10", "20"));
+
+        if rational {
+            weights.push(reader.take("42").and_then(|w| w.parse().ok()).unwrap_or(1.0));
+        }
+    }
+
+    // Newer writers add fit data here, which shares its group code with the boundary's source object count
+    if reader.peek_code_after() != Some("330") {
+        for _ in 0..reader.take_count("97") {
+            reader.take_vertex("11", "21");
+        }
 
+        reader.take_vertex("12", "22");
+        reader.take_vertex("13", "23");
+    }
+
+    Nurbs {degree, knots, control_points, weights}.into_polyline()
+}
+
+fn parse_pattern_line(reader: &mut PairReader) -> Option<PatternLine> {
+    let angle = reader.take_f64("53");
+    let base = reader.take_vertex("43", "44");
+    let offset = reader.take_vertex("45", "46");
+
+    let dashes = (0..reader.take_count("79"))
+        .map(|_| reader.take_f64("49"))
+        .collect();
+
+    // Lines with no offset across them would all sit on top of each other
+    if offset.rotate(-angle.to_radians()).y.abs() <= 1e-9 {
+        return None;
+    }
+
+    Some(PatternLine {angle, base, offset, dashes})
+}
+
+fn parse_text(entity: &Entity) -> Option<Text> {
+    let mut insertion = PartialVertex::new();
+    let mut alignment = PartialVertex::new();
+    let mut text = Text {
+        insertion: Vertex {x: 0.0, y: 0.0},
+        alignment: None,
+        height: 1.0,
+        value: String::new(),
+        rotation: 0.0,
+        width_factor: 1.0,
+        oblique: 0.0,
+        horizontal_alignment: 0,
+        vertical_alignment: 0
+    };
+
+    for (code, value) in entity.pairs() {
+        match code {
+            "10" => insertion.x = value.parse().ok(),
+            "20" => insertion.y = value.parse().ok(),
+            "11" => alignment.x = value.parse().ok(),
+            "21" => alignment.y = value.parse().ok(),
+            "40" => text.height = value.parse().unwrap_or(text.height),
+            "1" => text.value = value.to_string(),
+            "50" => text.rotation = value.parse().unwrap_or(0.0),
+            "41" => text.width_factor = value.parse().unwrap_or(1.0),
+            "51" => text.oblique = value.parse().unwrap_or(0.0),
+            "72" => text.horizontal_alignment = value.parse().unwrap_or(0),
+            "73" => text.vertical_alignment = value.parse().unwrap_or(0),
             _ => continue
         }
+    }
+
+    text.insertion = Vertex::from_partial(&insertion)?;
+    text.alignment = Vertex::from_partial(&alignment);
 
-        if let (None, Some(_)) = (radius, Vertex::from_partial(&vert)) {
-            state = CircleParserState::Radius;
+    Some(text)
+}
+
+fn parse_mtext(entity: &Entity) -> Option<MText> {
+    let mut insertion = PartialVertex::new();
+    let mut direction = PartialVertex::new();
+    let mut extra_text = String::new();
+    let mut mtext = MText {
+        insertion: Vertex {x: 0.0, y: 0.0},
+        height: 1.0,
+        reference_width: 0.0,
+        attachment: 1,
+        value: String::new(),
+        rotation: 0.0,
+        line_spacing: 1.0
+    };
+
+    for (code, value) in entity.pairs() {
+        match code {
+            "10" => insertion.x = value.parse().ok(),
+            "20" => insertion.y = value.parse().ok(),
+            "11" => direction.x = value.parse().ok(),
+            "21" => direction.y = value.parse().ok(),
+            "40" => mtext.height = value.parse().unwrap_or(mtext.height),
+            "41" => mtext.reference_width = value.parse().unwrap_or(0.0),
+            "71" => mtext.attachment = value.parse().unwrap_or(1),
+            "1" => mtext.value = value.to_string(),
+            "3" => extra_text += value,
+            "50" => mtext.rotation = value.parse::<f64>().unwrap_or(0.0).to_radians(),
+            "44" => mtext.line_spacing = value.parse().unwrap_or(1.0),
+            _ => continue
         }
+    }
 
-        if state == CircleParserState::Finish {
-            return match (start_angle, end_angle) {
-                (Some(start), Some(mut end)) => {
+    // Long strings are split into 250 character chunks with the final chunk stored last
+    mtext.value = extra_text + mtext.value.as_str();
+    mtext.insertion = Vertex::from_partial(&insertion)?;
 
-                    if end < start {
-                        end += 360.0;
-                    }
+    if let Some(direction) = Vertex::from_partial(&direction) {
+        mtext.rotation = direction.y.atan2(direction.x);
+    }
 
-                    Some(CircleEntity::Partial(Arc{
-                        centre: Vertex::from_partial(&vert).unwrap(),
-                        radius: radius.unwrap(),
-                        start_angle: start.to_radians(),
-                        end_angle: end.to_radians()
-                    }))
-                },
-
-                _ => Some(CircleEntity::FullCircle(Circle{
-                    centre: Vertex::from_partial(&vert).unwrap(),
-                    radius: radius.unwrap()
-                }))
-            }
+    Some(mtext)
+}
+
+fn parse_point(entity: &Entity) -> Option<Vertex> {
+    entity.point(0)
+}
+
+fn parse_corners(entity: &Entity, solid: bool) -> Option<PolyLine> {
+    let mut vertices = (0..3)
+        .map(|i| entity.point(i))
+        .collect::<Option<Vec<Vertex>>>()?;
+
+    // A missing fourth corner repeats the third, which makes the shape a triangle
+    vertices.push(entity.point(3).unwrap_or(vertices[2]));
+
+    // SOLID and TRACE corners zigzag across the shape rather than running around it
+    if solid {
+        vertices.swap(2, 3);
+    }
+
+    vertices.dedup_by(|a, b| a.distance_to(b) < 0.001);
+
+    if vertices.len() > 2 && vertices[0].distance_to(&vertices[vertices.len() - 1]) < 0.001 {
+        vertices.pop();
+    }
+
+    Some(PolyLine {vertices, closed: true})
+}
+
+fn parse_r12_polyline(polyline: &Entity, vertices: &[Entity], scale: f64) -> Option<PolyLine> {
+    let flags = polyline.flags("70");
+
+    // Polygon and polyface meshes have no single outline to follow
+    if flags & (16 | 64) != 0 {
+        return None;
+    }
+
+    let vertices: Vec<VertexWithBulge> = vertices.iter()
+        .filter(|vertex| vertex.flags("70") & (16 | 128) == 0)
+        .map(|vertex| Some(VertexWithBulge {vertex: vertex.point(0)?, bulge: vertex.f64("42").unwrap_or(0.0)}))
+        .collect::<Option<Vec<VertexWithBulge>>>()?;
+
+    if vertices.len() < 2 {
+        return None;
+    }
+
+    Some(PolyLine {vertices: explode_bulged_vertices(vertices, scale), closed: flags & 1 != 0})
+}
+
+fn parse_header(header: &Entity) -> Option<Unit> {
+    let mut insunits = None;
+    let mut measurement = None;
+    let mut variables = header.pairs();
+
+    while let Some((code, name)) = variables.next() {
+        if code != "9" {
+            continue;
+        }
+
+        match name {
+            "$INSUNITS" => insunits = variables.next().and_then(|(_, value)| value.parse().ok()).and_then(Unit::from_insunits),
+            "$MEASUREMENT" => measurement = variables.next().and_then(|(_, value)| value.parse().ok()).and_then(Unit::from_measurement),
+            _ => continue
         }
     }
 
-    None
+    // $MEASUREMENT only decides between imperial and metric when the drawing is unitless
+    insunits.or(measurement)
 }
 
-fn convert(pairs: &Vec<DataPair>) -> Vec<PolyLine> {
-    let mut lines = vec![];
-    let mut iterator = pairs.iter();
+fn convert<'a>(iterator: impl Iterator<Item = DataPair<'a>>, units: Option<Unit>) -> Drawing {
+    let mut pairs = iterator.peekable();
+    let mut drawing = Drawing {lines: vec![], hatches: vec![], points: vec![], units};
+    let mut blocks: HashMap<String, Block> = HashMap::new();
+    let mut block: Option<(String, Block)> = None;
 
-    while let Some(&pair) = iterator.next() {
-        match pair {
-            ("100", "AcDbLine") => {
-                if let Some(line) = parse_line(&mut iterator) {
-                    lines.push(line);
+    // Files that skip the section markers are treated as a bare list of entities
+    let mut section = String::from("ENTITIES");
+
+    while let Some((code, kind)) = pairs.next() {
+        if code != "0" {
+            continue;
+        }
+
+        let entity = Entity::read(&mut pairs);
+        let mut vertices = vec![];
+
+        if kind == "POLYLINE" {
+            while pairs.next_if(|(code, value)| code == "0" && value == "VERTEX").is_some() {
+                vertices.push(Entity::read(&mut pairs));
+            }
+        }
+
+        match (section.as_str(), kind.as_ref()) {
+            (_, "SECTION") => {
+                section = entity.value("2").unwrap_or_default().to_string();
+
+                if section == "HEADER" {
+                    drawing.units = units.or(parse_header(&entity));
                 }
             },
 
-            ("100", "AcDbPolyline") => {
-                if let Some(line) = parse_polyline(&mut iterator) {
-                    lines.push(line);
-                }
+            (_, "ENDSEC") => section.clear(),
+
+            ("BLOCKS", "BLOCK") => {
+                let name = entity.value("2").unwrap_or_default().to_string();
+                let base = entity.point(0).unwrap_or(Vertex {x: 0.0, y: 0.0});
+
+                block = Some((name, Block {base, entities: vec![]}));
             },
 
-            ("100", "AcDbSpline") => {
-                if let Some(spline) = parse_spline(&mut iterator) {
-                    lines.push(spline.into_polyline());
+            ("BLOCKS", "ENDBLK") => {
+                if let Some((name, definition)) = block.take() {
+                    blocks.insert(name, definition);
                 }
             },
 
-            ("100", "AcDbCircle") => {
-                match parse_circle(&mut iterator) {
-                    Some(CircleEntity::FullCircle(circle)) => {
-                        lines.push(circle.into_polyline());
-                    },
+            ("BLOCKS", _) => {
+                if let Some((_, definition)) = block.as_mut() {
+                    definition.entities.push((kind, entity, vertices));
+                }
+            },
 
-                    Some(CircleEntity::Partial(arc)) => {
-                        lines.push(arc.into_polyline());
-                    },
+            // Only model space geometry is cut, so paper space layouts are skipped
+            ("ENTITIES", _) if entity.flags("67") != 1 => {
+                // Curves are divided as finely as they would be if the drawing were in millimetres, since it is scaled later
+                let scale = drawing.units.map_or(1.0, |unit| unit.millimetres());
 
-                    _ => ()
-                }
+                add_entity(&mut drawing, &kind, &entity, &vertices, scale, &blocks, 0);
             },
 
-            _ => continue
+            _ => ()
         }
     }
 
-    lines
+    drawing
+}
+
+fn add_entity(drawing: &mut Drawing, kind: &str, entity: &Entity, vertices: &[Entity], scale: f64, blocks: &HashMap<String, Block>, depth: usize) {
+    match kind {
+        "LINE" => drawing.lines.extend(parse_line(entity)),
+        "LWPOLYLINE" => drawing.lines.extend(parse_polyline(entity, scale)),
+        "POLYLINE" => drawing.lines.extend(parse_r12_polyline(entity, vertices, scale)),
+        "SPLINE" => drawing.lines.extend(parse_spline(entity).map(|spline| spline.into_polyline(scale))),
+
+        "CIRCLE" | "ARC" => match parse_circle(entity) {
+            Some(CircleEntity::FullCircle(circle)) => drawing.lines.push(circle.into_polyline(scale)),
+            Some(CircleEntity::Partial(arc)) => drawing.lines.push(arc.into_polyline(scale)),
+            None => ()
+        },
+
+        "TEXT" => drawing.lines.extend(parse_text(entity).into_iter().flat_map(Text::into_polylines)),
+        "MTEXT" => drawing.lines.extend(parse_mtext(entity).into_iter().flat_map(MText::into_polylines)),
+        "SOLID" | "TRACE" => drawing.lines.extend(parse_corners(entity, true)),
+        "3DFACE" => drawing.lines.extend(parse_corners(entity, false)),
+        "POINT" => drawing.points.extend(parse_point(entity)),
+        "HATCH" => drawing.hatches.extend(parse_hatch(entity, scale)),
+
+        // Blocks that end up inserting themselves are cut off rather than followed forever
+        "INSERT" if depth < MAX_INSERT_DEPTH => insert_block(drawing, entity, scale, blocks, depth),
+        _ => ()
+    }
+}
+
+fn insert_block(drawing: &mut Drawing, entity: &Entity, scale: f64, blocks: &HashMap<String, Block>, depth: usize) {
+    let Some(block) = entity.value("2").and_then(|name| blocks.get(name)) else {
+        return;
+    };
+
+    let insertion = entity.point(0).unwrap_or(Vertex {x: 0.0, y: 0.0});
+    let (scale_x, scale_y) = (entity.f64("41").unwrap_or(1.0), entity.f64("42").unwrap_or(1.0));
+    let rotation = entity.f64("50").unwrap_or(0.0);
+    let (columns, rows) = (entity.flags("70").max(1), entity.flags("71").max(1));
+    let (column_spacing, row_spacing) = (entity.f64("44").unwrap_or(0.0), entity.f64("45").unwrap_or(0.0));
+
+    let mut geometry = Drawing {lines: vec![], hatches: vec![], points: vec![], units: None};
+    let block_scale = scale * scale_x.abs().max(scale_y.abs());
+
+    for (kind, block_entity, vertices) in block.entities.iter() {
+        add_entity(&mut geometry, kind, block_entity, vertices, block_scale, blocks, depth + 1);
+    }
+
+    // Arrays repeat the block along the rotated column and row directions
+    for row in 0..rows {
+        for column in 0..columns {
+            let step = Vertex {x: column as f64 * column_spacing, y: row as f64 * row_spacing}.rotate(rotation.to_radians());
+
+            let placement = Placement {
+                base: block.base,
+                insertion: insertion + step,
+                scale: (scale_x, scale_y),
+                rotation: rotation.to_radians()
+            };
+
+            let apply = |point: Vertex| placement.apply(point);
+
+            drawing.lines.extend(geometry.lines.iter().map(|line| PolyLine {
+                vertices: line.vertices.iter().copied().map(apply).collect(),
+                closed: line.closed
+            }));
+
+            drawing.hatches.extend(geometry.hatches.iter().map(|hatch| hatch.transform(apply, rotation, scale_x.abs())));
+            drawing.points.extend(geometry.points.iter().copied().map(apply));
+        }
+    }
 }
@@ -0,0 +1,143 @@
+use crate::dxf::vertex::Vertex;
+use crate::gcode::{write_blocks, Block, Word};
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum PointAction {
+    #[default]
+    Ignore,
+    Pulse(Pulse),
+    Drill(Drill)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pulse {
+    pub dwell: f64,
+    pub power: u8
+}
+
+// Depth, retract height and peck are in millimetres and the feed in mm/min, whatever the output units.
+// Without a spindle speed the spindle is left for the post-processor or operator to start
+#[derive(Debug, Clone, PartialEq)]
+pub struct Drill {
+    pub depth: f64,
+    pub retract: f64,
+    pub peck: Option<f64>,
+    pub feed: f64,
+    pub canned: bool,
+    pub spindle_speed: Option<f64>
+}
+
+impl Pulse {
+    pub fn new(dwell: f64) -> Pulse {
+        Pulse {
+            dwell,
+            power: 255
+        }
+    }
+}
+
+impl Drill {
+    pub fn new(depth: f64) -> Drill {
+        Drill {
+            depth,
+            retract: 2.0,
+            peck: None,
+            feed: 100.0,
+            canned: true,
+            spindle_speed: None
+        }
+    }
+}
+
+pub fn points_to_gcode(points: &[Vertex], action: &PointAction) -> String {
+    write_blocks(&point_blocks(points, action))
+}
+
+pub(crate) fn point_blocks(points: &[Vertex], action: &PointAction) -> Vec<Block> {
+    match action {
+        PointAction::Ignore => vec![],
+
+        PointAction::Pulse(pulse) => points.iter()
+            .flat_map(|point| pulse_blocks(point, pulse))
+            .collect(),
+
+        PointAction::Drill(_) if points.is_empty() => vec![],
+
+        PointAction::Drill(drill) => {
+            let mut blocks = vec![Block::new(vec![Word::code('G', 0), Word::coordinate('Z', drill.retract)])];
+
+            if let Some(speed) = drill.spindle_speed {
+                blocks.push(Block::new(vec![Word::code('M', 3), Word::number('S', speed)]));
+            }
+
+            for point in points {
+                blocks.extend(if drill.canned { vec![canned_cycle(point, drill)] } else { expanded_cycle(point, drill) });
+            }
+
+            if drill.canned {
+                blocks.push(Block::new(vec![Word::code('G', 80)]));
+            }
+
+            if drill.spindle_speed.is_some() {
+                blocks.push(Block::new(vec![Word::code('M', 5)]));
+            }
+
+            // Cuts that follow are written without a motion code, so they need feed moves back
+            blocks.push(Block::new(vec![Word::code('G', 1)]));
+
+            blocks
+        }
+    }
+}
+
+fn pulse_blocks(point: &Vertex, pulse: &Pulse) -> Vec<Block> {
+    vec![
+        Block::travel(point.x, point.y),
+        Block::new(vec![Word::code('M', 3), Word::number('S', pulse.power as f64)]),
+        Block::new(vec![Word::code('G', 4), Word::new('P', pulse.dwell, 3)]),
+        Block::new(vec![Word::code('M', 5)])
+    ]
+}
+
+fn canned_cycle(point: &Vertex, drill: &Drill) -> Block {
+    let mut words = vec![
+        Word::coordinate('X', point.x),
+        Word::coordinate('Y', point.y),
+        Word::coordinate('Z', -drill.depth),
+        Word::coordinate('R', drill.retract)
+    ];
+
+    match drill.peck {
+        Some(peck) if peck > 0.0 => {
+            words.insert(0, Word::code('G', 83));
+            words.push(Word::coordinate('Q', peck));
+        },
+
+        _ => words.insert(0, Word::code('G', 81))
+    }
+
+    words.push(Word::number('F', drill.feed));
+
+    Block::new(words)
+}
+
+fn expanded_cycle(point: &Vertex, drill: &Drill) -> Vec<Block> {
+    let mut blocks = vec![Block::new(vec![Word::code('G', 0), Word::coordinate('X', point.x), Word::coordinate('Y', point.y)])];
+
+    let peck = match drill.peck {
+        Some(peck) if peck > 0.0 => peck,
+        _ => drill.depth
+    };
+
+    let mut depth = 0.0;
+
+    while depth < drill.depth {
+        depth = (depth + peck).min(drill.depth);
+
+        // Each peck clears the chips by retracting fully before plunging deeper
+        blocks.push(Block::new(vec![Word::code('G', 1), Word::coordinate('Z', -depth), Word::number('F', drill.feed)]));
+        blocks.push(Block::new(vec![Word::code('G', 0), Word::coordinate('Z', drill.retract)]));
+    }
+
+    blocks
+}
@@ -21,6 +21,20 @@ impl PolyLine {
         PolyLine { vertices, closed: false }
     }
 
+    pub fn scale(&self, factor: f64) -> PolyLine {
+        PolyLine {
+            vertices: self.vertices.iter().map(|v| v.scale(factor)).collect(),
+            closed: self.closed
+        }
+    }
+
+    pub fn is_closed(&self) -> bool {
+        match (self.vertices.first(), self.vertices.last()) {
+            (Some(a), Some(b)) if self.vertices.len() > 2 => self.closed || a.distance_to(b) < 0.001,
+            _ => false
+        }
+    }
+
     pub fn continues_from(&self, previous: &PolyLine) -> bool {
         if let (Some(a), Some(b)) = (self.vertices.first(), previous.vertices.last()) {
             return a.distance_to(b) < 0.001;
@@ -41,11 +55,10 @@ impl PolyLine {
 pub fn glue_polylines(lines: Vec<PolyLine>) -> Vec<PolyLine> {
     let mut new_lines = vec![];
 
-    let mut iterator = lines.iter();
     let mut last_line = None;
     let mut tmp: PolyLine;
 
-    while let Some(line) = iterator.next() {
+    for line in lines.iter() {
         match (last_line, line) {
             (Some(l), r) if r.continues_from(l) => {
                 tmp = PolyLine::from_sections(l, r);
@@ -0,0 +1,279 @@
+use crate::dxf::font::{glyph, CAP_HEIGHT, DESCENT, LETTER_SPACING};
+use crate::dxf::polyline::PolyLine;
+use crate::dxf::vertex::Vertex;
+
+const MTEXT_LINE_SPACING: f64 = 5.0 / 3.0;
+
+#[derive(Debug, Clone)]
+pub struct Text {
+    pub insertion: Vertex,
+    pub alignment: Option<Vertex>,
+    pub height: f64,
+    pub value: String,
+    pub rotation: f64,
+    pub width_factor: f64,
+    pub oblique: f64,
+    pub horizontal_alignment: u8,
+    pub vertical_alignment: u8
+}
+
+#[derive(Debug, Clone)]
+pub struct MText {
+    pub insertion: Vertex,
+    pub height: f64,
+    pub reference_width: f64,
+    pub attachment: u8,
+    pub value: String,
+    pub rotation: f64,
+    pub line_spacing: f64
+}
+
+struct Placement {
+    origin: Vertex,
+    rotation: f64,
+    x_scale: f64,
+    y_scale: f64,
+    shear: f64
+}
+
+impl Placement {
+    fn place(&self, u: f64, v: f64) -> Vertex {
+        let local = Vertex {
+            x: u * self.x_scale + v * self.y_scale * self.shear,
+            y: v * self.y_scale
+        };
+
+        self.origin + local.rotate(self.rotation)
+    }
+}
+
+impl Text {
+    pub fn into_polylines(self) -> Vec<PolyLine> {
+        let value = decode_special_codes(&self.value);
+        let width = line_width(&value);
+
+        let scale = self.height / CAP_HEIGHT;
+        let mut placement = Placement {
+            origin: self.insertion,
+            rotation: self.rotation.to_radians(),
+            x_scale: scale * self.width_factor,
+            y_scale: scale,
+            shear: self.oblique.to_radians().tan()
+        };
+
+        let (offset_x, offset_y) = match (self.horizontal_alignment, self.vertical_alignment, self.alignment) {
+            // Aligned and fit text is stretched between the two alignment points
+            (h @ (3 | 5), _, Some(end)) if width > 0.0 => {
+                let length = self.insertion.distance_to(&end);
+
+                placement.rotation = self.insertion.angle_to(&end);
+                placement.x_scale = length / width;
+
+                if h == 3 {
+                    placement.y_scale = placement.x_scale / self.width_factor;
+                }
+
+                (0.0, 0.0)
+            },
+
+            (0, 0, _) => (0.0, 0.0),
+
+            (h, v, alignment) => {
+                placement.origin = alignment.unwrap_or(self.insertion);
+
+                let offset_x = match h {
+                    1 | 4 => width / 2.0,
+                    2 => width,
+                    _ => 0.0
+                };
+
+                let offset_y = match (h, v) {
+                    (4, _) | (_, 2) => CAP_HEIGHT / 2.0,
+                    (_, 1) => -DESCENT,
+                    (_, 3) => CAP_HEIGHT,
+                    _ => 0.0
+                };
+
+                (offset_x, offset_y)
+            }
+        };
+
+        stroke_line(&value, &placement, -offset_x, -offset_y)
+    }
+}
+
+impl MText {
+    pub fn into_polylines(self) -> Vec<PolyLine> {
+        let scale = self.height / CAP_HEIGHT;
+        let placement = Placement {
+            origin: self.insertion,
+            rotation: self.rotation,
+            x_scale: scale,
+            y_scale: scale,
+            shear: 0.0
+        };
+
+        let lines: Vec<String> = mtext_paragraphs(&self.value)
+            .into_iter()
+            .flat_map(|paragraph| wrap(&paragraph, self.reference_width / scale))
+            .collect();
+
+        let spacing = CAP_HEIGHT * MTEXT_LINE_SPACING * self.line_spacing;
+        let block_height = CAP_HEIGHT + (lines.len().max(1) - 1) as f64 * spacing;
+
+        let attachment = self.attachment.clamp(1, 9) - 1;
+
+        let top = match attachment / 3 {
+            0 => 0.0,
+            1 => block_height / 2.0,
+            _ => block_height
+        };
+
+        lines.iter()
+            .enumerate()
+            .flat_map(|(i, line)| {
+                let width = line_width(line);
+
+                let offset_x = match attachment % 3 {
+                    0 => 0.0,
+                    1 => -width / 2.0,
+                    _ => -width
+                };
+
+                stroke_line(line, &placement, offset_x, top - CAP_HEIGHT - i as f64 * spacing)
+            })
+            .collect()
+    }
+}
+
+fn line_width(line: &str) -> f64 {
+    match line.chars().map(|c| glyph(c).advance()).sum::<f64>() {
+        w if w > 0.0 => w - LETTER_SPACING,
+        _ => 0.0
+    }
+}
+
+fn stroke_line(line: &str, placement: &Placement, offset_x: f64, offset_y: f64) -> Vec<PolyLine> {
+    let mut polylines = vec![];
+    let mut cursor = offset_x;
+
+    for character in line.chars() {
+        let glyph = glyph(character);
+
+        for stroke in glyph.strokes {
+            polylines.push(PolyLine {
+                vertices: stroke.iter()
+                    .map(|&(u, v)| placement.place(cursor + u as f64, offset_y + v as f64))
+                    .collect(),
+                closed: false
+            });
+        }
+
+        cursor += glyph.advance();
+    }
+
+    polylines
+}
+
+fn wrap(paragraph: &str, width: f64) -> Vec<String> {
+    if width <= 0.0 {
+        return vec![paragraph.to_string()];
+    }
+
+    let mut lines = vec![];
+    let mut line = String::new();
+
+    for word in paragraph.split(' ') {
+        let candidate = if line.is_empty() { word.to_string() } else { format!("{} {}", line, word) };
+
+        if line_width(&candidate) > width && !line.is_empty() {
+            lines.push(line);
+            line = word.to_string();
+        } else {
+            line = candidate;
+        }
+    }
+
+    lines.push(line);
+    lines
+}
+
+fn decode_special_codes(value: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = value;
+
+    while let Some(index) = rest.find("%%") {
+        decoded += &rest[..index];
+        rest = &rest[index + 2..];
+
+        let code = rest.chars().next().map(|c| c.to_ascii_lowercase());
+
+        match code {
+            Some('d') => decoded.push('°'),
+            Some('p') => decoded.push('±'),
+            Some('c') => decoded.push('Ø'),
+            Some('%') => decoded.push('%'),
+            Some('u' | 'o' | 'k') => (),
+
+            Some(c) if c.is_ascii_digit() => {
+                let digits: String = rest.chars().take(3).take_while(|c| c.is_ascii_digit()).collect();
+                decoded.extend(digits.parse::<u32>().ok().and_then(char::from_u32));
+                rest = &rest[digits.len() - 1..];
+            },
+
+            _ => {
+                decoded += "%%";
+                continue;
+            }
+        }
+
+        rest = &rest[1..];
+    }
+
+    decoded + rest
+}
+
+fn mtext_paragraphs(value: &str) -> Vec<String> {
+    let mut paragraphs = vec![String::new()];
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let paragraph = paragraphs.last_mut().unwrap();
+
+        match c {
+            '\\' => match chars.next() {
+                Some('P') => paragraphs.push(String::new()),
+                Some('~') => paragraph.push(' '),
+                Some(c @ ('\\' | '{' | '}')) => paragraph.push(c),
+
+                // Stacked fractions are written inline
+                Some('S') => {
+                    let stack: String = chars.by_ref().take_while(|c| *c != ';').collect();
+                    paragraph.push_str(&stack.replace(['^', '#'], "/"));
+                },
+
+                Some('U') if chars.peek() == Some(&'+') => {
+                    chars.next();
+                    let hex: String = chars.by_ref().take(4).collect();
+                    paragraph.extend(u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32));
+                },
+
+                Some('f' | 'F' | 'H' | 'C' | 'c' | 'T' | 'Q' | 'W' | 'A' | 'p') => {
+                    chars.by_ref().take_while(|c| *c != ';').for_each(drop);
+                },
+
+                Some('L' | 'l' | 'O' | 'o' | 'K' | 'k') => (),
+
+                Some(other) => paragraph.push(other),
+
+                None => ()
+            },
+
+            '{' | '}' => (),
+
+            c => paragraph.push(c)
+        }
+    }
+
+    paragraphs
+}
@@ -0,0 +1,103 @@
+use std::borrow::Cow;
+use std::io::{self, BufRead};
+use std::str::Lines;
+use std::sync::OnceLock;
+
+const MAX_GROUP_CODE: u16 = 1071;
+
+static GROUP_CODES: OnceLock<Vec<String>> = OnceLock::new();
+
+pub type DataPair<'a> = (Cow<'a, str>, Cow<'a, str>);
+
+pub trait Tokenizer<'a>: Iterator<Item = DataPair<'a>> {
+    fn take_error(&mut self) -> Option<io::Error> {
+        None
+    }
+}
+
+pub struct StrTokenizer<'a> {
+    lines: Lines<'a>
+}
+
+pub struct ReaderTokenizer<R: BufRead> {
+    reader: R,
+    buffer: Vec<u8>,
+    error: Option<io::Error>
+}
+
+impl<'a> StrTokenizer<'a> {
+    pub fn new(contents: &'a str) -> StrTokenizer<'a> {
+        StrTokenizer {lines: contents.lines()}
+    }
+}
+
+impl<'a> Iterator for StrTokenizer<'a> {
+    type Item = DataPair<'a>;
+
+    fn next(&mut self) -> Option<DataPair<'a>> {
+        let code = self.lines.next()?;
+        let value = self.lines.next()?;
+
+        Some((Cow::Borrowed(code.trim()), Cow::Borrowed(value.trim())))
+    }
+}
+
+impl<'a> Tokenizer<'a> for StrTokenizer<'a> {}
+
+// Codes are shared from one table, so reading a pair from a stream only allocates its value
+pub fn group_code(code: u16) -> Cow<'static, str> {
+    let codes = GROUP_CODES.get_or_init(|| (0..=MAX_GROUP_CODE).map(|code| code.to_string()).collect());
+
+    match codes.get(code as usize) {
+        Some(text) => Cow::Borrowed(text.as_str()),
+        None => Cow::Owned(code.to_string())
+    }
+}
+
+impl<R: BufRead> ReaderTokenizer<R> {
+    pub fn new(reader: R) -> ReaderTokenizer<R> {
+        ReaderTokenizer {
+            reader,
+            buffer: vec![],
+            error: None
+        }
+    }
+
+    // Lines are read into the same buffer each time, and trimming also drops the carriage return from CRLF
+    fn read_line(&mut self) -> Option<&[u8]> {
+        self.buffer.clear();
+
+        match self.reader.read_until(b'\n', &mut self.buffer) {
+            Ok(0) => None,
+            Ok(_) => Some(self.buffer.trim_ascii()),
+
+            Err(e) => {
+                self.error = Some(e);
+                None
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for ReaderTokenizer<R> {
+    type Item = DataPair<'static>;
+
+    fn next(&mut self) -> Option<DataPair<'static>> {
+        let line = self.read_line()?;
+
+        let code = match std::str::from_utf8(line).ok().and_then(|code| code.parse().ok()) {
+            Some(code) => group_code(code),
+            None => Cow::Owned(String::from_utf8_lossy(line).into_owned())
+        };
+
+        let value = String::from_utf8_lossy(self.read_line()?).into_owned();
+
+        Some((code, Cow::Owned(value)))
+    }
+}
+
+impl<R: BufRead> Tokenizer<'static> for ReaderTokenizer<R> {
+    fn take_error(&mut self) -> Option<io::Error> {
+        self.error.take()
+    }
+}
@@ -0,0 +1,120 @@
+use crate::gcode::{Block, Word};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Unit {
+    Inches,
+    Feet,
+    Yards,
+    Miles,
+    Mils,
+    Microinches,
+    Millimetres,
+    Centimetres,
+    Decimetres,
+    Metres,
+    Kilometres,
+    Microns,
+    Nanometres,
+    Angstroms
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputUnits {
+    Millimetres,
+    Inches
+}
+
+impl Unit {
+    pub fn from_insunits(code: u8) -> Option<Unit> {
+        match code {
+            1 => Some(Unit::Inches),
+            2 => Some(Unit::Feet),
+            3 => Some(Unit::Miles),
+            4 => Some(Unit::Millimetres),
+            5 => Some(Unit::Centimetres),
+            6 => Some(Unit::Metres),
+            7 => Some(Unit::Kilometres),
+            8 => Some(Unit::Microinches),
+            9 => Some(Unit::Mils),
+            10 => Some(Unit::Yards),
+            11 => Some(Unit::Angstroms),
+            12 => Some(Unit::Nanometres),
+            13 => Some(Unit::Microns),
+            14 => Some(Unit::Decimetres),
+            _ => None
+        }
+    }
+
+    pub fn from_measurement(code: u8) -> Option<Unit> {
+        match code {
+            0 => Some(Unit::Inches),
+            1 => Some(Unit::Millimetres),
+            _ => None
+        }
+    }
+
+    pub fn millimetres(&self) -> f64 {
+        match self {
+            Unit::Inches => 25.4,
+            Unit::Feet => 304.8,
+            Unit::Yards => 914.4,
+            Unit::Miles => 1_609_344.0,
+            Unit::Mils => 0.0254,
+            Unit::Microinches => 0.0000254,
+            Unit::Millimetres => 1.0,
+            Unit::Centimetres => 10.0,
+            Unit::Decimetres => 100.0,
+            Unit::Metres => 1000.0,
+            Unit::Kilometres => 1_000_000.0,
+            Unit::Microns => 0.001,
+            Unit::Nanometres => 0.000001,
+            Unit::Angstroms => 0.0000001
+        }
+    }
+}
+
+impl OutputUnits {
+    pub fn millimetres(&self) -> f64 {
+        match self {
+            OutputUnits::Millimetres => 1.0,
+            OutputUnits::Inches => 25.4
+        }
+    }
+
+    // Drawings are scaled to the output units before any blocks are made, but feeds, drilling depths and
+    // retract heights are still in millimetres. Coordinates are written to a hundredth of a millimetre,
+    // which in inches needs two more decimals
+    pub(crate) fn convert(&self, blocks: &mut [Block]) {
+        if *self == OutputUnits::Millimetres {
+            return;
+        }
+
+        for word in blocks.iter_mut().flat_map(|block| block.words.iter_mut()) {
+            match word.letter {
+                'F' => word.value = (word.value / self.millimetres() * 100.0).round() / 100.0,
+                'Z' | 'R' | 'Q' => word.value /= self.millimetres(),
+                _ => {}
+            }
+
+            if word.decimals == Some(2) {
+                word.decimals = Some(4);
+            }
+        }
+    }
+
+    // For blocks generated in millimetres throughout, such as raster engraving, rather than from a scaled drawing
+    pub(crate) fn convert_millimetres(&self, blocks: &mut [Block]) {
+        for word in blocks.iter_mut().flat_map(|block| block.words.iter_mut()).filter(|word| matches!(word.letter, 'X' | 'Y')) {
+            word.value /= self.millimetres();
+        }
+
+        self.convert(blocks);
+    }
+
+    pub fn command(&self) -> Block {
+        match self {
+            OutputUnits::Millimetres => Block::new(vec![Word::code('G', 21)]),
+            OutputUnits::Inches => Block::new(vec![Word::code('G', 20)])
+        }
+    }
+}
@@ -74,6 +74,22 @@ impl Vertex {
         }
     }
 
+    pub fn scale(&self, factor: f64) -> Vertex {
+        Vertex {
+            x: self.x * factor,
+            y: self.y * factor
+        }
+    }
+
+    pub fn rotate(&self, angle: f64) -> Vertex {
+        let (sin, cos) = angle.sin_cos();
+
+        Vertex {
+            x: self.x * cos - self.y * sin,
+            y: self.x * sin + self.y * cos
+        }
+    }
+
     pub fn angle_to(&self, other: &Vertex) -> f64 {
         (other.y - self.y).atan2(other.x - self.x)
     }
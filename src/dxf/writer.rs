@@ -0,0 +1,104 @@
+use crate::dxf::polyline::PolyLine;
+use crate::dxf::units::OutputUnits;
+use crate::dxf::vertex::Vertex;
+
+pub struct Layer<'a> {
+    pub name: &'a str,
+    pub colour: u8,
+    pub lines: &'a [PolyLine],
+    pub points: &'a [Vertex]
+}
+
+struct Writer {
+    output: String
+}
+
+impl Writer {
+    fn group(&mut self, code: u16, value: impl std::fmt::Display) {
+        self.output += format!("{:>3}\n{}\n", code, value).as_str();
+    }
+}
+
+pub fn write_dxf(layers: &[Layer], units: OutputUnits) -> String {
+    let mut writer = Writer {output: String::new()};
+
+    writer.group(0, "SECTION");
+    writer.group(2, "HEADER");
+    writer.group(9, "$ACADVER");
+    writer.group(1, "AC1009");
+    writer.group(9, "$MEASUREMENT");
+    writer.group(70, match units {
+        OutputUnits::Millimetres => 1,
+        OutputUnits::Inches => 0
+    });
+    writer.group(0, "ENDSEC");
+
+    // R12 needs no handles or subclass markers, but the linetype layers refer to still has to be defined
+    writer.group(0, "SECTION");
+    writer.group(2, "TABLES");
+    writer.group(0, "TABLE");
+    writer.group(2, "LTYPE");
+    writer.group(70, 1);
+    writer.group(0, "LTYPE");
+    writer.group(2, "CONTINUOUS");
+    writer.group(70, 0);
+    writer.group(3, "Solid line");
+    writer.group(72, 65);
+    writer.group(73, 0);
+    writer.group(40, 0.0);
+    writer.group(0, "ENDTAB");
+
+    writer.group(0, "TABLE");
+    writer.group(2, "LAYER");
+    writer.group(70, layers.len());
+
+    for layer in layers {
+        writer.group(0, "LAYER");
+        writer.group(2, layer.name);
+        writer.group(70, 0);
+        writer.group(62, layer.colour);
+        writer.group(6, "CONTINUOUS");
+    }
+
+    writer.group(0, "ENDTAB");
+    writer.group(0, "ENDSEC");
+
+    writer.group(0, "SECTION");
+    writer.group(2, "ENTITIES");
+
+    for layer in layers {
+        for line in layer.lines.iter().filter(|line| !line.vertices.is_empty()) {
+            writer.group(0, "POLYLINE");
+            writer.group(8, layer.name);
+            writer.group(66, 1);
+            writer.group(10, 0.0);
+            writer.group(20, 0.0);
+            writer.group(30, 0.0);
+            writer.group(70, if line.closed { 1 } else { 0 });
+
+            for vertex in line.vertices.iter() {
+                writer.group(0, "VERTEX");
+                writer.group(8, layer.name);
+                writer.group(10, vertex.x);
+                writer.group(20, vertex.y);
+                writer.group(30, 0.0);
+            }
+
+            writer.group(0, "SEQEND");
+            writer.group(8, layer.name);
+        }
+
+        for point in layer.points {
+            writer.group(0, "POINT");
+            writer.group(8, layer.name);
+            writer.group(10, point.x);
+            writer.group(20, point.y);
+            writer.group(30, 0.0);
+        }
+    }
+
+    writer.group(0, "ENDSEC");
+    writer.group(0, "EOF");
+
+    writer.output
+}
@@ -0,0 +1,159 @@
+use crate::gcode::{Motion, Move};
+
+const SECONDS_PER_MINUTE: f64 = 60.0;
+
+#[derive(Debug, Clone)]
+pub struct EstimateOptions {
+    pub max_feed: f64,
+    pub acceleration: f64,
+    pub junction_deviation: f64
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Estimate {
+    pub cut_length: f64,
+    pub travel_length: f64,
+    pub laser_events: usize,
+    pub duration: f64
+}
+
+// A straight piece of motion with speeds in mm/s
+struct Segment {
+    length: f64,
+    direction: (f64, f64, f64),
+    speed: f64,
+    entry: f64,
+    max_entry: f64
+}
+
+impl Default for EstimateOptions {
+    fn default() -> EstimateOptions {
+        EstimateOptions {
+            max_feed: 6000.0,
+            acceleration: 500.0,
+            junction_deviation: 0.01
+        }
+    }
+}
+
+pub(crate) fn estimate_moves(moves: &[Move], options: &EstimateOptions) -> Estimate {
+    let mut estimate = Estimate::default();
+    let mut plan: Vec<Segment> = vec![];
+    let mut laser_on = false;
+
+    for step in moves {
+        let cut = step.power > 0.0;
+
+        if cut && !laser_on {
+            estimate.laser_events += 1;
+        }
+
+        laser_on = cut;
+
+        // The machine comes to a stop to dwell, so planning starts afresh afterwards
+        if step.dwell > 0.0 {
+            estimate.duration += plan_duration(&mut plan, options) + step.dwell;
+            plan.clear();
+            continue;
+        }
+
+        let feed = match step.motion {
+            Motion::Rapid => options.max_feed,
+            _ => step.feed.filter(|feed| *feed > 0.0).unwrap_or(options.max_feed).min(options.max_feed)
+        };
+
+        for segment in segments(step) {
+            if cut {
+                estimate.cut_length += segment.0;
+            } else {
+                estimate.travel_length += segment.0;
+            }
+
+            plan.push(Segment {
+                length: segment.0,
+                direction: segment.1,
+                speed: feed / SECONDS_PER_MINUTE,
+                entry: 0.0,
+                max_entry: 0.0
+            });
+        }
+    }
+
+    estimate.duration += plan_duration(&mut plan, options);
+
+    estimate
+}
+
+fn segments(step: &Move) -> Vec<(f64, (f64, f64, f64))> {
+    let points = step.points();
+    let dz = (step.to.z - step.from.z) / (points.len() - 1) as f64;
+
+    points.windows(2)
+        .filter_map(|pair| {
+            let (dx, dy) = (pair[1].0 - pair[0].0, pair[1].1 - pair[0].1);
+            let length = (dx * dx + dy * dy + dz * dz).sqrt();
+
+            (length > 1e-9).then(|| (length, (dx / length, dy / length, dz / length)))
+        })
+        .collect()
+}
+
+// Trapezoidal planning as GRBL does it: corner speeds are limited by the junction deviation, then a
+// backward and a forward pass make sure every segment can be reached and left within the acceleration
+fn plan_duration(plan: &mut [Segment], options: &EstimateOptions) -> f64 {
+    let acceleration = options.acceleration.max(1e-9);
+
+    for i in 1..plan.len() {
+        let (previous, current) = (&plan[i - 1], &plan[i]);
+        let limit = previous.speed.min(current.speed);
+
+        let cos_theta = -(previous.direction.0 * current.direction.0
+            + previous.direction.1 * current.direction.1
+            + previous.direction.2 * current.direction.2);
+
+        plan[i].max_entry = match cos_theta {
+            c if c > 0.999999 => 0.0,
+            c if c < -0.999999 => limit,
+            c => {
+                let sin_theta_d2 = (0.5 * (1.0 - c)).sqrt();
+
+                (acceleration * options.junction_deviation * sin_theta_d2 / (1.0 - sin_theta_d2)).sqrt().min(limit)
+            }
+        };
+    }
+
+    let mut exit = 0.0;
+
+    for segment in plan.iter_mut().rev() {
+        segment.entry = segment.max_entry.min((exit * exit + 2.0 * acceleration * segment.length).sqrt());
+        exit = segment.entry;
+    }
+
+    for i in 1..plan.len() {
+        let reachable = (plan[i - 1].entry * plan[i - 1].entry + 2.0 * acceleration * plan[i - 1].length).sqrt();
+        plan[i].entry = plan[i].entry.min(reachable);
+    }
+
+    (0..plan.len())
+        .map(|i| {
+            let exit = plan.get(i + 1).map_or(0.0, |next| next.entry);
+
+            trapezoid_time(&plan[i], plan[i].entry, exit, acceleration)
+        })
+        .sum()
+}
+
+fn trapezoid_time(segment: &Segment, entry: f64, exit: f64, acceleration: f64) -> f64 {
+    let cruise = segment.speed;
+    let accelerating = (cruise * cruise - entry * entry) / (2.0 * acceleration);
+    let decelerating = (cruise * cruise - exit * exit) / (2.0 * acceleration);
+
+    if accelerating + decelerating <= segment.length {
+        return (cruise - entry) / acceleration + (cruise - exit) / acceleration + (segment.length - accelerating - decelerating) / cruise;
+    }
+
+    // Too short to reach full speed, so the move peaks somewhere in the middle
+    let peak = ((2.0 * acceleration * segment.length + entry * entry + exit * exit) / 2.0).sqrt().max(entry.max(exit));
+
+    (peak - entry) / acceleration + (peak - exit) / acceleration
+}
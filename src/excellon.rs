@@ -0,0 +1,228 @@
+use crate::dxf::generate_gcode_with_power;
+use crate::dxf::point::{points_to_gcode, Drill, PointAction};
+use crate::dxf::polyline::PolyLine;
+use crate::dxf::vertex::Vertex;
+use crate::gcode::{write_blocks, Block, Word};
+use crate::gerber::aperture::circle;
+
+const MM_PER_INCH: f64 = 25.4;
+const DEFAULT_DRILL_DEPTH: f64 = 1.8;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HoleAction {
+    Drill(Drill),
+    Cutout
+}
+
+#[derive(Debug, Clone)]
+pub struct ExcellonOptions {
+    pub holes: HoleAction,
+    pub tool_change: bool
+}
+
+struct Tool {
+    number: u32,
+    diameter: f64,
+    holes: Vec<Vertex>
+}
+
+struct Parser {
+    metric: bool,
+    leading_zeros: bool,
+    format: Option<(usize, usize)>,
+    incremental: bool,
+    header: bool,
+    tools: Vec<Tool>,
+    tool: Option<u32>,
+    position: Vertex
+}
+
+impl Default for ExcellonOptions {
+    fn default() -> ExcellonOptions {
+        ExcellonOptions {
+            holes: HoleAction::Drill(Drill::new(DEFAULT_DRILL_DEPTH)),
+            tool_change: true
+        }
+    }
+}
+
+pub(crate) fn excellon_to_gcode(excellon_contents: &str, options: &ExcellonOptions) -> String {
+    parse(excellon_contents).iter()
+        .filter(|tool| !tool.holes.is_empty())
+        .map(|tool| match &options.holes {
+            HoleAction::Drill(drill) => {
+                let tool_change = match options.tool_change {
+                    true => write_blocks(&[Block::new(vec![Word::number('T', tool.number as f64), Word::code('M', 6)])]),
+                    false => String::new()
+                };
+
+                tool_change + points_to_gcode(&tool.holes, &PointAction::Drill(drill.clone())).as_str()
+            },
+
+            HoleAction::Cutout => {
+                let cutouts: Vec<PolyLine> = tool.holes.iter()
+                    .filter(|_| tool.diameter > 0.0)
+                    .map(|hole| circle(*hole, tool.diameter / 2.0))
+                    .collect();
+
+                generate_gcode_with_power(&cutouts, 255)
+            }
+        })
+        .collect()
+}
+
+fn parse(excellon_contents: &str) -> Vec<Tool> {
+    let mut parser = Parser {
+        metric: false,
+        leading_zeros: true,
+        format: None,
+        incremental: false,
+        header: false,
+        tools: vec![],
+        tool: None,
+        position: Vertex {x: 0.0, y: 0.0}
+    };
+
+    for line in excellon_contents.lines() {
+        let line = line.split(';').next().unwrap_or("").trim();
+
+        if parser.line(line) {
+            break;
+        }
+    }
+
+    parser.tools
+}
+
+impl Parser {
+    // Returns true at the end of the program
+    fn line(&mut self, line: &str) -> bool {
+        if line.starts_with("METRIC") || line.starts_with("INCH") {
+            self.units(line);
+            return false;
+        }
+
+        match line {
+            "M30" | "M00" => return true,
+            "M48" => self.header = true,
+            "%" | "M95" => self.header = false,
+            "M71" => self.metric = true,
+            "M72" => self.metric = false,
+            "G90" | "ICI,OFF" => self.incremental = false,
+            "G91" | "ICI,ON" | "ICI" => self.incremental = true,
+            _ => {}
+        }
+
+        if let Some(tool) = line.strip_prefix('T') {
+            self.tool(tool);
+        } else if line.starts_with('X') || line.starts_with('Y') {
+            self.hole(line);
+        }
+
+        false
+    }
+
+    fn units(&mut self, line: &str) {
+        self.metric = line.starts_with("METRIC");
+
+        for option in line.split(',').skip(1) {
+            match option {
+                "LZ" => self.leading_zeros = true,
+                "TZ" => self.leading_zeros = false,
+
+                // An explicit number format such as 000.000
+                format => if let Some((integer, decimal)) = format.split_once('.') {
+                    self.format = Some((integer.len(), decimal.len()));
+                }
+            }
+        }
+    }
+
+    fn scale(&self) -> f64 {
+        if self.metric { 1.0 } else { MM_PER_INCH }
+    }
+
+    fn tool(&mut self, definition: &str) {
+        let digits = definition.find(|c: char| !c.is_ascii_digit()).unwrap_or(definition.len());
+        let Ok(number) = definition[..digits].parse::<u32>() else {
+            return;
+        };
+
+        let diameter = definition.find('C').and_then(|start| {
+            let value = &definition[start + 1..];
+            let end = value.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(value.len());
+
+            value[..end].parse::<f64>().ok()
+        });
+
+        if let Some(diameter) = diameter.map(|d| d * self.scale()) {
+            match self.tools.iter_mut().find(|tool| tool.number == number) {
+                Some(tool) => tool.diameter = diameter,
+                None => self.tools.push(Tool {number, diameter, holes: vec![]})
+            }
+        }
+
+        // Tools defined in the body are selected at the same time, and T0 unloads the current one
+        if !self.header {
+            self.tool = (number != 0).then_some(number);
+        }
+    }
+
+    fn hole(&mut self, line: &str) {
+        // Routed slots are not drilled
+        if line.contains("G85") {
+            return;
+        }
+
+        let value = |axis: char| line.find(axis).map(|start| {
+            let value = &line[start + 1..];
+            let end = value.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(value.len());
+
+            self.coordinate(&value[..end])
+        });
+
+        let (x, y) = (value('X').flatten(), value('Y').flatten());
+
+        self.position = match self.incremental {
+            true => self.position + Vertex {x: x.unwrap_or(0.0), y: y.unwrap_or(0.0)},
+            false => Vertex {x: x.unwrap_or(self.position.x), y: y.unwrap_or(self.position.y)}
+        };
+
+        let Some(number) = self.tool else {
+            return;
+        };
+
+        // Holes for a tool missing from the header still get drilled, just without a known size
+        let index = match self.tools.iter().position(|tool| tool.number == number) {
+            Some(index) => index,
+            None => {
+                self.tools.push(Tool {number, diameter: 0.0, holes: vec![]});
+                self.tools.len() - 1
+            }
+        };
+
+        let position = self.position;
+        self.tools[index].holes.push(position);
+    }
+
+    fn coordinate(&self, value: &str) -> Option<f64> {
+        if value.contains('.') {
+            return value.parse::<f64>().ok().map(|n| n * self.scale());
+        }
+
+        let (integer_digits, decimal_digits) = self.format.unwrap_or(if self.metric { (3, 3) } else { (2, 4) });
+
+        let (sign, digits) = match value.strip_prefix('-') {
+            Some(digits) => (-1.0, digits),
+            None => (1.0, value.strip_prefix('+').unwrap_or(value))
+        };
+
+        // Leading zeros are kept when trailing zeros are left out, so pad back to the full width
+        let digits = match self.leading_zeros {
+            true => format!("{:0<width$}", digits, width = integer_digits + decimal_digits),
+            false => digits.to_string()
+        };
+
+        digits.parse::<f64>().ok().map(|n| sign * n * self.scale() / 10f64.powi(decimal_digits as i32))
+    }
+}
@@ -0,0 +1,69 @@
+use std::fmt;
+
+mod block;
+mod parser;
+mod program;
+
+pub use crate::gcode::block::{Block, Word};
+pub use crate::gcode::program::{ModalState, Motion, Move, Position, Program};
+pub(crate) use crate::gcode::block::write_blocks;
+
+#[derive(Debug, Clone)]
+pub struct GcodeOptions {
+    pub power_scale: f64,
+    pub feed_scale: f64,
+    pub offset_x: f64,
+    pub offset_y: f64
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GcodeError {
+    Parse(usize, String),
+    ImageTooLarge(usize, usize)
+}
+
+impl Default for GcodeOptions {
+    fn default() -> GcodeOptions {
+        GcodeOptions {
+            power_scale: 1.0,
+            feed_scale: 1.0,
+            offset_x: 0.0,
+            offset_y: 0.0
+        }
+    }
+}
+
+impl fmt::Display for GcodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GcodeError::Parse(line, message) => write!(f, "invalid G-code on line {}: {}", line, message),
+            GcodeError::ImageTooLarge(width, height) => write!(f, "simulated image of {}x{} pixels is too large", width, height)
+        }
+    }
+}
+
+impl std::error::Error for GcodeError {}
+
+impl Program {
+    pub fn parse(gcode_contents: &str) -> Result<Program, GcodeError> {
+        parser::parse(gcode_contents)
+    }
+}
+
+pub(crate) fn gcode_to_gcode(gcode_contents: &str, options: &GcodeOptions) -> Result<String, GcodeError> {
+    let mut program = Program::parse(gcode_contents)?;
+
+    if options.power_scale != 1.0 {
+        program.scale_power(options.power_scale);
+    }
+
+    if options.feed_scale != 1.0 {
+        program.scale_feed(options.feed_scale);
+    }
+
+    if options.offset_x != 0.0 || options.offset_y != 0.0 {
+        program.translate(options.offset_x, options.offset_y);
+    }
+
+    Ok(program.to_string())
+}
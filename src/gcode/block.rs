@@ -0,0 +1,105 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Word {
+    pub letter: char,
+    pub value: f64,
+    pub decimals: Option<usize>
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Block {
+    pub words: Vec<Word>,
+    pub comment: Option<String>,
+    pub block_delete: bool,
+    // Where the block was read from, for blocks that were parsed rather than generated
+    pub line: Option<usize>
+}
+
+impl Word {
+    pub fn new(letter: char, value: f64, decimals: usize) -> Word {
+        Word {letter, value, decimals: Some(decimals)}
+    }
+
+    pub fn code(letter: char, value: u32) -> Word {
+        Word::new(letter, value as f64, 0)
+    }
+
+    // Written with as many decimals as the value needs
+    pub fn number(letter: char, value: f64) -> Word {
+        Word {letter, value, decimals: None}
+    }
+
+    pub fn coordinate(letter: char, value: f64) -> Word {
+        Word::new(letter, value, 2)
+    }
+
+    pub fn is_code(&self, letter: char, value: f64) -> bool {
+        self.letter == letter && (self.value - value).abs() < 1e-9
+    }
+}
+
+impl fmt::Display for Word {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.letter, self.decimals) {
+            ('G' | 'M', Some(0)) => write!(f, "{}{:02.0}", self.letter, self.value),
+            (letter, Some(decimals)) => write!(f, "{}{:.*}", letter, decimals, self.value),
+            (letter, None) => write!(f, "{}{}", letter, self.value)
+        }
+    }
+}
+
+impl Block {
+    pub fn new(words: Vec<Word>) -> Block {
+        Block {words, comment: None, block_delete: false, line: None}
+    }
+
+    pub fn word(&self, letter: char) -> Option<&Word> {
+        self.words.iter().find(|word| word.letter == letter)
+    }
+
+    pub fn value(&self, letter: char) -> Option<f64> {
+        self.word(letter).map(|word| word.value)
+    }
+
+    pub fn has_code(&self, letter: char, value: f64) -> bool {
+        self.words.iter().any(|word| word.is_code(letter, value))
+    }
+
+    pub fn travel(x: f64, y: f64) -> Block {
+        Block::new(vec![Word::code('M', 5), Word::number('F', 2000.0), Word::coordinate('X', x), Word::coordinate('Y', y)])
+    }
+
+    pub fn burn(x: f64, y: f64, power: u8) -> Block {
+        Block::new(vec![
+            Word::code('M', 3),
+            Word::number('F', 1000.0),
+            Word::coordinate('X', x),
+            Word::coordinate('Y', y),
+            Word::number('S', power as f64)
+        ])
+    }
+}
+
+impl fmt::Display for Block {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let words: Vec<String> = self.words.iter().map(Word::to_string).collect();
+
+        // Optional blocks are left for the controller to skip when block delete is switched on
+        if self.block_delete {
+            write!(f, "/")?;
+        }
+
+        match &self.comment {
+            Some(comment) if words.is_empty() => write!(f, "; {}", comment),
+            Some(comment) => write!(f, "{} ; {}", words.join(" "), comment),
+            None => write!(f, "{}", words.join(" "))
+        }
+    }
+}
+
+pub fn write_blocks(blocks: &[Block]) -> String {
+    blocks.iter()
+        .map(|block| block.to_string() + "\n")
+        .collect()
+}
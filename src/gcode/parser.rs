@@ -0,0 +1,82 @@
+use crate::gcode::block::{Block, Word};
+use crate::gcode::program::Program;
+use crate::gcode::GcodeError;
+
+pub fn parse(gcode_contents: &str) -> Result<Program, GcodeError> {
+    let mut blocks = vec![];
+
+    for (index, line) in gcode_contents.lines().enumerate() {
+        let line = line.trim();
+
+        // Tape markers only delimit the program
+        if line.starts_with('%') {
+            continue;
+        }
+
+        let mut block = parse_block(line).map_err(|message| GcodeError::Parse(index + 1, message))?;
+        block.line = Some(index + 1);
+
+        if !block.words.is_empty() || block.comment.is_some() {
+            blocks.push(block);
+        }
+    }
+
+    Ok(Program {blocks})
+}
+
+fn parse_block(line: &str) -> Result<Block, String> {
+    let mut words = vec![];
+    let mut comments: Vec<&str> = vec![];
+    let block_delete = line.starts_with('/');
+    let mut rest = line.strip_prefix('/').unwrap_or(line);
+
+    loop {
+        rest = rest.trim_start();
+
+        let Some(c) = rest.chars().next() else {
+            break;
+        };
+
+        match c {
+            ';' => {
+                comments.push(rest[1..].trim());
+                break;
+            },
+
+            '(' => {
+                let end = rest.find(')').unwrap_or(rest.len());
+                comments.push(rest[1..end].trim());
+                rest = rest.get(end + 1..).unwrap_or("");
+            },
+
+            // Checksums are only meaningful to the sender
+            '*' => break,
+
+            c if c.is_ascii_alphabetic() => {
+                let number = rest[1..].trim_start();
+                let end = number.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+')).unwrap_or(number.len());
+
+                let value = number[..end].parse::<f64>()
+                    .map_err(|_| format!("expected a number after {}", c))?;
+
+                let decimals = number[..end].split_once('.').map_or(0, |(_, fraction)| fraction.len());
+
+                words.push(Word::new(c.to_ascii_uppercase(), value, decimals));
+                rest = &number[end..];
+            },
+
+            c => return Err(format!("unexpected character '{}'", c))
+        }
+    }
+
+    let comment = comments.into_iter()
+        .filter(|comment| !comment.is_empty())
+        .collect::<Vec<_>>();
+
+    Ok(Block {
+        words,
+        comment: (!comment.is_empty()).then(|| comment.join(" ")),
+        block_delete,
+        line: None
+    })
+}
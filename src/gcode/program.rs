@@ -0,0 +1,344 @@
+use std::f64::consts::PI;
+use std::fmt;
+use crate::gcode::block::{write_blocks, Block};
+
+const MM_PER_INCH: f64 = 25.4;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Program {
+    pub blocks: Vec<Block>
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Motion {
+    Rapid,
+    Linear,
+    ClockwiseArc,
+    AnticlockwiseArc,
+    Cycle
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Position {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64
+}
+
+// Positions are held in millimetres whichever units the program is written in
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModalState {
+    pub motion: Option<Motion>,
+    pub absolute: bool,
+    pub absolute_arcs: bool,
+    pub inches: bool,
+    pub feed: Option<f64>,
+    pub power: f64,
+    pub laser_on: bool,
+    pub position: Position,
+    pub cycle_depth: f64,
+    pub cycle_retract: f64
+}
+
+// A single straight or arc move in millimetres, with power zero when the laser is off.
+// Dwells are moves that stay put for a number of seconds
+#[derive(Debug, Clone, PartialEq)]
+pub struct Move {
+    pub motion: Motion,
+    pub from: Position,
+    pub to: Position,
+    pub centre: Option<(f64, f64)>,
+    pub feed: Option<f64>,
+    pub power: f64,
+    pub dwell: f64
+}
+
+impl Default for ModalState {
+    fn default() -> ModalState {
+        ModalState {
+            motion: Some(Motion::Rapid),
+            absolute: true,
+            absolute_arcs: false,
+            inches: false,
+            feed: None,
+            power: 0.0,
+            laser_on: false,
+            position: Position::default(),
+            cycle_depth: 0.0,
+            cycle_retract: 0.0
+        }
+    }
+}
+
+impl ModalState {
+    fn scale(&self) -> f64 {
+        if self.inches { MM_PER_INCH } else { 1.0 }
+    }
+
+    // Codes that change how the rest of the block is read
+    pub fn set_modes(&mut self, block: &Block) {
+        for word in block.words.iter().filter(|word| word.letter == 'G') {
+            match (word.value * 10.0).round() as i64 {
+                0 => self.motion = Some(Motion::Rapid),
+                10 => self.motion = Some(Motion::Linear),
+                20 => self.motion = Some(Motion::ClockwiseArc),
+                30 => self.motion = Some(Motion::AnticlockwiseArc),
+                200 => self.inches = true,
+                210 => self.inches = false,
+                800 => self.motion = None,
+                810..=890 => self.motion = Some(Motion::Cycle),
+                900 => self.absolute = true,
+                910 => self.absolute = false,
+                901 => self.absolute_arcs = true,
+                911 => self.absolute_arcs = false,
+                _ => {}
+            }
+        }
+
+        for word in block.words.iter().filter(|word| word.letter == 'M') {
+            match word.value as i64 {
+                3 | 4 => self.laser_on = true,
+                2 | 5 | 30 => self.laser_on = false,
+                _ => {}
+            }
+        }
+
+        if let Some(feed) = block.value('F') {
+            self.feed = Some(feed);
+        }
+
+        if let Some(power) = block.value('S') {
+            self.power = power;
+        }
+    }
+
+    pub fn apply(&mut self, block: &Block) -> Vec<Move> {
+        self.set_modes(block);
+
+        if block.has_code('G', 4.0) {
+            let dwell = block.value('P').unwrap_or(0.0);
+
+            return vec![Move {dwell, ..self.line(Motion::Linear, self.position, self.position)}];
+        }
+
+        let has_axes = ['X', 'Y', 'Z'].iter().any(|axis| block.word(*axis).is_some());
+
+        if !has_axes || uses_axes_as_data(block) {
+            return vec![];
+        }
+
+        let scale = self.scale();
+        let axis = |letter: char, current: f64| match (block.value(letter), self.absolute) {
+            (Some(value), true) => value * scale,
+            (Some(value), false) => current + value * scale,
+            (None, _) => current
+        };
+
+        let from = self.position;
+        let to = Position {x: axis('X', from.x), y: axis('Y', from.y), z: axis('Z', from.z)};
+
+        // Setting the origin or homing still leaves the tool at a known place
+        if block.has_code('G', 92.0) {
+            self.position = to;
+            return vec![];
+        }
+
+        if block.has_code('G', 28.0) || block.has_code('G', 30.0) {
+            self.position = Position::default();
+            return vec![self.line(Motion::Rapid, from, self.position)];
+        }
+
+        let Some(motion) = self.motion else {
+            self.position = to;
+            return vec![];
+        };
+
+        let moves = match motion {
+            Motion::Rapid | Motion::Linear => vec![self.line(motion, from, to)],
+            Motion::ClockwiseArc | Motion::AnticlockwiseArc => vec![self.arc(block, motion, from, to)],
+            Motion::Cycle => return self.cycle(block, from)
+        };
+
+        self.position = to;
+
+        moves
+    }
+
+    fn line(&self, motion: Motion, from: Position, to: Position) -> Move {
+        Move {
+            motion,
+            from,
+            to,
+            centre: None,
+            feed: self.feed.map(|feed| feed * self.scale()),
+            power: if self.laser_on && motion != Motion::Rapid { self.power } else { 0.0 },
+            dwell: 0.0
+        }
+    }
+
+    fn arc(&self, block: &Block, motion: Motion, from: Position, to: Position) -> Move {
+        let scale = self.scale();
+
+        let centre = match block.value('R') {
+            Some(radius) => radius_centre(from, to, radius * scale, motion == Motion::ClockwiseArc),
+            None => {
+                let (i, j) = (block.value('I').unwrap_or(0.0) * scale, block.value('J').unwrap_or(0.0) * scale);
+
+                if self.absolute_arcs { (i, j) } else { (from.x + i, from.y + j) }
+            }
+        };
+
+        Move {centre: Some(centre), ..self.line(motion, from, to)}
+    }
+
+    // Drilling cycles rapid over the hole, feed to depth and rapid back out to the retract plane
+    fn cycle(&mut self, block: &Block, from: Position) -> Vec<Move> {
+        let scale = self.scale();
+
+        if let Some(depth) = block.value('Z') {
+            self.cycle_depth = depth * scale;
+        }
+
+        if let Some(retract) = block.value('R') {
+            self.cycle_retract = retract * scale;
+        }
+
+        let axis = |letter: char, current: f64| match (block.value(letter), self.absolute) {
+            (Some(value), true) => value * scale,
+            (Some(value), false) => current + value * scale,
+            (None, _) => current
+        };
+
+        let above = Position {x: axis('X', from.x), y: axis('Y', from.y), z: from.z.max(self.cycle_retract)};
+        let bottom = Position {z: self.cycle_depth, ..above};
+        let retracted = Position {z: self.cycle_retract, ..above};
+
+        self.position = retracted;
+
+        vec![
+            self.line(Motion::Rapid, from, above),
+            self.line(Motion::Linear, above, bottom),
+            self.line(Motion::Rapid, bottom, retracted)
+        ]
+    }
+}
+
+fn uses_axes_as_data(block: &Block) -> bool {
+    [4.0, 10.0, 53.0].iter().any(|code| block.has_code('G', *code))
+}
+
+fn radius_centre(from: Position, to: Position, radius: f64, clockwise: bool) -> (f64, f64) {
+    let (dx, dy) = (to.x - from.x, to.y - from.y);
+    let chord = (dx * dx + dy * dy).sqrt();
+    let (mid_x, mid_y) = ((from.x + to.x) / 2.0, (from.y + to.y) / 2.0);
+
+    if chord < 1e-12 {
+        return (mid_x, mid_y);
+    }
+
+    // The centre sits left of the chord for short anticlockwise arcs, and a negative radius asks for the long way round
+    let height = (radius * radius - chord * chord / 4.0).max(0.0).sqrt();
+    let side = if clockwise == (radius < 0.0) { 1.0 } else { -1.0 };
+
+    (mid_x - side * height * dy / chord, mid_y + side * height * dx / chord)
+}
+
+impl Move {
+    // Radius, start angle and signed sweep, with arcs that end where they start going all the way round
+    pub(crate) fn arc_angles(&self, centre: (f64, f64)) -> (f64, f64, f64) {
+        let radius = (self.from.x - centre.0).hypot(self.from.y - centre.1);
+        let start = (self.from.y - centre.1).atan2(self.from.x - centre.0);
+        let end = (self.to.y - centre.1).atan2(self.to.x - centre.0);
+
+        let sweep = match self.motion {
+            Motion::ClockwiseArc => -(start - end).rem_euclid(2.0 * PI),
+            _ => (end - start).rem_euclid(2.0 * PI)
+        };
+
+        let sweep = match sweep.abs() < 1e-9 {
+            true if self.motion == Motion::ClockwiseArc => -2.0 * PI,
+            true => 2.0 * PI,
+            false => sweep
+        };
+
+        (radius, start, sweep)
+    }
+
+    // Points along the move in the XY plane, with arcs broken into short chords
+    pub(crate) fn points(&self) -> Vec<(f64, f64)> {
+        let Some(centre) = self.centre else {
+            return vec![(self.from.x, self.from.y), (self.to.x, self.to.y)];
+        };
+
+        let (radius, start, sweep) = self.arc_angles(centre);
+        let count = ((sweep.abs() * radius).ceil() as usize).clamp(8, 256);
+
+        (0..=count)
+            .map(|i| {
+                let angle = start + sweep * i as f64 / count as f64;
+
+                (centre.0 + radius * angle.cos(), centre.1 + radius * angle.sin())
+            })
+            .collect()
+    }
+}
+
+impl Program {
+    pub fn moves(&self) -> Vec<Move> {
+        let mut state = ModalState::default();
+
+        self.blocks.iter()
+            .flat_map(|block| state.apply(block))
+            .collect()
+    }
+
+    pub fn scale_power(&mut self, factor: f64) {
+        self.scale_words('S', factor);
+    }
+
+    pub fn scale_feed(&mut self, factor: f64) {
+        self.scale_words('F', factor);
+    }
+
+    fn scale_words(&mut self, letter: char, factor: f64) {
+        for word in self.blocks.iter_mut().flat_map(|block| block.words.iter_mut()).filter(|word| word.letter == letter) {
+            word.value = ((word.value * factor).max(0.0) * 1000.0).round() / 1000.0;
+            word.decimals = None;
+        }
+    }
+
+    // Only absolute moves are shifted, since relative ones follow along from wherever they start
+    pub fn translate(&mut self, x: f64, y: f64) {
+        let mut state = ModalState::default();
+
+        for block in self.blocks.iter_mut() {
+            let mut modes = state.clone();
+            modes.set_modes(block);
+
+            let movable = !uses_axes_as_data(block) && ![28.0, 30.0, 92.0].iter().any(|code| block.has_code('G', *code));
+            let (dx, dy) = (x / modes.scale(), y / modes.scale());
+            let precision = if modes.inches { 4 } else { 2 };
+
+            for word in block.words.iter_mut() {
+                let offset = match word.letter {
+                    'X' if movable && modes.absolute => dx,
+                    'Y' if movable && modes.absolute => dy,
+                    'I' if movable && modes.absolute_arcs => dx,
+                    'J' if movable && modes.absolute_arcs => dy,
+                    _ => continue
+                };
+
+                word.value += offset;
+                word.decimals = word.decimals.map(|decimals| decimals.max(precision));
+            }
+
+            state.apply(block);
+        }
+    }
+}
+
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", write_blocks(&self.blocks))
+    }
+}
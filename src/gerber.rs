@@ -0,0 +1,61 @@
+use std::fmt;
+use crate::dxf::fill::FillOptions;
+use crate::dxf::generate_gcode_with_power;
+use crate::gerber::image::{fill, outline};
+use crate::gerber::parser::parse;
+
+pub(crate) mod aperture;
+mod image;
+mod macros;
+mod parser;
+
+const DEFAULT_RESOLUTION: f64 = 0.025;
+
+#[derive(Debug, Clone)]
+pub struct GerberOptions {
+    pub fill: Option<FillOptions>,
+    pub outline: bool,
+    pub offset: f64,
+    pub resolution: f64
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GerberError {
+    TooLarge(usize, usize)
+}
+
+impl Default for GerberOptions {
+    fn default() -> GerberOptions {
+        GerberOptions {
+            fill: None,
+            outline: true,
+            offset: 0.0,
+            resolution: DEFAULT_RESOLUTION
+        }
+    }
+}
+
+impl fmt::Display for GerberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GerberError::TooLarge(columns, rows) => write!(f, "isolation grid of {}x{} cells is too large, try a coarser resolution", columns, rows)
+        }
+    }
+}
+
+impl std::error::Error for GerberError {}
+
+pub(crate) fn gerber_to_gcode(gerber_contents: &str, options: &GerberOptions) -> Result<String, GerberError> {
+    let objects = parse(gerber_contents);
+    let mut gcode = String::new();
+
+    if let Some(fill_options) = &options.fill {
+        gcode += generate_gcode_with_power(&fill(&objects, fill_options), 255).as_str();
+    }
+
+    if options.outline {
+        gcode += generate_gcode_with_power(&outline(&objects, options.offset, options.resolution)?, 255).as_str();
+    }
+
+    Ok(gcode)
+}
@@ -0,0 +1,154 @@
+use std::f64::consts::PI;
+use crate::dxf::polyline::PolyLine;
+use crate::dxf::vertex::Vertex;
+use crate::gerber::image::Primitive;
+
+#[derive(Debug, Clone)]
+pub enum Aperture {
+    Circle(f64),
+    Shape(Vec<Primitive>)
+}
+
+impl Aperture {
+    pub fn standard(template: &str, parameters: &[f64], scale: f64) -> Option<Aperture> {
+        // Everything but a polygon's vertex count and rotation is a length
+        let parameter = |i: usize| match (template, i) {
+            ("P", 1 | 2) => parameters.get(i).copied().unwrap_or(0.0),
+            _ => parameters.get(i).copied().unwrap_or(0.0) * scale
+        };
+
+        let (outline, hole) = match template {
+            "C" if parameter(1) <= 0.0 => return Some(Aperture::Circle(parameter(0))),
+            "C" => (circle(Vertex {x: 0.0, y: 0.0}, parameter(0) / 2.0), parameter(1)),
+            "R" => (rectangle(Vertex {x: 0.0, y: 0.0}, parameter(0), parameter(1)), parameter(2)),
+            "O" => (obround(parameter(0), parameter(1)), parameter(2)),
+            "P" => (polygon(Vertex {x: 0.0, y: 0.0}, parameter(0), parameter(1) as usize, parameter(2)), parameter(3)),
+            _ => return None
+        };
+
+        let mut contours = vec![outline];
+
+        if hole > 0.0 {
+            contours.push(circle(Vertex {x: 0.0, y: 0.0}, hole / 2.0));
+        }
+
+        Some(Aperture::Shape(vec![Primitive::new(true, contours)]))
+    }
+
+    pub fn flash(&self, at: Vertex) -> Vec<Primitive> {
+        match self {
+            Aperture::Circle(diameter) if *diameter > 0.0 => vec![Primitive::new(true, vec![circle(at, diameter / 2.0)])],
+            Aperture::Circle(_) => vec![],
+            Aperture::Shape(primitives) => primitives.iter().map(|p| p.map(|v| *v + at)).collect()
+        }
+    }
+
+    pub fn draw(&self, from: Vertex, to: Vertex) -> Vec<Primitive> {
+        match self {
+            Aperture::Circle(diameter) if *diameter > 0.0 => vec![Primitive::new(true, vec![capsule(from, to, diameter / 2.0)])],
+            Aperture::Circle(_) => vec![],
+
+            // Other apertures sweep out the hull of their outline at both ends of the stroke
+            Aperture::Shape(primitives) => {
+                let points: Vec<Vertex> = primitives.iter()
+                    .filter(|p| p.dark)
+                    .flat_map(|p| p.contours.first())
+                    .flat_map(|contour| contour.vertices.iter().flat_map(|v| [*v + from, *v + to]))
+                    .collect();
+
+                vec![Primitive::new(true, vec![convex_hull(points)])]
+            }
+        }
+    }
+}
+
+pub fn circle_segments(radius: f64) -> usize {
+    ((radius * 40.0) as usize).clamp(16, 128)
+}
+
+pub fn circle(centre: Vertex, radius: f64) -> PolyLine {
+    let count = circle_segments(radius);
+
+    PolyLine {
+        vertices: (0..count)
+            .map(|i| centre + Vertex::from_polar(radius, 2.0 * PI * i as f64 / count as f64))
+            .collect(),
+        closed: true
+    }
+}
+
+pub fn rectangle(centre: Vertex, width: f64, height: f64) -> PolyLine {
+    let (w, h) = (width / 2.0, height / 2.0);
+
+    PolyLine {
+        vertices: vec![
+            centre + Vertex {x: -w, y: -h},
+            centre + Vertex {x: w, y: -h},
+            centre + Vertex {x: w, y: h},
+            centre + Vertex {x: -w, y: h}
+        ],
+        closed: true
+    }
+}
+
+pub fn polygon(centre: Vertex, diameter: f64, vertices: usize, rotation: f64) -> PolyLine {
+    let count = vertices.max(3);
+
+    PolyLine {
+        vertices: (0..count)
+            .map(|i| centre + Vertex::from_polar(diameter / 2.0, rotation.to_radians() + 2.0 * PI * i as f64 / count as f64))
+            .collect(),
+        closed: true
+    }
+}
+
+fn obround(width: f64, height: f64) -> PolyLine {
+    let offset = if width > height {
+        Vertex {x: (width - height) / 2.0, y: 0.0}
+    } else {
+        Vertex {x: 0.0, y: (height - width) / 2.0}
+    };
+
+    capsule(offset.scale(-1.0), offset, width.min(height) / 2.0)
+}
+
+pub fn capsule(from: Vertex, to: Vertex, radius: f64) -> PolyLine {
+    let direction = from.vector_to(&to);
+    let angle = direction.y.atan2(direction.x);
+    let count = circle_segments(radius) / 2;
+
+    let cap = |centre: Vertex, start: f64| (0..=count)
+        .map(move |i| centre + Vertex::from_polar(radius, start + PI * i as f64 / count as f64));
+
+    PolyLine {
+        vertices: cap(to, angle - PI / 2.0).chain(cap(from, angle + PI / 2.0)).collect(),
+        closed: true
+    }
+}
+
+pub fn convex_hull(mut points: Vec<Vertex>) -> PolyLine {
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup();
+
+    let cross = |o: &Vertex, a: &Vertex, b: &Vertex| (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x);
+    let mut hull: Vec<Vertex> = vec![];
+
+    // Monotone chain, building the lower hull then the upper hull
+    for pass in 0..2 {
+        let start = hull.len();
+
+        for i in 0..points.len() {
+            let point = if pass == 0 { points[i] } else { points[points.len() - 1 - i] };
+
+            while hull.len() >= start + 2 && cross(&hull[hull.len() - 2], &hull[hull.len() - 1], &point) <= 0.0 {
+                hull.pop();
+            }
+
+            hull.push(point);
+        }
+
+        hull.pop();
+    }
+
+    PolyLine {vertices: hull, closed: true}
+}
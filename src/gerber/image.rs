@@ -0,0 +1,385 @@
+use std::collections::HashMap;
+use crate::dxf::fill::{contour_edges, scan_rows, scanline_spans, FillOptions, FillRule};
+use crate::dxf::polyline::PolyLine;
+use crate::dxf::vertex::Vertex;
+use crate::gerber::GerberError;
+
+// Isolation is traced on a grid, so the board has to fit in a bounded number of cells at the chosen resolution
+const MAX_CELLS: usize = 1 << 26;
+
+type Span = (f64, f64);
+type Edge = (Vertex, Vertex);
+
+#[derive(Debug, Clone)]
+pub struct Primitive {
+    pub dark: bool,
+    pub contours: Vec<PolyLine>
+}
+
+#[derive(Debug, Clone)]
+pub struct Object {
+    pub dark: bool,
+    pub primitives: Vec<Primitive>
+}
+
+struct LayerObject {
+    dark: bool,
+    primitives: Vec<(bool, Vec<Edge>)>,
+    y_min: f64,
+    y_max: f64
+}
+
+// Objects flattened to edges in a scan frame, so spans can be taken along any axis
+struct Layer {
+    objects: Vec<LayerObject>
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum GridEdge {
+    Horizontal(usize, usize),
+    Vertical(usize, usize)
+}
+
+impl Primitive {
+    pub fn new(dark: bool, contours: Vec<PolyLine>) -> Primitive {
+        Primitive {dark, contours}
+    }
+
+    pub fn map(&self, f: impl Fn(&Vertex) -> Vertex) -> Primitive {
+        Primitive {
+            dark: self.dark,
+            contours: self.contours.iter()
+                .map(|contour| PolyLine {vertices: contour.vertices.iter().map(&f).collect(), closed: true})
+                .collect()
+        }
+    }
+}
+
+impl Object {
+    pub fn map(&self, f: impl Fn(&Vertex) -> Vertex) -> Object {
+        Object {
+            dark: self.dark,
+            primitives: self.primitives.iter().map(|primitive| primitive.map(&f)).collect()
+        }
+    }
+}
+
+impl Layer {
+    fn new(objects: &[Object], frame: impl Fn(&Vertex) -> Vertex) -> Layer {
+        let objects = objects.iter()
+            .map(|object| {
+                let primitives: Vec<(bool, Vec<Edge>)> = object.primitives.iter()
+                    .map(|primitive| {
+                        let edges = primitive.contours.iter()
+                            .flat_map(contour_edges)
+                            .map(|(a, b)| (frame(&a), frame(&b)))
+                            .collect();
+
+                        (primitive.dark, edges)
+                    })
+                    .collect();
+
+                let ys = primitives.iter().flat_map(|(_, edges)| edges.iter().map(|(a, _)| a.y));
+
+                LayerObject {
+                    dark: object.dark,
+                    y_min: ys.clone().fold(f64::MAX, f64::min),
+                    y_max: ys.fold(f64::MIN, f64::max),
+                    primitives
+                }
+            })
+            .filter(|object| object.y_min <= object.y_max)
+            .collect();
+
+        Layer {objects}
+    }
+
+    fn y_range(&self) -> Option<(f64, f64)> {
+        let y_min = self.objects.iter().map(|o| o.y_min).fold(f64::MAX, f64::min);
+        let y_max = self.objects.iter().map(|o| o.y_max).fold(f64::MIN, f64::max);
+
+        (y_min <= y_max).then_some((y_min, y_max))
+    }
+
+    fn spans(&self, y: f64) -> Vec<Span> {
+        let mut image = vec![];
+
+        // Later objects are drawn over earlier ones, so clear polarity erases what came before
+        for object in self.objects.iter().filter(|o| o.y_min <= y && y <= o.y_max) {
+            let mut shape = vec![];
+
+            for (dark, edges) in object.primitives.iter() {
+                let spans = scanline_spans(edges, y, FillRule::EvenOdd);
+
+                shape = if *dark { union(&shape, &spans) } else { subtract(&shape, &spans) };
+            }
+
+            image = if object.dark { union(&image, &shape) } else { subtract(&image, &shape) };
+        }
+
+        image
+    }
+}
+
+fn union(a: &[Span], b: &[Span]) -> Vec<Span> {
+    let mut spans: Vec<Span> = a.iter().chain(b.iter()).copied().collect();
+    spans.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut merged: Vec<Span> = vec![];
+
+    for span in spans {
+        match merged.last_mut() {
+            Some(last) if span.0 <= last.1 => last.1 = last.1.max(span.1),
+            _ => merged.push(span)
+        }
+    }
+
+    merged
+}
+
+fn subtract(a: &[Span], b: &[Span]) -> Vec<Span> {
+    let mut result = vec![];
+
+    for &(start, end) in a {
+        let mut start = start;
+
+        for &(cut_start, cut_end) in b {
+            if cut_end <= start || cut_start >= end {
+                continue;
+            }
+
+            if cut_start > start {
+                result.push((start, cut_start));
+            }
+
+            start = start.max(cut_end);
+        }
+
+        if end > start {
+            result.push((start, end));
+        }
+    }
+
+    result
+}
+
+pub fn fill(objects: &[Object], options: &FillOptions) -> Vec<PolyLine> {
+    let mut lines = fill_at_angle(objects, options, options.angle);
+
+    if options.cross_hatch {
+        lines.append(&mut fill_at_angle(objects, options, options.angle + 90.0));
+    }
+
+    lines
+}
+
+fn fill_at_angle(objects: &[Object], options: &FillOptions, angle: f64) -> Vec<PolyLine> {
+    let rotation = angle.to_radians();
+    let layer = Layer::new(objects, |v| v.rotate(-rotation));
+
+    match layer.y_range() {
+        Some((y_min, y_max)) => scan_rows(y_min, y_max, options, rotation, |y| layer.spans(y)),
+        None => vec![]
+    }
+}
+
+pub fn outline(objects: &[Object], offset: f64, resolution: f64) -> Result<Vec<PolyLine>, GerberError> {
+    let rows = Layer::new(objects, |v| *v);
+    let columns = Layer::new(objects, |v| Vertex {x: v.y, y: v.x});
+
+    let (Some((y_min, y_max)), Some((x_min, x_max))) = (rows.y_range(), columns.y_range()) else {
+        return Ok(vec![]);
+    };
+
+    if resolution <= 0.0 {
+        return Ok(vec![]);
+    }
+
+    // A margin of empty cells around the copper keeps every contour closed
+    let offset = offset.max(0.0);
+    let margin = offset + 2.0 * resolution;
+    let origin = Vertex {x: x_min - margin, y: y_min - margin};
+    let column_count = ((x_max - x_min + 2.0 * margin) / resolution).ceil() as usize;
+    let row_count = ((y_max - y_min + 2.0 * margin) / resolution).ceil() as usize;
+
+    if column_count.saturating_add(1).saturating_mul(row_count.saturating_add(1)) > MAX_CELLS {
+        return Err(GerberError::TooLarge(column_count, row_count));
+    }
+
+    let row_spans = dilate(&sample(&rows, origin.y, row_count, resolution), offset, resolution);
+    let column_spans = dilate(&sample(&columns, origin.x, column_count, resolution), offset, resolution);
+
+    let inside: Vec<Vec<bool>> = row_spans.iter()
+        .map(|spans| {
+            let mut row = vec![false; column_count + 1];
+
+            for (start, end) in spans {
+                let first = ((start - origin.x) / resolution).ceil().max(0.0) as usize;
+                let last = (((end - origin.x) / resolution).floor() as usize).min(column_count);
+
+                row.iter_mut().take(last + 1).skip(first).for_each(|cell| *cell = true);
+            }
+
+            row
+        })
+        .collect();
+
+    let crossing = |spans: &[Span], from: f64| spans.iter()
+        .flat_map(|(start, end)| [*start, *end])
+        .find(|position| *position >= from && *position <= from + resolution)
+        .unwrap_or(from + resolution / 2.0);
+
+    let position = |edge: GridEdge| match edge {
+        GridEdge::Horizontal(c, r) => Vertex {
+            x: crossing(&row_spans[r], origin.x + c as f64 * resolution),
+            y: origin.y + r as f64 * resolution
+        },
+
+        GridEdge::Vertical(c, r) => Vertex {
+            x: origin.x + c as f64 * resolution,
+            y: crossing(&column_spans[c], origin.y + r as f64 * resolution)
+        }
+    };
+
+    Ok(trace(&inside, column_count, row_count)
+        .into_iter()
+        .map(|contour| PolyLine {
+            vertices: simplify(&contour.into_iter().map(position).collect::<Vec<_>>(), resolution / 4.0),
+            closed: true
+        })
+        .filter(|contour| contour.vertices.len() > 2)
+        .collect())
+}
+
+fn sample(layer: &Layer, origin: f64, count: usize, resolution: f64) -> Vec<Vec<Span>> {
+    (0..=count)
+        .map(|i| layer.spans(origin + i as f64 * resolution))
+        .collect()
+}
+
+fn dilate(lines: &[Vec<Span>], offset: f64, resolution: f64) -> Vec<Vec<Span>> {
+    if offset <= 0.0 {
+        return lines.to_vec();
+    }
+
+    let reach = (offset / resolution).floor() as usize;
+
+    // Each scanline grows by the width of a disc of the offset radius at that distance
+    (0..lines.len())
+        .map(|i| {
+            let mut spans = vec![];
+
+            let first = i.saturating_sub(reach);
+
+            for (j, line) in lines.iter().enumerate().take(i + reach + 1).skip(first) {
+                let distance = i.abs_diff(j) as f64 * resolution;
+                let grow = (offset * offset - distance * distance).max(0.0).sqrt();
+                let grown: Vec<Span> = line.iter().map(|(start, end)| (start - grow, end + grow)).collect();
+
+                spans = union(&spans, &grown);
+            }
+
+            spans
+        })
+        .collect()
+}
+
+// Marching squares, with contours running anticlockwise around copper
+fn trace(inside: &[Vec<bool>], column_count: usize, row_count: usize) -> Vec<Vec<GridEdge>> {
+    let mut next = HashMap::new();
+
+    for r in 0..row_count {
+        for c in 0..column_count {
+            let corners = [inside[r][c], inside[r][c + 1], inside[r + 1][c + 1], inside[r + 1][c]];
+            let edges = [
+                GridEdge::Horizontal(c, r),
+                GridEdge::Vertical(c + 1, r),
+                GridEdge::Horizontal(c, r + 1),
+                GridEdge::Vertical(c, r)
+            ];
+
+            let leaving: Vec<usize> = (0..4).filter(|&i| corners[i] && !corners[(i + 1) % 4]).collect();
+            let entering: Vec<usize> = (0..4).filter(|&i| !corners[i] && corners[(i + 1) % 4]).collect();
+
+            match (leaving.as_slice(), entering.as_slice()) {
+                ([from], [to]) => {
+                    next.insert(edges[*from], edges[*to]);
+                },
+
+                // Diagonal corners are kept apart rather than joined
+                _ => for from in leaving {
+                    next.insert(edges[from], edges[(from + 3) % 4]);
+                }
+            }
+        }
+    }
+
+    let mut starts: Vec<GridEdge> = next.keys().copied().collect();
+    starts.sort_by_key(|edge| match *edge {
+        GridEdge::Horizontal(c, r) => (r, c, 0),
+        GridEdge::Vertical(c, r) => (r, c, 1)
+    });
+
+    let mut contours = vec![];
+
+    for start in starts {
+        let mut contour = vec![];
+        let mut edge = start;
+
+        while let Some(following) = next.remove(&edge) {
+            contour.push(edge);
+            edge = following;
+        }
+
+        if !contour.is_empty() {
+            contours.push(contour);
+        }
+    }
+
+    contours
+}
+
+fn simplify(points: &[Vertex], tolerance: f64) -> Vec<Vertex> {
+    if points.len() < 4 {
+        return points.to_vec();
+    }
+
+    // Split the loop at its furthest point so each half can be simplified as an open line
+    let furthest = (1..points.len())
+        .max_by(|a, b| points[0].distance_to(&points[*a]).total_cmp(&points[0].distance_to(&points[*b])))
+        .unwrap_or(1);
+
+    let mut first = simplify_line(&points[..=furthest], tolerance);
+    let mut second = simplify_line(&[&points[furthest..], &points[..1]].concat(), tolerance);
+
+    first.pop();
+    second.pop();
+    first.append(&mut second);
+
+    first
+}
+
+fn simplify_line(points: &[Vertex], tolerance: f64) -> Vec<Vertex> {
+    let (first, last) = (points[0], points[points.len() - 1]);
+    let chord = first.vector_to(&last);
+    let length = chord.magnitude();
+
+    let distance = |p: &Vertex| match length {
+        l if l < 1e-12 => first.distance_to(p),
+        l => (chord.x * (p.y - first.y) - chord.y * (p.x - first.x)).abs() / l
+    };
+
+    let furthest = (1..points.len() - 1)
+        .max_by(|a, b| distance(&points[*a]).total_cmp(&distance(&points[*b])));
+
+    match furthest {
+        Some(i) if distance(&points[i]) > tolerance => {
+            let mut left = simplify_line(&points[..=i], tolerance);
+            left.pop();
+            left.append(&mut simplify_line(&points[i..], tolerance));
+            left
+        },
+
+        _ => vec![first, last]
+    }
+}
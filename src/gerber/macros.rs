@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use crate::dxf::polyline::PolyLine;
+use crate::dxf::vertex::Vertex;
+use crate::gerber::aperture::{circle, circle_segments, polygon, rectangle};
+use crate::gerber::image::Primitive;
+
+#[derive(Debug, Clone)]
+pub struct Macro {
+    statements: Vec<String>
+}
+
+struct Expression<'a> {
+    tokens: &'a [u8],
+    position: usize,
+    variables: &'a HashMap<usize, f64>
+}
+
+impl Macro {
+    pub fn new(statements: &[&str]) -> Macro {
+        Macro {statements: statements.iter().map(|s| s.to_string()).collect()}
+    }
+
+    pub fn evaluate(&self, parameters: &[f64], scale: f64) -> Vec<Primitive> {
+        let mut variables: HashMap<usize, f64> = parameters.iter()
+            .enumerate()
+            .map(|(i, value)| (i + 1, *value))
+            .collect();
+
+        let mut primitives = vec![];
+
+        for statement in self.statements.iter() {
+            let statement: String = statement.chars().filter(|c| !c.is_whitespace()).collect();
+
+            if let Some((variable, expression)) = statement.strip_prefix('$').and_then(|s| s.split_once('=')) {
+                if let Ok(variable) = variable.parse() {
+                    let value = evaluate(expression, &variables);
+                    variables.insert(variable, value);
+                }
+
+                continue;
+            }
+
+            let values: Vec<f64> = statement.split(',').map(|e| evaluate(e, &variables)).collect();
+
+            // Code 0 is a comment, which may contain anything
+            if statement.starts_with('0') || values.is_empty() {
+                continue;
+            }
+
+            let code = values[0] as u32;
+            let values: Vec<f64> = values[1..].iter()
+                .enumerate()
+                .map(|(i, value)| if is_length(code, i, values.len() - 1) { value * scale } else { *value })
+                .collect();
+
+            primitives.extend(primitive(code, &values));
+        }
+
+        primitives
+    }
+}
+
+fn is_length(code: u32, index: usize, count: usize) -> bool {
+    match code {
+        1 => (1..=3).contains(&index),
+        20 => (1..=5).contains(&index),
+        21 => (1..=4).contains(&index),
+        4 => index >= 2 && index + 1 < count,
+        5 => (2..=4).contains(&index),
+        6 => index <= 4 || index == 6 || index == 7,
+        7 => index <= 4,
+        _ => false
+    }
+}
+
+fn primitive(code: u32, values: &[f64]) -> Vec<Primitive> {
+    let value = |i: usize| values.get(i).copied().unwrap_or(0.0);
+    let dark = value(0) != 0.0;
+
+    // Rotations are always about the macro origin rather than the primitive's own centre
+    let rotated = |contours: Vec<PolyLine>, rotation: f64| {
+        Primitive::new(dark, contours).map(|v| v.rotate(rotation.to_radians()))
+    };
+
+    match code {
+        1 => vec![rotated(vec![circle(Vertex {x: value(2), y: value(3)}, value(1) / 2.0)], value(4))],
+
+        20 => {
+            let (start, end) = (Vertex {x: value(2), y: value(3)}, Vertex {x: value(4), y: value(5)});
+            let direction = start.vector_to(&end);
+
+            if direction.magnitude() == 0.0 {
+                return vec![];
+            }
+
+            let side = direction.normal().with_magnitude(value(1) / 2.0);
+            let across = side.scale(-1.0);
+
+            vec![rotated(vec![PolyLine {vertices: vec![start + side, end + side, end + across, start + across], closed: true}], value(6))]
+        },
+
+        21 => vec![rotated(vec![rectangle(Vertex {x: value(3), y: value(4)}, value(1), value(2))], value(5))],
+
+        4 => {
+            let count = value(1).max(0.0) as usize;
+            let vertices: Vec<Vertex> = (0..count)
+                .map(|i| Vertex {x: value(2 + 2 * i), y: value(3 + 2 * i)})
+                .collect();
+
+            vec![rotated(vec![PolyLine {vertices, closed: true}], value(4 + 2 * count))]
+        },
+
+        5 => vec![rotated(vec![polygon(Vertex {x: value(2), y: value(3)}, value(4), value(1) as usize, 0.0)], value(5))],
+
+        // Moiré primitives have no exposure parameter
+        6 => {
+            let centre = Vertex {x: value(0), y: value(1)};
+            let rotation = value(8).to_radians();
+            let mut primitives = vec![];
+            let mut diameter = value(2);
+
+            for _ in 0..value(5).max(0.0) as usize {
+                if diameter <= 0.0 {
+                    break;
+                }
+
+                let mut ring = vec![circle(centre, diameter / 2.0)];
+                let inner = diameter - 2.0 * value(3);
+
+                if inner > 0.0 {
+                    ring.push(circle(centre, inner / 2.0));
+                }
+
+                primitives.push(Primitive::new(true, ring));
+                diameter -= 2.0 * (value(3) + value(4));
+            }
+
+            primitives.push(Primitive::new(true, vec![rectangle(centre, value(7), value(6))]));
+            primitives.push(Primitive::new(true, vec![rectangle(centre, value(6), value(7))]));
+
+            primitives.iter().map(|p| p.map(|v| v.rotate(rotation))).collect()
+        },
+
+        7 => {
+            let centre = Vertex {x: value(0), y: value(1)};
+            let contours = (0..4).map(|q| thermal_quadrant(value(2) / 2.0, value(3) / 2.0, value(4) / 2.0, q as f64 * PI / 2.0)).collect();
+
+            vec![Primitive::new(true, contours).map(|v| (*v + centre).rotate(value(5).to_radians()))]
+        },
+
+        _ => vec![]
+    }
+}
+
+fn thermal_quadrant(outer: f64, inner: f64, gap: f64, rotation: f64) -> PolyLine {
+    let count = circle_segments(outer) / 4;
+    let outer_start = (gap / outer).clamp(-1.0, 1.0).asin();
+    let outer_end = PI / 2.0 - outer_start;
+
+    let mut vertices: Vec<Vertex> = (0..=count)
+        .map(|i| Vertex::from_polar(outer, outer_start + (outer_end - outer_start) * i as f64 / count as f64))
+        .collect();
+
+    // A small inner diameter leaves the gaps meeting at a square corner
+    if inner > gap * 2.0_f64.sqrt() {
+        let inner_start = (gap / inner).asin();
+        let inner_end = PI / 2.0 - inner_start;
+
+        vertices.extend((0..=count).rev().map(|i| Vertex::from_polar(inner, inner_start + (inner_end - inner_start) * i as f64 / count as f64)));
+    } else {
+        vertices.push(Vertex {x: gap, y: gap});
+    }
+
+    PolyLine {
+        vertices: vertices.iter().map(|v| v.rotate(rotation)).collect(),
+        closed: true
+    }
+}
+
+pub fn evaluate(expression: &str, variables: &HashMap<usize, f64>) -> f64 {
+    let mut expression = Expression {tokens: expression.as_bytes(), position: 0, variables};
+
+    expression.sum()
+}
+
+impl Expression<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.tokens.get(self.position).copied()
+    }
+
+    fn sum(&mut self) -> f64 {
+        let mut value = self.product();
+
+        while let Some(operator @ (b'+' | b'-')) = self.peek() {
+            self.position += 1;
+            let operand = self.product();
+            value = if operator == b'+' { value + operand } else { value - operand };
+        }
+
+        value
+    }
+
+    fn product(&mut self) -> f64 {
+        let mut value = self.factor();
+
+        while let Some(operator @ (b'x' | b'X' | b'/')) = self.peek() {
+            self.position += 1;
+            let operand = self.factor();
+            value = if operator == b'/' { value / operand } else { value * operand };
+        }
+
+        value
+    }
+
+    fn factor(&mut self) -> f64 {
+        match self.peek() {
+            Some(b'-') => {
+                self.position += 1;
+                -self.factor()
+            },
+
+            Some(b'+') => {
+                self.position += 1;
+                self.factor()
+            },
+
+            Some(b'(') => {
+                self.position += 1;
+                let value = self.sum();
+
+                if self.peek() == Some(b')') {
+                    self.position += 1;
+                }
+
+                value
+            },
+
+            Some(b'$') => {
+                self.position += 1;
+                let variable = self.digits();
+
+                variable.parse().ok().and_then(|v| self.variables.get(&v).copied()).unwrap_or(0.0)
+            },
+
+            _ => self.digits().parse().unwrap_or(0.0)
+        }
+    }
+
+    fn digits(&mut self) -> String {
+        let start = self.position;
+
+        while matches!(self.peek(), Some(b'0'..=b'9' | b'.')) {
+            self.position += 1;
+        }
+
+        String::from_utf8_lossy(&self.tokens[start..self.position]).into_owned()
+    }
+}
@@ -0,0 +1,409 @@
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use crate::dxf::polyline::PolyLine;
+use crate::dxf::vertex::Vertex;
+use crate::gerber::aperture::{circle_segments, Aperture};
+use crate::gerber::image::{Object, Primitive};
+use crate::gerber::macros::Macro;
+
+const MM_PER_INCH: f64 = 25.4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Interpolation {
+    Linear,
+    Clockwise,
+    Anticlockwise
+}
+
+struct StepRepeat {
+    first_object: usize,
+    x_count: usize,
+    y_count: usize,
+    x_step: f64,
+    y_step: f64
+}
+
+struct Parser {
+    integer_digits: usize,
+    decimal_digits: usize,
+    trailing_zeros: bool,
+    incremental: bool,
+    scale: f64,
+    macros: HashMap<String, Macro>,
+    apertures: HashMap<u32, Aperture>,
+    aperture: Option<u32>,
+    position: Vertex,
+    interpolation: Interpolation,
+    multi_quadrant: bool,
+    operation: u32,
+    dark: bool,
+    region: Option<Vec<Vec<Vertex>>>,
+    step_repeat: Option<StepRepeat>,
+    objects: Vec<Object>
+}
+
+pub fn parse(gerber_contents: &str) -> Vec<Object> {
+    let mut parser = Parser::new();
+    let mut remaining = gerber_contents;
+
+    while let Some(start) = remaining.find(|c: char| !c.is_whitespace()) {
+        remaining = &remaining[start..];
+
+        // Extended commands are wrapped in percent signs and may hold several blocks
+        if let Some(extended) = remaining.strip_prefix('%') {
+            let end = extended.find('%').unwrap_or(extended.len());
+            parser.extended(&extended[..end]);
+            remaining = extended.get(end + 1..).unwrap_or("");
+        } else {
+            let end = remaining.find('*').unwrap_or(remaining.len());
+            let word: String = remaining[..end].chars().filter(|c| !c.is_whitespace()).collect();
+
+            if parser.word(&word) {
+                break;
+            }
+
+            remaining = remaining.get(end + 1..).unwrap_or("");
+        }
+    }
+
+    parser.finish()
+}
+
+impl Parser {
+    fn new() -> Parser {
+        Parser {
+            integer_digits: 2,
+            decimal_digits: 4,
+            trailing_zeros: false,
+            incremental: false,
+            scale: 1.0,
+            macros: HashMap::new(),
+            apertures: HashMap::new(),
+            aperture: None,
+            position: Vertex {x: 0.0, y: 0.0},
+            interpolation: Interpolation::Linear,
+            multi_quadrant: false,
+            operation: 2,
+            dark: true,
+            region: None,
+            step_repeat: None,
+            objects: vec![]
+        }
+    }
+
+    fn finish(mut self) -> Vec<Object> {
+        self.close_step_repeat();
+        self.objects
+    }
+
+    fn extended(&mut self, command: &str) {
+        let command: String = command.chars().filter(|c| !c.is_whitespace() || *c == ' ').collect();
+        let blocks: Vec<&str> = command.split('*').map(str::trim).filter(|b| !b.is_empty()).collect();
+
+        // A macro owns every block that follows its name
+        if let Some(name) = blocks.first().and_then(|b| b.strip_prefix("AM")) {
+            self.macros.insert(name.to_string(), Macro::new(&blocks[1..]));
+            return;
+        }
+
+        for block in blocks {
+            self.parameter(block);
+        }
+    }
+
+    fn parameter(&mut self, block: &str) {
+        let (code, value) = block.split_at(block.len().min(2));
+
+        match code {
+            "FS" => {
+                self.trailing_zeros = value.starts_with('T');
+                self.incremental = value.contains('I') && !value.contains('A');
+
+                if let Some(x) = value.find('X') {
+                    let digits: Vec<usize> = value[x + 1..].chars().take(2).filter_map(|c| c.to_digit(10)).map(|d| d as usize).collect();
+
+                    if let [integer, decimal] = digits[..] {
+                        self.integer_digits = integer;
+                        self.decimal_digits = decimal;
+                    }
+                }
+            },
+
+            "MO" => self.scale = if value.starts_with("IN") { MM_PER_INCH } else { 1.0 },
+
+            "LP" => self.dark = !value.starts_with('C'),
+
+            "AD" => self.define_aperture(value),
+
+            "SR" => {
+                self.close_step_repeat();
+
+                let number = |axis: char| value.find(axis)
+                    .map(|i| &value[i + 1..])
+                    .map(|rest| rest.split(|c: char| c.is_ascii_alphabetic()).next().unwrap_or(""))
+                    .and_then(|n| n.parse::<f64>().ok());
+
+                let (x_count, y_count) = (number('X').unwrap_or(1.0) as usize, number('Y').unwrap_or(1.0) as usize);
+
+                if x_count > 1 || y_count > 1 {
+                    self.step_repeat = Some(StepRepeat {
+                        first_object: self.objects.len(),
+                        x_count: x_count.max(1),
+                        y_count: y_count.max(1),
+                        x_step: number('I').unwrap_or(0.0) * self.scale,
+                        y_step: number('J').unwrap_or(0.0) * self.scale
+                    });
+                }
+            },
+
+            _ => {}
+        }
+    }
+
+    fn define_aperture(&mut self, value: &str) {
+        let Some(definition) = value.strip_prefix('D') else {
+            return;
+        };
+
+        let digits = definition.find(|c: char| !c.is_ascii_digit()).unwrap_or(definition.len());
+        let Ok(code) = definition[..digits].parse() else {
+            return;
+        };
+
+        let (template, parameters) = definition[digits..].split_once(',').unwrap_or((&definition[digits..], ""));
+        let parameters: Vec<f64> = parameters.split('X').filter_map(|p| p.trim().parse().ok()).collect();
+
+        let aperture = match self.macros.get(template) {
+            Some(definition) => Some(Aperture::Shape(definition.evaluate(&parameters, self.scale))),
+            None => Aperture::standard(template, &parameters, self.scale)
+        };
+
+        if let Some(aperture) = aperture {
+            self.apertures.insert(code, aperture);
+        }
+    }
+
+    fn close_step_repeat(&mut self) {
+        let Some(step) = self.step_repeat.take() else {
+            return;
+        };
+
+        let block: Vec<Object> = self.objects.drain(step.first_object..).collect();
+
+        for y in 0..step.y_count {
+            for x in 0..step.x_count {
+                let offset = Vertex {x: x as f64 * step.x_step, y: y as f64 * step.y_step};
+
+                self.objects.extend(block.iter().map(|object| object.map(|v| *v + offset)));
+            }
+        }
+    }
+
+    // Returns true once the end of file has been reached
+    fn word(&mut self, word: &str) -> bool {
+        let mut codes: Vec<(char, &str)> = vec![];
+        let mut rest = word;
+
+        while let Some(letter) = rest.chars().next() {
+            let value_start = letter.len_utf8();
+            let value_end = rest[value_start..].find(|c: char| c.is_ascii_alphabetic()).map_or(rest.len(), |i| i + value_start);
+            let value = &rest[value_start..value_end];
+
+            codes.push((letter.to_ascii_uppercase(), value));
+
+            // Comments run to the end of the word
+            if letter == 'G' && value.trim_start_matches('0') == "4" {
+                break;
+            }
+
+            rest = &rest[value_end..];
+        }
+
+        let mut target: (Option<f64>, Option<f64>) = (None, None);
+        let mut offset = Vertex {x: 0.0, y: 0.0};
+        let mut operation = None;
+
+        for (letter, value) in codes {
+            let number = || value.parse::<f64>().ok().map(|n| n as u32);
+
+            match (letter, number()) {
+                ('G', Some(1)) => self.interpolation = Interpolation::Linear,
+                ('G', Some(2)) => self.interpolation = Interpolation::Clockwise,
+                ('G', Some(3)) => self.interpolation = Interpolation::Anticlockwise,
+                ('G', Some(4)) => return false,
+                ('G', Some(36)) => self.region = Some(vec![vec![]]),
+                ('G', Some(37)) => self.close_region(),
+                ('G', Some(70)) => self.scale = MM_PER_INCH,
+                ('G', Some(71)) => self.scale = 1.0,
+                ('G', Some(74)) => self.multi_quadrant = false,
+                ('G', Some(75)) => self.multi_quadrant = true,
+                ('G', Some(90)) => self.incremental = false,
+                ('G', Some(91)) => self.incremental = true,
+                ('X', _) => target.0 = self.coordinate(value),
+                ('Y', _) => target.1 = self.coordinate(value),
+                ('I', _) => offset.x = self.coordinate(value).unwrap_or(0.0),
+                ('J', _) => offset.y = self.coordinate(value).unwrap_or(0.0),
+                ('D', Some(code)) if code >= 10 => self.aperture = Some(code),
+                ('D', Some(code)) => operation = Some(code),
+                ('M', Some(0 | 2)) => return true,
+                _ => {}
+            }
+        }
+
+        let has_coordinates = target.0.is_some() || target.1.is_some();
+
+        // Coordinates without an operation repeat the previous one
+        let Some(operation) = operation.or(has_coordinates.then_some(self.operation)) else {
+            return false;
+        };
+
+        let target = match self.incremental {
+            true => self.position + Vertex {x: target.0.unwrap_or(0.0), y: target.1.unwrap_or(0.0)},
+            false => Vertex {x: target.0.unwrap_or(self.position.x), y: target.1.unwrap_or(self.position.y)}
+        };
+
+        match operation {
+            1 => self.interpolate(target, offset),
+            2 => self.move_to(target),
+            3 => self.flash(target),
+            _ => {}
+        }
+
+        self.operation = operation;
+        self.position = target;
+
+        false
+    }
+
+    fn coordinate(&self, value: &str) -> Option<f64> {
+        if value.contains('.') {
+            return value.parse::<f64>().ok().map(|n| n * self.scale);
+        }
+
+        let (sign, digits) = match value.strip_prefix('-') {
+            Some(digits) => (-1.0, digits),
+            None => (1.0, value.strip_prefix('+').unwrap_or(value))
+        };
+
+        let digits = match self.trailing_zeros {
+            true => format!("{:0<width$}", digits, width = self.integer_digits + self.decimal_digits),
+            false => digits.to_string()
+        };
+
+        digits.parse::<f64>().ok().map(|n| sign * n * self.scale / 10f64.powi(self.decimal_digits as i32))
+    }
+
+    fn interpolate(&mut self, target: Vertex, offset: Vertex) {
+        let points = match self.interpolation {
+            Interpolation::Linear => vec![self.position, target],
+            _ => self.arc_points(target, offset)
+        };
+
+        if let Some(region) = self.region.as_mut() {
+            let contour = region.last_mut().expect("a region always has a contour");
+
+            if contour.is_empty() {
+                contour.push(self.position);
+            }
+
+            contour.extend(points.into_iter().skip(1));
+            return;
+        }
+
+        let Some(aperture) = self.aperture.and_then(|code| self.apertures.get(&code)) else {
+            return;
+        };
+
+        let primitives: Vec<Primitive> = points.windows(2)
+            .flat_map(|pair| aperture.draw(pair[0], pair[1]))
+            .collect();
+
+        self.add_object(primitives);
+    }
+
+    fn arc_points(&self, target: Vertex, offset: Vertex) -> Vec<Vertex> {
+        let start = self.position;
+        let clockwise = self.interpolation == Interpolation::Clockwise;
+
+        let sweep_between = |centre: Vertex| {
+            let angle = |p: Vertex| (p.y - centre.y).atan2(p.x - centre.x);
+            let mut sweep = angle(target) - angle(start);
+
+            if clockwise && sweep >= 0.0 {
+                sweep -= 2.0 * PI;
+            } else if !clockwise && sweep <= 0.0 {
+                sweep += 2.0 * PI;
+            }
+
+            sweep
+        };
+
+        let (centre, sweep) = if self.multi_quadrant {
+            let centre = start + offset;
+            let sweep = sweep_between(centre);
+
+            // Only a complete circle starts and ends at the same point
+            let sweep = if start.distance_to(&target) < 1e-9 { sweep.signum() * 2.0 * PI } else { sweep };
+
+            (centre, sweep)
+        } else {
+            // Single quadrant offsets are unsigned, so pick the centre giving an arc of at most 90 degrees
+            [(1.0, 1.0), (-1.0, 1.0), (1.0, -1.0), (-1.0, -1.0)].iter()
+                .map(|(sx, sy)| start + Vertex {x: offset.x.abs() * sx, y: offset.y.abs() * sy})
+                .map(|centre| (centre, sweep_between(centre)))
+                .filter(|(_, sweep)| sweep.abs() <= PI / 2.0 + 1e-6)
+                .min_by(|a, b| {
+                    let error = |c: &Vertex| (c.distance_to(&start) - c.distance_to(&target)).abs();
+                    error(&a.0).total_cmp(&error(&b.0))
+                })
+                .unwrap_or((start + offset, sweep_between(start + offset)))
+        };
+
+        let radius = centre.distance_to(&start);
+        let start_angle = (start.y - centre.y).atan2(start.x - centre.x);
+        let count = ((circle_segments(radius) as f64 * sweep.abs() / (2.0 * PI)).ceil() as usize).max(2);
+
+        let mut points: Vec<Vertex> = (0..count)
+            .map(|i| centre + Vertex::from_polar(radius, start_angle + sweep * i as f64 / count as f64))
+            .collect();
+
+        points.push(target);
+        points
+    }
+
+    fn move_to(&mut self, target: Vertex) {
+        if let Some(region) = self.region.as_mut() {
+            region.push(vec![target]);
+        }
+    }
+
+    fn flash(&mut self, target: Vertex) {
+        if self.region.is_some() {
+            return;
+        }
+
+        if let Some(aperture) = self.aperture.and_then(|code| self.apertures.get(&code)) {
+            let primitives = aperture.flash(target);
+            self.add_object(primitives);
+        }
+    }
+
+    fn close_region(&mut self) {
+        let Some(contours) = self.region.take() else {
+            return;
+        };
+
+        let primitives = contours.into_iter()
+            .filter(|contour| contour.len() > 2)
+            .map(|vertices| Primitive::new(true, vec![PolyLine {vertices, closed: true}]))
+            .collect();
+
+        self.add_object(primitives);
+    }
+
+    fn add_object(&mut self, primitives: Vec<Primitive>) {
+        if !primitives.is_empty() {
+            self.objects.push(Object {dark: self.dark, primitives});
+        }
+    }
+}
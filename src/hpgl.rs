@@ -0,0 +1,263 @@
+use std::f64::consts::PI;
+use crate::dxf::generate_gcode_with_power;
+use crate::dxf::polyline::PolyLine;
+use crate::dxf::vertex::Vertex;
+
+const MM_PER_UNIT: f64 = 0.025;
+const DEFAULT_CHORD_ANGLE: f64 = 5.0;
+const DEFAULT_LABEL_TERMINATOR: u8 = 0x03;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PenPass {
+    pub pen: u8,
+    pub power: u8
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct HpglOptions {
+    pub pen_passes: Vec<PenPass>
+}
+
+struct Stroke {
+    line: PolyLine,
+    pen: u8
+}
+
+struct Plotter {
+    strokes: Vec<Stroke>,
+    current: Vec<Vertex>,
+    position: Vertex,
+    pen: u8,
+    pen_down: bool,
+    relative: bool
+}
+
+impl Plotter {
+    fn new() -> Plotter {
+        Plotter {
+            strokes: vec![],
+            current: vec![],
+            position: Vertex {x: 0.0, y: 0.0},
+            pen: 1,
+            pen_down: false,
+            relative: false
+        }
+    }
+
+    fn add_stroke(&mut self, line: PolyLine) {
+        // Pen 0 puts the pen away, so nothing it draws reaches the paper
+        if self.pen != 0 && line.vertices.len() > 1 {
+            self.strokes.push(Stroke {line, pen: self.pen});
+        }
+    }
+
+    fn lift(&mut self) {
+        let vertices = std::mem::take(&mut self.current);
+
+        self.add_stroke(PolyLine {vertices, closed: false});
+    }
+
+    fn set_pen_down(&mut self, down: bool) {
+        if !down {
+            self.lift();
+        }
+
+        self.pen_down = down;
+    }
+
+    fn select_pen(&mut self, pen: u8) {
+        self.lift();
+        self.pen = pen;
+    }
+
+    fn move_to(&mut self, point: Vertex) {
+        if self.pen_down {
+            if self.current.is_empty() {
+                self.current.push(self.position);
+            }
+
+            self.current.push(point);
+        }
+
+        self.position = point;
+    }
+
+    fn plot(&mut self, arguments: &[f64]) {
+        for pair in arguments.chunks_exact(2) {
+            let point = Vertex {x: pair[0], y: pair[1]};
+
+            self.move_to(if self.relative { self.position + point } else { point });
+        }
+    }
+
+    fn arc(&mut self, centre: Vertex, sweep: f64, chord: f64) {
+        let radius = centre.distance_to(&self.position);
+        let start = centre.vector_to(&self.position);
+        let start_angle = start.y.atan2(start.x);
+        let count = (sweep.abs() / chord).ceil().max(1.0) as usize;
+
+        for i in 1..=count {
+            let angle = start_angle + (sweep * i as f64 / count as f64).to_radians();
+
+            self.move_to(centre + Vertex::from_polar(radius, angle));
+        }
+    }
+
+    fn circle(&mut self, radius: f64, chord: f64) {
+        // Circles are always drawn, and leave the pen where it started
+        let count = (360.0 / chord).ceil() as usize;
+        let vertices = (0..count)
+            .map(|i| self.position + Vertex::from_polar(radius, 2.0 * PI * i as f64 / count as f64))
+            .collect();
+
+        self.lift();
+        self.add_stroke(PolyLine {vertices, closed: true});
+    }
+
+    fn rectangle(&mut self, corner: Vertex) {
+        let Vertex {x, y} = self.position;
+        let vertices = vec![self.position, Vertex {x: corner.x, y}, corner, Vertex {x, y: corner.y}];
+
+        self.lift();
+        self.add_stroke(PolyLine {vertices, closed: true});
+    }
+}
+
+pub(crate) fn hpgl_to_gcode(hpgl_contents: &str, options: &HpglOptions) -> String {
+    let strokes = parse(hpgl_contents);
+
+    if options.pen_passes.is_empty() {
+        let lines: Vec<PolyLine> = strokes.into_iter().map(|stroke| stroke.line).collect();
+
+        return generate_gcode_with_power(&lines, 255);
+    }
+
+    options.pen_passes.iter()
+        .map(|pass| {
+            let lines: Vec<PolyLine> = strokes.iter()
+                .filter(|stroke| stroke.pen == pass.pen)
+                .map(|stroke| stroke.line.clone())
+                .collect();
+
+            generate_gcode_with_power(&lines, pass.power)
+        })
+        .collect()
+}
+
+fn parse(hpgl_contents: &str) -> Vec<Stroke> {
+    let bytes = hpgl_contents.as_bytes();
+    let mut plotter = Plotter::new();
+    let mut terminator = DEFAULT_LABEL_TERMINATOR;
+    let mut position = 0;
+
+    while position + 1 < bytes.len() {
+        if !bytes[position].is_ascii_alphabetic() || !bytes[position + 1].is_ascii_alphabetic() {
+            position += 1;
+            continue;
+        }
+
+        let mnemonic = [bytes[position].to_ascii_uppercase(), bytes[position + 1].to_ascii_uppercase()];
+        position += 2;
+
+        // Label text can contain anything, including semicolons, up to its terminator
+        match &mnemonic {
+            b"LB" => {
+                position += bytes[position..].iter().position(|&b| b == terminator).map_or(bytes.len(), |end| end + 1);
+                continue;
+            },
+
+            b"DT" => {
+                terminator = match bytes.get(position) {
+                    Some(&b) if b != b';' => {
+                        position += 1;
+                        b
+                    },
+                    _ => DEFAULT_LABEL_TERMINATOR
+                };
+                continue;
+            },
+
+            _ => {}
+        }
+
+        let end = bytes[position..].iter()
+            .position(|b| b.is_ascii_alphabetic() || *b == b';')
+            .map_or(bytes.len(), |end| position + end);
+
+        let arguments: Vec<f64> = hpgl_contents[position..end]
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|argument| !argument.is_empty())
+            .map_while(|argument| argument.parse().ok())
+            .collect();
+
+        position = end;
+
+        let argument = |i: usize| arguments.get(i).copied();
+        let chord = |i: usize| argument(i).map_or(DEFAULT_CHORD_ANGLE, f64::abs).clamp(0.5, 180.0);
+
+        match &mnemonic {
+            b"IN" | b"DF" => {
+                plotter.set_pen_down(false);
+                plotter.relative = false;
+            },
+
+            b"SP" => plotter.select_pen(argument(0).unwrap_or(0.0) as u8),
+
+            b"PU" => {
+                plotter.set_pen_down(false);
+                plotter.plot(&arguments);
+            },
+
+            b"PD" => {
+                plotter.set_pen_down(true);
+                plotter.plot(&arguments);
+            },
+
+            b"PA" => {
+                plotter.relative = false;
+                plotter.plot(&arguments);
+            },
+
+            b"PR" => {
+                plotter.relative = true;
+                plotter.plot(&arguments);
+            },
+
+            b"AA" | b"AR" => {
+                let (Some(x), Some(y), Some(sweep)) = (argument(0), argument(1), argument(2)) else { continue };
+                let mut centre = Vertex {x, y};
+
+                if &mnemonic == b"AR" {
+                    centre = plotter.position + centre;
+                }
+
+                plotter.arc(centre, sweep, chord(3));
+            },
+
+            b"CI" => {
+                if let Some(radius) = argument(0) {
+                    plotter.circle(radius.abs(), chord(1));
+                }
+            },
+
+            b"EA" | b"ER" => {
+                let (Some(x), Some(y)) = (argument(0), argument(1)) else { continue };
+                let mut corner = Vertex {x, y};
+
+                if &mnemonic == b"ER" {
+                    corner = plotter.position + corner;
+                }
+
+                plotter.rectangle(corner);
+            },
+
+            _ => {}
+        }
+    }
+
+    plotter.lift();
+
+    plotter.strokes.into_iter()
+        .map(|stroke| Stroke {line: stroke.line.scale(MM_PER_UNIT), pen: stroke.pen})
+        .collect()
+}
@@ -1,14 +1,32 @@
 use std::os::raw::c_char;
 use std::ffi::{CStr, CString};
+use std::io::{self, BufRead};
 
 mod dxf;
 mod bmp;
+mod svg;
+mod hpgl;
+mod gerber;
+mod excellon;
+mod gcode;
+mod preview;
+mod estimate;
+mod machine;
 
-const GCODE_HEADER: &'static str = "G01\n";
-const GCODE_FOOTER: &'static str = "M05 F2000 X0 Y0";
+pub use dxf::{Drill, DxfOptions, FillOptions, FillRule, HatchMode, OutputUnits, PointAction, Pulse, Unit};
+pub use bmp::{Anchor, Colour, ColourPass, DotShape, GreyscaleMode, Halftone, ImageError, RasterMode, RasterOptions, ScanDirection};
+pub use svg::{SvgError, SvgOptions};
+pub use hpgl::{HpglOptions, PenPass};
+pub use gerber::{GerberError, GerberOptions};
+pub use excellon::{ExcellonOptions, HoleAction};
+pub use gcode::{Block, GcodeError, GcodeOptions, ModalState, Motion, Move, Position, Program, Word};
+pub use preview::{PreviewOptions, SimulationOptions};
+pub use estimate::{Estimate, EstimateOptions};
+pub use machine::{MachineProfile, Origin, Violation};
 
 #[no_mangle]
-pub extern fn dxf_to_gcode(s: *const c_char) -> *mut c_char {
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn dxf_to_gcode(s: *const c_char) -> *mut c_char {
     let c_str = unsafe {
         assert!(!s.is_null());
 
@@ -24,29 +42,172 @@ pub extern fn dxf_to_gcode(s: *const c_char) -> *mut c_char {
 }
 
 #[no_mangle]
-pub extern fn gcode_free(s: *mut c_char) {
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn gcode_free(s: *mut c_char) {
     unsafe {
         if s.is_null() {
             return
         }
 
-        CString::from_raw(s)
+        drop(CString::from_raw(s))
     };
 }
 
 pub fn process(dxf_contents: &str) -> String {
-    wrap_gcode(dxf::dxf_to_gcode(dxf_contents).as_str())
+    process_with_options(dxf_contents, &DxfOptions::default())
 }
 
+pub fn process_with_options(dxf_contents: &str, options: &DxfOptions) -> String {
+    wrap_gcode_in(dxf::dxf_to_gcode(dxf_contents, options).as_str(), options.output_units)
+}
+
+pub fn export_toolpaths(dxf_contents: &str, options: &DxfOptions) -> String {
+    dxf::dxf_to_dxf(dxf_contents, options)
+}
+
+pub fn export_toolpaths_bytes(dxf_contents: &[u8], options: &DxfOptions) -> io::Result<String> {
+    export_toolpaths_reader(dxf_contents, options)
+}
+
+pub fn export_toolpaths_reader(reader: impl BufRead, options: &DxfOptions) -> io::Result<String> {
+    dxf::dxf_reader_to_dxf(reader, options)
+}
+
+pub fn process_bytes(dxf_contents: &[u8]) -> io::Result<String> {
+    process_bytes_with_options(dxf_contents, &DxfOptions::default())
+}
+
+pub fn process_bytes_with_options(dxf_contents: &[u8], options: &DxfOptions) -> io::Result<String> {
+    process_reader_with_options(dxf_contents, options)
+}
+
+pub fn process_reader(reader: impl BufRead) -> io::Result<String> {
+    process_reader_with_options(reader, &DxfOptions::default())
+}
+
+pub fn process_reader_with_options(reader: impl BufRead, options: &DxfOptions) -> io::Result<String> {
+    dxf::dxf_reader_to_gcode(reader, options).map(|gcode| wrap_gcode_in(gcode.as_str(), options.output_units))
+}
+
+pub fn process_svg(svg_contents: &str) -> Result<String, SvgError> {
+    process_svg_with_options(svg_contents, &SvgOptions::default())
+}
+
+pub fn process_svg_with_options(svg_contents: &str, options: &SvgOptions) -> Result<String, SvgError> {
+    svg::svg_to_gcode(svg_contents, options).map(|gcode| wrap_gcode(gcode.as_str()))
+}
+
+pub fn process_hpgl(hpgl_contents: &str) -> String {
+    process_hpgl_with_options(hpgl_contents, &HpglOptions::default())
+}
+
+pub fn process_hpgl_with_options(hpgl_contents: &str, options: &HpglOptions) -> String {
+    wrap_gcode(hpgl::hpgl_to_gcode(hpgl_contents, options).as_str())
+}
+
+pub fn process_gerber(gerber_contents: &str) -> Result<String, GerberError> {
+    process_gerber_with_options(gerber_contents, &GerberOptions::default())
+}
+
+pub fn process_gerber_with_options(gerber_contents: &str, options: &GerberOptions) -> Result<String, GerberError> {
+    gerber::gerber_to_gcode(gerber_contents, options).map(|gcode| wrap_gcode(gcode.as_str()))
+}
+
+pub fn process_excellon(excellon_contents: &str) -> String {
+    process_excellon_with_options(excellon_contents, &ExcellonOptions::default())
+}
+
+pub fn process_excellon_with_options(excellon_contents: &str, options: &ExcellonOptions) -> String {
+    wrap_gcode(excellon::excellon_to_gcode(excellon_contents, options).as_str())
+}
+
+pub fn process_gcode(gcode_contents: &str) -> Result<String, GcodeError> {
+    process_gcode_with_options(gcode_contents, &GcodeOptions::default())
+}
+
+pub fn process_gcode_with_options(gcode_contents: &str, options: &GcodeOptions) -> Result<String, GcodeError> {
+    gcode::gcode_to_gcode(gcode_contents, options)
+}
+
+pub fn preview_svg(gcode_contents: &str, options: &PreviewOptions) -> Result<String, GcodeError> {
+    Program::parse(gcode_contents).map(|program| preview_program_svg(&program, options))
+}
+
+pub fn preview_program_svg(program: &Program, options: &PreviewOptions) -> String {
+    preview::moves_to_svg(&program.moves(), options)
+}
+
+pub fn simulate_png(gcode_contents: &str, options: &SimulationOptions) -> Result<Vec<u8>, GcodeError> {
+    Program::parse(gcode_contents).and_then(|program| simulate_program_png(&program, options))
+}
+
+pub fn simulate_program_png(program: &Program, options: &SimulationOptions) -> Result<Vec<u8>, GcodeError> {
+    preview::moves_to_png(&program.moves(), options)
+}
+
+pub fn estimate(gcode_contents: &str, options: &EstimateOptions) -> Result<Estimate, GcodeError> {
+    Program::parse(gcode_contents).map(|program| estimate_program(&program, options))
+}
+
+pub fn estimate_program(program: &Program, options: &EstimateOptions) -> Estimate {
+    estimate::estimate_moves(&program.moves(), options)
+}
+
+pub fn validate(gcode_contents: &str, profile: &MachineProfile) -> Result<Vec<Violation>, GcodeError> {
+    Program::parse(gcode_contents).map(|program| validate_program(&program, profile))
+}
+
+pub fn validate_program(program: &Program, profile: &MachineProfile) -> Vec<Violation> {
+    machine::validate_program(program, profile)
+}
 
-pub fn process_bmp(bmp_contents: &[u8], dpi: u16) -> String {
-    wrap_gcode(bmp::bmp_to_gcode(bmp_contents, dpi).as_str())
+pub fn fit_to_bed(gcode_contents: &str, profile: &MachineProfile) -> Result<Option<String>, GcodeError> {
+    let mut program = Program::parse(gcode_contents)?;
+
+    Ok(fit_program_to_bed(&mut program, profile).map(|_| program.to_string()))
+}
+
+pub fn fit_program_to_bed(program: &mut Program, profile: &MachineProfile) -> Option<(f64, f64)> {
+    machine::fit_program(program, profile)
+}
+
+pub fn process_bmp(image_contents: &[u8], dpi: u16) -> Result<String, ImageError> {
+    process_bmp_with_options(image_contents, &RasterOptions::new(dpi))
+}
+
+pub fn process_bmp_with_options(image_contents: &[u8], options: &RasterOptions) -> Result<String, ImageError> {
+    bmp::bmp_to_gcode(image_contents, options).map(|gcode| wrap_gcode(gcode.as_str()))
+}
+
+pub fn process_with_image(dxf_contents: &str, image_contents: &[u8], dxf_options: &DxfOptions, raster_options: &RasterOptions) -> Result<String, ImageError> {
+    let units = dxf_options.output_units;
+    let mut blocks = vec![];
+
+    // The engraving and the cut share one unit system so they stay registered with each other
+    if dxf_options.unit_command {
+        blocks.push(units.command());
+    }
+
+    let mut engraving = bmp::bmp_to_blocks(image_contents, raster_options)?;
+    units.convert_millimetres(&mut engraving);
+
+    blocks.append(&mut engraving);
+    blocks.extend(dxf::dxf_to_blocks(dxf_contents, dxf_options));
+
+    Ok(wrap_gcode_in(gcode::write_blocks(&blocks).as_str(), units))
 }
 
 fn wrap_gcode(gcode: &str) -> String {
-    String::from(GCODE_HEADER)
-        + gcode
-        + GCODE_FOOTER
+    wrap_gcode_in(gcode, OutputUnits::Millimetres)
+}
+
+fn wrap_gcode_in(gcode: &str, units: OutputUnits) -> String {
+    let header = Block::new(vec![Word::code('G', 1)]);
+    let mut footer = [Block::new(vec![Word::code('M', 5), Word::number('F', 2000.0), Word::new('X', 0.0, 0), Word::new('Y', 0.0, 0)])];
+
+    units.convert(&mut footer);
+
+    gcode::write_blocks(&[header]) + gcode + footer[0].to_string().as_str()
 }
 
 
@@ -0,0 +1,143 @@
+use std::fmt;
+use crate::gcode::{ModalState, Move, Program};
+
+const TOLERANCE: f64 = 1e-6;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Origin {
+    BottomLeft,
+    TopLeft,
+    Centre
+}
+
+#[derive(Debug, Clone)]
+pub struct MachineProfile {
+    pub bed_width: f64,
+    pub bed_height: f64,
+    pub origin: Origin,
+    pub max_feed: f64,
+    pub max_power: f64
+}
+
+// Lines are those the blocks were read from, or counted one block to a line for generated programs
+#[derive(Debug, Clone, PartialEq)]
+pub enum Violation {
+    NegativeCoordinate {line: usize, x: f64, y: f64},
+    OutOfBounds {line: usize, x: f64, y: f64},
+    Feed {line: usize, feed: f64},
+    Power {line: usize, power: f64}
+}
+
+impl Default for MachineProfile {
+    fn default() -> MachineProfile {
+        MachineProfile {
+            bed_width: 300.0,
+            bed_height: 200.0,
+            origin: Origin::BottomLeft,
+            max_feed: 6000.0,
+            max_power: 255.0
+        }
+    }
+}
+
+impl MachineProfile {
+    // Lower left and upper right corners of the bed in machine coordinates
+    pub fn bounds(&self) -> ((f64, f64), (f64, f64)) {
+        let (w, h) = (self.bed_width, self.bed_height);
+
+        match self.origin {
+            Origin::BottomLeft => ((0.0, 0.0), (w, h)),
+            Origin::TopLeft => ((0.0, -h), (w, 0.0)),
+            Origin::Centre => ((-w / 2.0, -h / 2.0), (w / 2.0, h / 2.0))
+        }
+    }
+
+    fn contains(&self, (x, y): (f64, f64)) -> bool {
+        let ((min_x, min_y), (max_x, max_y)) = self.bounds();
+
+        x >= min_x - TOLERANCE && x <= max_x + TOLERANCE && y >= min_y - TOLERANCE && y <= max_y + TOLERANCE
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::NegativeCoordinate {line, x, y} => write!(f, "line {}: negative coordinate X{:.2} Y{:.2}", line, x, y),
+            Violation::OutOfBounds {line, x, y} => write!(f, "line {}: X{:.2} Y{:.2} is outside the bed", line, x, y),
+            Violation::Feed {line, feed} => write!(f, "line {}: feed F{} is over the machine limit", line, feed),
+            Violation::Power {line, power} => write!(f, "line {}: power S{} is over the machine limit", line, power)
+        }
+    }
+}
+
+pub(crate) fn validate_program(program: &Program, profile: &MachineProfile) -> Vec<Violation> {
+    let mut state = ModalState::default();
+    let mut violations = vec![];
+
+    for (index, block) in program.blocks.iter().enumerate() {
+        let line = block.line.unwrap_or(index + 1);
+        let moves = state.apply(block);
+
+        if block.word('S').is_some() && state.power > profile.max_power {
+            violations.push(Violation::Power {line, power: state.power});
+        }
+
+        if let Some(feed) = moves.iter().filter_map(|step| step.feed).find(|feed| *feed > profile.max_feed) {
+            violations.push(Violation::Feed {line, feed});
+        }
+
+        // Each block is reported once, at the first point it moves to off the bed
+        let outside = moves.iter()
+            .flat_map(|step| step.points().into_iter().skip(1))
+            .find(|point| !profile.contains(*point));
+
+        if let Some((x, y)) = outside {
+            violations.push(match profile.origin == Origin::BottomLeft && (x < 0.0 || y < 0.0) {
+                true => Violation::NegativeCoordinate {line, x, y},
+                false => Violation::OutOfBounds {line, x, y}
+            });
+        }
+    }
+
+    violations
+}
+
+// Shifts the work so it lies on the bed, returning the offset used or None when it is too big to fit
+pub(crate) fn fit_program(program: &mut Program, profile: &MachineProfile) -> Option<(f64, f64)> {
+    let moves = program.moves();
+
+    // Travel to and from the origin is left out, since it moves along with the work
+    let work: Vec<&Move> = match moves.iter().any(is_work) {
+        true => moves.iter().filter(|step| is_work(step)).collect(),
+        false => moves.iter().collect()
+    };
+
+    let points: Vec<(f64, f64)> = work.iter().flat_map(|step| step.points()).collect();
+
+    if points.is_empty() {
+        return Some((0.0, 0.0));
+    }
+
+    let ((bed_min_x, bed_min_y), (bed_max_x, bed_max_y)) = profile.bounds();
+    let (min_x, max_x) = points.iter().fold((f64::MAX, f64::MIN), |(a, b), p| (a.min(p.0), b.max(p.0)));
+    let (min_y, max_y) = points.iter().fold((f64::MAX, f64::MIN), |(a, b), p| (a.min(p.1), b.max(p.1)));
+
+    let shift = |min: f64, max: f64, bed_min: f64, bed_max: f64| match (min < bed_min, max > bed_max) {
+        _ if max - min > bed_max - bed_min => None,
+        (true, _) => Some(bed_min - min),
+        (_, true) => Some(bed_max - max),
+        _ => Some(0.0)
+    };
+
+    let offset = (shift(min_x, max_x, bed_min_x, bed_max_x)?, shift(min_y, max_y, bed_min_y, bed_max_y)?);
+
+    if offset != (0.0, 0.0) {
+        program.translate(offset.0, offset.1);
+    }
+
+    Some(offset)
+}
+
+fn is_work(step: &Move) -> bool {
+    step.power > 0.0 || step.to.z < step.from.z
+}
@@ -1,28 +1,114 @@
-use rust_cnc::{process};
+use rust_cnc::{estimate, fit_to_bed, process_excellon, process_gcode, process_gerber, process_hpgl, process_reader, process_svg, validate, Estimate, EstimateOptions, MachineProfile, Origin};
 use std::process::exit;
 use std::env::Args;
-use std::fs::read_to_string;
-use std::io::Error;
+use std::fs::File;
+use std::io::{BufReader, Error, ErrorKind};
+use std::path::Path;
 
 pub struct Config {
-    pub dxf_file: String
+    pub input_file: String,
+    pub estimate: bool,
+    pub validate: bool,
+    pub fit: bool,
+    pub estimate_options: EstimateOptions,
+    pub profile: MachineProfile
 }
 
 impl Config {
     pub fn new(mut args: Args) -> Result<Config, &'static str> {
         args.next();
 
-        let dxf_file = match args.next() {
+        let mut config = Config {
+            input_file: String::new(),
+            estimate: false,
+            validate: false,
+            fit: false,
+            estimate_options: EstimateOptions::default(),
+            profile: MachineProfile::default()
+        };
+
+        let mut input_file = None;
+
+        for arg in args {
+            let Some(option) = arg.strip_prefix("--") else {
+                input_file = Some(arg);
+                continue;
+            };
+
+            let (name, value) = option.split_once('=').unwrap_or((option, ""));
+            let number = || value.parse::<f64>().map_err(|_| "Option values must be numbers");
+
+            match name {
+                "estimate" => config.estimate = true,
+                "validate" => config.validate = true,
+                "fit" => config.fit = true,
+                "acceleration" => config.estimate_options.acceleration = number()?,
+                "junction-deviation" => config.estimate_options.junction_deviation = number()?,
+                "bed-width" => config.profile.bed_width = number()?,
+                "bed-height" => config.profile.bed_height = number()?,
+                "max-power" => config.profile.max_power = number()?,
+
+                "max-feed" => {
+                    config.estimate_options.max_feed = number()?;
+                    config.profile.max_feed = config.estimate_options.max_feed;
+                },
+
+                "origin" => config.profile.origin = match value {
+                    "bottom-left" => Origin::BottomLeft,
+                    "top-left" => Origin::TopLeft,
+                    "centre" | "center" => Origin::Centre,
+                    _ => return Err("Origin must be bottom-left, top-left or centre")
+                },
+
+                _ => return Err("Unknown option")
+            }
+        }
+
+        config.input_file = match input_file {
             Some(s) => s,
-            None => return Err("No DXF file provided")
+            None => return Err("No input file provided")
         };
 
-        Ok(Config {dxf_file})
+        Ok(config)
     }
 }
 
-fn read_dxf(file: &str) -> Result<String, Error> {
-    read_to_string(file)
+fn convert_dxf(file: &str) -> Result<String, Error> {
+    process_reader(BufReader::new(File::open(file)?))
+}
+
+fn convert_svg(file: &str) -> Result<String, Error> {
+    process_svg(&std::fs::read_to_string(file)?).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+}
+
+fn convert_hpgl(file: &str) -> Result<String, Error> {
+    Ok(process_hpgl(&String::from_utf8_lossy(&std::fs::read(file)?)))
+}
+
+fn convert_gerber(file: &str) -> Result<String, Error> {
+    process_gerber(&std::fs::read_to_string(file)?).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+}
+
+fn convert_excellon(file: &str) -> Result<String, Error> {
+    Ok(process_excellon(&std::fs::read_to_string(file)?))
+}
+
+fn convert_gcode(file: &str) -> Result<String, Error> {
+    process_gcode(&std::fs::read_to_string(file)?).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+}
+
+fn fail(context: &str, error: impl std::fmt::Display) -> ! {
+    eprintln!("{}: {}", context, error);
+    exit(1);
+}
+
+fn print_estimate(estimate: &Estimate) {
+    let seconds = estimate.duration.round() as u64;
+
+    println!("Cutting length: {:.1} mm", estimate.cut_length);
+    println!("Travel length: {:.1} mm", estimate.travel_length);
+    println!("Laser on events: {}", estimate.laser_events);
+    println!("Estimated time: {}:{:02}:{:02}", seconds / 3600, seconds / 60 % 60, seconds % 60);
 }
 
 fn main() {
@@ -31,12 +117,51 @@ fn main() {
         exit(1);
     });
 
-    let dxf_contents = read_dxf(&config.dxf_file).unwrap_or_else(|e| {
+    let extension = Path::new(&config.input_file).extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase);
+
+    let converted = match extension.as_deref() {
+        Some("svg") => convert_svg(&config.input_file),
+        Some("plt" | "hpgl" | "hpg") => convert_hpgl(&config.input_file),
+        Some("gbr" | "ger" | "gtl" | "gbl") => convert_gerber(&config.input_file),
+        Some("drl" | "xln" | "exc") => convert_excellon(&config.input_file),
+        Some("nc" | "gcode" | "ngc" | "gc") => convert_gcode(&config.input_file),
+        _ => convert_dxf(&config.input_file)
+    };
+
+    let mut lines = converted.unwrap_or_else(|e| {
         eprintln!("Processing failed: {}", e);
         exit(1);
     });
 
-    let lines = process(&dxf_contents.replace('\r', ""));
+    if config.fit {
+        match fit_to_bed(&lines, &config.profile) {
+            Ok(Some(fitted)) => lines = fitted,
+            Ok(None) => eprintln!("The job is too big to fit on the bed"),
+            Err(e) => fail("Fitting failed", e)
+        }
+    }
+
+    let violations = match config.validate || config.fit {
+        true => validate(&lines, &config.profile).unwrap_or_else(|e| fail("Validation failed", e)),
+        false => vec![]
+    };
+
+    for violation in violations.iter() {
+        eprintln!("{}", violation);
+    }
 
-    println!("{}", lines);
+    if config.estimate {
+        match estimate(&lines, &config.estimate_options) {
+            Ok(estimate) => print_estimate(&estimate),
+            Err(e) => fail("Estimating failed", e)
+        }
+    } else {
+        println!("{}", lines.trim_end());
+    }
+
+    if config.validate && !violations.is_empty() {
+        exit(1);
+    }
 }
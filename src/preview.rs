@@ -0,0 +1,47 @@
+use crate::gcode::Move;
+
+mod raster;
+mod svg;
+
+pub(crate) use crate::preview::raster::moves_to_png;
+pub(crate) use crate::preview::svg::moves_to_svg;
+
+#[derive(Debug, Clone)]
+pub struct PreviewOptions {
+    pub bed_width: f64,
+    pub bed_height: f64,
+    pub travel: bool,
+    pub arrows: bool
+}
+
+#[derive(Debug, Clone)]
+pub struct SimulationOptions {
+    pub dpi: u16,
+    pub max_power: f64,
+    pub feed: f64
+}
+
+impl SimulationOptions {
+    pub fn new(dpi: u16) -> SimulationOptions {
+        SimulationOptions {
+            dpi,
+            max_power: 255.0,
+            feed: 1000.0
+        }
+    }
+}
+
+impl Default for PreviewOptions {
+    fn default() -> PreviewOptions {
+        PreviewOptions {
+            bed_width: 300.0,
+            bed_height: 200.0,
+            travel: true,
+            arrows: true
+        }
+    }
+}
+
+fn is_cut(step: &Move) -> bool {
+    step.power > 0.0
+}
@@ -0,0 +1,126 @@
+use png::{BitDepth, ColorType, Encoder, PixelDimensions, Unit};
+use crate::gcode::{GcodeError, Move};
+use crate::preview::{is_cut, SimulationOptions};
+
+const SECONDS_PER_MINUTE: f64 = 60.0;
+
+// Large enough for a full bed at high resolution, without letting a stray coordinate exhaust memory
+const MAX_PIXELS: u64 = 1 << 26;
+
+struct Canvas {
+    width: usize,
+    height: usize,
+    column: i64,
+    row: i64,
+    exposure: Vec<f64>
+}
+
+impl Canvas {
+    fn new(moves: &[Move], pixel: f64) -> Result<Canvas, GcodeError> {
+        let cells: Vec<(i64, i64)> = moves.iter()
+            .filter(|step| is_cut(step))
+            .flat_map(Move::points)
+            .map(|(x, y)| ((x / pixel).round() as i64, (y / pixel).round() as i64))
+            .collect();
+
+        let (min_column, max_column) = cells.iter().fold((i64::MAX, i64::MIN), |(a, b), (c, _)| (a.min(*c), b.max(*c)));
+        let (min_row, max_row) = cells.iter().fold((i64::MAX, i64::MIN), |(a, b), (_, r)| (a.min(*r), b.max(*r)));
+
+        // Nothing burned still gives a blank image
+        if cells.is_empty() {
+            return Ok(Canvas {width: 1, height: 1, column: 0, row: 0, exposure: vec![0.0]});
+        }
+
+        let width = max_column.abs_diff(min_column).saturating_add(1);
+        let height = max_row.abs_diff(min_row).saturating_add(1);
+
+        if width.saturating_mul(height) > MAX_PIXELS {
+            return Err(GcodeError::ImageTooLarge(width as usize, height as usize));
+        }
+
+        let (width, height) = (width as usize, height as usize);
+
+        Ok(Canvas {width, height, column: min_column, row: max_row, exposure: vec![0.0; width * height]})
+    }
+
+    fn expose(&mut self, x: f64, y: f64, amount: f64) {
+        let column = x.round() as i64 - self.column;
+        let row = self.row - y.round() as i64;
+
+        if column >= 0 && row >= 0 && (column as usize) < self.width && (row as usize) < self.height {
+            self.exposure[row as usize * self.width + column as usize] += amount;
+        }
+    }
+}
+
+pub fn moves_to_png(moves: &[Move], options: &SimulationOptions) -> Result<Vec<u8>, GcodeError> {
+    let pixel = 25.4 / options.dpi.max(1) as f64;
+    let mut canvas = Canvas::new(moves, pixel)?;
+
+    // A pixel crossed once at full power and the reference feed comes out black
+    let pixel_time = pixel / options.feed;
+
+    for step in moves.iter().filter(|step| is_cut(step)) {
+        let strength = step.power / options.max_power;
+        let feed = step.feed.filter(|feed| *feed > 0.0).unwrap_or(options.feed);
+
+        if step.dwell > 0.0 {
+            canvas.expose(step.to.x / pixel, step.to.y / pixel, strength * step.dwell / SECONDS_PER_MINUTE / pixel_time);
+        }
+
+        for pair in step.points().windows(2) {
+            let (from, to) = ((pair[0].0 / pixel, pair[0].1 / pixel), (pair[1].0 / pixel, pair[1].1 / pixel));
+            let length = (to.0 - from.0).hypot(to.1 - from.1) * pixel;
+
+            // Each piece between pixel edges burns the pixel it lies in for as long as the head takes to cross it
+            let cuts = crossings(from, to);
+
+            for piece in cuts.windows(2) {
+                let t = (piece[0] + piece[1]) / 2.0;
+                let (x, y) = (from.0 + (to.0 - from.0) * t, from.1 + (to.1 - from.1) * t);
+
+                canvas.expose(x, y, strength * length * (piece[1] - piece[0]) / feed / pixel_time);
+            }
+        }
+    }
+
+    let pixels: Vec<u8> = canvas.exposure.iter()
+        .map(|exposure| (255.0 * (1.0 - exposure.clamp(0.0, 1.0))).round() as u8)
+        .collect();
+
+    Ok(encode(&pixels, canvas.width as u32, canvas.height as u32, options.dpi))
+}
+
+// Where along a line, from 0 to 1, it passes between pixels
+fn crossings(from: (f64, f64), to: (f64, f64)) -> Vec<f64> {
+    let mut cuts = vec![0.0, 1.0];
+
+    for (a, b) in [(from.0, to.0), (from.1, to.1)] {
+        let (low, high) = (a.min(b), a.max(b));
+        let mut edge = (low - 0.5).ceil() + 0.5;
+
+        while edge < high {
+            cuts.push((edge - a) / (b - a));
+            edge += 1.0;
+        }
+    }
+
+    cuts.sort_by(f64::total_cmp);
+    cuts
+}
+
+fn encode(pixels: &[u8], width: u32, height: u32, dpi: u16) -> Vec<u8> {
+    let mut data = vec![];
+    let pixels_per_metre = (dpi as f64 / 0.0254).round() as u32;
+
+    let mut encoder = Encoder::new(&mut data, width, height);
+    encoder.set_color(ColorType::Grayscale);
+    encoder.set_depth(BitDepth::Eight);
+    encoder.set_pixel_dims(Some(PixelDimensions {xppu: pixels_per_metre, yppu: pixels_per_metre, unit: Unit::Meter}));
+
+    let mut writer = encoder.write_header().expect("writing to memory cannot fail");
+    writer.write_image_data(pixels).expect("the image is the size given in its header");
+    writer.finish().expect("writing to memory cannot fail");
+
+    data
+}
@@ -0,0 +1,181 @@
+use crate::gcode::{Motion, Move};
+use crate::preview::{is_cut, PreviewOptions};
+
+const BED_COLOUR: &str = "#999999";
+const TRAVEL_COLOUR: &str = "#888888";
+const PLUNGE_COLOUR: &str = "#000000";
+
+struct Run {
+    cut: bool,
+    power: f64,
+    path: String,
+    start: (f64, f64),
+    end: (f64, f64),
+    heading: (f64, f64)
+}
+
+pub fn moves_to_svg(moves: &[Move], options: &PreviewOptions) -> String {
+    let max_power = moves.iter().map(|step| step.power).fold(0.0, f64::max);
+    let visible: Vec<&Move> = moves.iter().filter(|step| is_cut(step) || options.travel).collect();
+
+    let (mut min_x, mut min_y, mut max_x, mut max_y) = (0.0f64, 0.0f64, options.bed_width, options.bed_height);
+
+    for (x, y) in visible.iter().flat_map(|step| step.points()) {
+        (min_x, min_y, max_x, max_y) = (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y));
+    }
+
+    let size = (max_x - min_x).max(max_y - min_y).max(1.0);
+    let margin = size * 0.02;
+    let stroke = size / 1000.0;
+    let (width, height) = (max_x - min_x + 2.0 * margin, max_y - min_y + 2.0 * margin);
+
+    // Y is flipped so the machine origin ends up in the bottom left
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}mm\" height=\"{}mm\" viewBox=\"{} {} {} {}\">\n",
+        number(width), number(height), number(min_x - margin), number(-max_y - margin), number(width), number(height)
+    );
+
+    svg += format!(
+        "<rect x=\"0\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\"/>\n",
+        number(-options.bed_height), number(options.bed_width), number(options.bed_height), BED_COLOUR, number(stroke * 2.0)
+    ).as_str();
+
+    for run in runs(moves, options) {
+        let colour = if run.cut { power_colour(run.power, max_power) } else { TRAVEL_COLOUR.to_string() };
+
+        let dashes = match run.cut {
+            true => String::new(),
+            false => format!(" stroke-dasharray=\"{} {}\"", number(stroke * 4.0), number(stroke * 4.0))
+        };
+
+        svg += format!(
+            "<path d=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\"{}/>\n",
+            run.path.trim_end(), colour, number(stroke), dashes
+        ).as_str();
+
+        if run.cut {
+            svg += format!(
+                "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"{}\"/>\n",
+                number(run.start.0), number(-run.start.1), number(stroke * 3.0), colour
+            ).as_str();
+
+            if options.arrows {
+                svg += arrow(run.end, run.heading, stroke * 8.0, &colour).as_str();
+            }
+        }
+    }
+
+    // Drilling shows up as plunges on the spot rather than moves across the bed
+    for step in moves.iter().filter(|step| is_plunge(step)) {
+        svg += format!(
+            "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\"/>\n",
+            number(step.to.x), number(-step.to.y), number(stroke * 5.0), PLUNGE_COLOUR, number(stroke)
+        ).as_str();
+    }
+
+    svg + "</svg>\n"
+}
+
+fn runs(moves: &[Move], options: &PreviewOptions) -> Vec<Run> {
+    let mut runs: Vec<Run> = vec![];
+
+    for step in moves {
+        let points = step.points();
+        let (from, to) = ((step.from.x, step.from.y), (step.to.x, step.to.y));
+
+        if step.centre.is_none() && (to.0 - from.0).hypot(to.1 - from.1) < 1e-9 {
+            continue;
+        }
+
+        let cut = is_cut(step);
+
+        if !cut && !options.travel {
+            continue;
+        }
+
+        let continues = runs.last().is_some_and(|run| {
+            run.cut == cut && run.power == step.power && (run.end.0 - from.0).hypot(run.end.1 - from.1) < 1e-6
+        });
+
+        if !continues {
+            runs.push(Run {
+                cut,
+                power: step.power,
+                path: format!("M {} {} ", number(from.0), number(-from.1)),
+                start: from,
+                end: from,
+                heading: (0.0, 0.0)
+            });
+        }
+
+        let Some(run) = runs.last_mut() else {
+            continue;
+        };
+
+        run.path += segment(step).as_str();
+        run.end = to;
+
+        if let [.., a, b] = points.as_slice() {
+            run.heading = (b.0 - a.0, b.1 - a.1);
+        }
+    }
+
+    runs
+}
+
+fn segment(step: &Move) -> String {
+    let Some(centre) = step.centre else {
+        return format!("L {} {} ", number(step.to.x), number(-step.to.y));
+    };
+
+    let (radius, start, sweep) = step.arc_angles(centre);
+
+    // SVG can't draw a whole circle as one arc, so every arc is drawn in two halves
+    let middle = start + sweep / 2.0;
+    let (mid_x, mid_y) = (centre.0 + radius * middle.cos(), centre.1 + radius * middle.sin());
+    // Y is flipped for the page, so clockwise moves sweep towards positive SVG angles
+    let flag = if step.motion == Motion::ClockwiseArc { 1 } else { 0 };
+
+    [(mid_x, mid_y), (step.to.x, step.to.y)].iter()
+        .map(|(x, y)| format!("A {} {} 0 0 {} {} {} ", number(radius), number(radius), flag, number(*x), number(-y)))
+        .collect()
+}
+
+fn arrow(tip: (f64, f64), heading: (f64, f64), size: f64, colour: &str) -> String {
+    let length = heading.0.hypot(heading.1);
+
+    if length < 1e-12 {
+        return String::new();
+    }
+
+    let (dx, dy) = (heading.0 / length * size, heading.1 / length * size);
+    let back = (tip.0 - dx, tip.1 - dy);
+    let left = (back.0 - dy / 2.0, back.1 + dx / 2.0);
+    let right = (back.0 + dy / 2.0, back.1 - dx / 2.0);
+
+    format!(
+        "<path d=\"M {} {} L {} {} L {} {} Z\" fill=\"{}\"/>\n",
+        number(tip.0), number(-tip.1), number(left.0), number(-left.1), number(right.0), number(-right.1), colour
+    )
+}
+
+fn is_plunge(step: &Move) -> bool {
+    step.motion == Motion::Linear && step.to.z < step.from.z && (step.to.x - step.from.x).hypot(step.to.y - step.from.y) < 1e-9
+}
+
+// Low power is drawn in blue, shading through to red at full power
+fn power_colour(power: f64, max_power: f64) -> String {
+    let t = if max_power > 0.0 { (power / max_power).clamp(0.0, 1.0) } else { 1.0 };
+
+    format!("#{:02x}00{:02x}", (255.0 * t).round() as u8, (255.0 * (1.0 - t)).round() as u8)
+}
+
+fn number(value: f64) -> String {
+    let text = format!("{:.3}", value);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+
+    match text {
+        "-0" => "0".to_string(),
+        text => text.to_string()
+    }
+}
@@ -0,0 +1,247 @@
+use std::f64::consts::PI;
+use std::fmt;
+use roxmltree::{Document, Node};
+use crate::bmp::{Colour, ColourPass};
+use crate::dxf::generate_gcode_with_power;
+use crate::dxf::polyline::PolyLine;
+use crate::dxf::vertex::Vertex;
+use crate::svg::path::{ellipse_points, parse_numbers, parse_path, parse_points, PathBuilder};
+use crate::svg::style::{parse_colour, parse_length, property, px_to_mm};
+use crate::svg::transform::Transform;
+
+mod path;
+mod style;
+mod transform;
+
+#[derive(Debug, Clone, Default)]
+pub struct SvgOptions {
+    pub colour_passes: Vec<ColourPass>
+}
+
+#[derive(Debug)]
+pub enum SvgError {
+    Parse(String)
+}
+
+struct Shape {
+    lines: Vec<PolyLine>,
+    stroke: Option<Colour>
+}
+
+impl fmt::Display for SvgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SvgError::Parse(message) => write!(f, "invalid SVG: {}", message)
+        }
+    }
+}
+
+impl std::error::Error for SvgError {}
+
+pub(crate) fn svg_to_gcode(svg_contents: &str, options: &SvgOptions) -> Result<String, SvgError> {
+    let shapes = parse(svg_contents)?;
+
+    if options.colour_passes.is_empty() {
+        let lines: Vec<PolyLine> = shapes.into_iter().flat_map(|shape| shape.lines).collect();
+
+        return Ok(generate_gcode_with_power(&lines, 255));
+    }
+
+    Ok(options.colour_passes.iter()
+        .map(|pass| {
+            let lines: Vec<PolyLine> = shapes.iter()
+                .filter(|shape| shape.stroke.is_some_and(|stroke| pass.matches(&stroke)))
+                .flat_map(|shape| shape.lines.iter().cloned())
+                .collect();
+
+            generate_gcode_with_power(&lines, pass.power)
+        })
+        .collect())
+}
+
+fn parse(svg_contents: &str) -> Result<Vec<Shape>, SvgError> {
+    let document = Document::parse(svg_contents).map_err(|e| SvgError::Parse(e.to_string()))?;
+    let root = document.root_element();
+
+    if root.tag_name().name() != "svg" {
+        return Err(SvgError::Parse("the root element is not <svg>".into()));
+    }
+
+    let (transform, height) = viewport(&root);
+    let mut shapes = vec![];
+
+    walk(&root, &transform, Some(Colour::grey(0)), &mut shapes);
+
+    // Without a page height the drawing is flipped about its own extent
+    let height = height.unwrap_or_else(|| shapes.iter()
+        .flat_map(|shape| shape.lines.iter().flat_map(|line| line.vertices.iter().map(|v| v.y)))
+        .fold(0.0, f64::max));
+
+    let flip = Transform::matrix(1.0, 0.0, 0.0, -1.0, 0.0, height);
+
+    for shape in shapes.iter_mut() {
+        shape.lines = shape.lines.iter().map(|line| flip.apply_polyline(line)).collect();
+    }
+
+    Ok(shapes)
+}
+
+fn viewport(root: &Node) -> (Transform, Option<f64>) {
+    let width = root.attribute("width").and_then(parse_length);
+    let height = root.attribute("height").and_then(parse_length);
+
+    let view_box = root.attribute("viewBox")
+        .map(parse_numbers)
+        .filter(|v| v.len() == 4 && v[2] > 0.0 && v[3] > 0.0);
+
+    let Some(view_box) = view_box else {
+        return (Transform::scale(px_to_mm(1.0), px_to_mm(1.0)), height);
+    };
+
+    let (x, y, view_width, view_height) = (view_box[0], view_box[1], view_box[2], view_box[3]);
+
+    let (width, height) = match (width, height) {
+        (Some(w), Some(h)) => (w, h),
+        (Some(w), None) => (w, w * view_height / view_width),
+        (None, Some(h)) => (h * view_width / view_height, h),
+        (None, None) => (px_to_mm(view_width), px_to_mm(view_height))
+    };
+
+    let origin = Transform::translate(-x, -y);
+    let (scale_x, scale_y) = (width / view_width, height / view_height);
+
+    let aspect = root.attribute("preserveAspectRatio").unwrap_or("xMidYMid meet");
+    let mut tokens = aspect.split_whitespace();
+    let align = tokens.next().unwrap_or("xMidYMid");
+
+    if align == "none" {
+        return (Transform::scale(scale_x, scale_y).then(&origin), Some(height));
+    }
+
+    let scale = match tokens.next() {
+        Some("slice") => scale_x.max(scale_y),
+        _ => scale_x.min(scale_y)
+    };
+
+    let alignment = |axis: &str| match axis {
+        "Min" => 0.0,
+        "Max" => 1.0,
+        _ => 0.5
+    };
+
+    let offset_x = (width - view_width * scale) * alignment(align.get(1..4).unwrap_or("Mid"));
+    let offset_y = (height - view_height * scale) * alignment(align.get(5..8).unwrap_or("Mid"));
+
+    let transform = Transform::translate(offset_x, offset_y)
+        .then(&Transform::scale(scale, scale))
+        .then(&origin);
+
+    (transform, Some(height))
+}
+
+fn walk(node: &Node, parent: &Transform, parent_stroke: Option<Colour>, shapes: &mut Vec<Shape>) {
+    for child in node.children().filter(Node::is_element) {
+        if property(&child, "display") == Some("none") {
+            continue;
+        }
+
+        let transform = parent.then(&child.attribute("transform").map(Transform::parse).unwrap_or(Transform::identity()));
+
+        let stroke = match property(&child, "stroke") {
+            Some("inherit") | None => parent_stroke,
+            Some(value) => parse_colour(value)
+        };
+
+        match child.tag_name().name() {
+            "g" | "a" | "switch" => walk(&child, &transform, stroke, shapes),
+
+            "svg" => {
+                let offset = Transform::translate(number(&child, "x"), number(&child, "y"));
+
+                walk(&child, &transform.then(&offset), stroke, shapes);
+            },
+
+            name => {
+                if let Some(lines) = shape_lines(&child, name, transform.scale_factor()) {
+                    shapes.push(Shape {
+                        lines: lines.iter().map(|line| transform.apply_polyline(line)).collect(),
+                        stroke
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn number(node: &Node, attribute: &str) -> f64 {
+    node.attribute(attribute)
+        .and_then(|value| parse_numbers(value).first().copied())
+        .unwrap_or(0.0)
+}
+
+fn shape_lines(node: &Node, name: &str, scale: f64) -> Option<Vec<PolyLine>> {
+    let value = |attribute: &str| number(node, attribute);
+
+    match name {
+        "path" => Some(parse_path(node.attribute("d")?, scale)),
+
+        "rect" => rect(node, scale),
+
+        "circle" if value("r") > 0.0 => Some(vec![ellipse(value("cx"), value("cy"), value("r"), value("r"), scale)]),
+
+        "ellipse" if value("rx") > 0.0 && value("ry") > 0.0 => Some(vec![ellipse(value("cx"), value("cy"), value("rx"), value("ry"), scale)]),
+
+        "line" => Some(vec![PolyLine {
+            vertices: vec![Vertex {x: value("x1"), y: value("y1")}, Vertex {x: value("x2"), y: value("y2")}],
+            closed: false
+        }]),
+
+        "polyline" | "polygon" => {
+            let vertices = parse_points(node.attribute("points")?);
+
+            if vertices.len() < 2 {
+                return None;
+            }
+
+            Some(vec![PolyLine {vertices, closed: name == "polygon"}])
+        },
+
+        _ => None
+    }
+}
+
+fn ellipse(cx: f64, cy: f64, rx: f64, ry: f64, scale: f64) -> PolyLine {
+    let mut vertices = ellipse_points(Vertex {x: cx, y: cy}, rx, ry, 0.0, 0.0, 2.0 * PI, scale);
+    vertices.pop();
+
+    PolyLine {vertices, closed: true}
+}
+
+fn rect(node: &Node, scale: f64) -> Option<Vec<PolyLine>> {
+    let (x, y) = (number(node, "x"), number(node, "y"));
+    let (width, height) = (number(node, "width"), number(node, "height"));
+
+    if width <= 0.0 || height <= 0.0 {
+        return None;
+    }
+
+    // A missing corner radius copies the other one
+    let rx = node.attribute("rx").map(|_| number(node, "rx"));
+    let ry = node.attribute("ry").map(|_| number(node, "ry"));
+    let rx = rx.or(ry).unwrap_or(0.0).clamp(0.0, width / 2.0);
+    let ry = ry.or(Some(rx)).unwrap_or(0.0).clamp(0.0, height / 2.0);
+
+    let mut builder = PathBuilder::new(scale);
+    builder.move_to(Vertex {x: x + rx, y});
+    builder.line_to(Vertex {x: x + width - rx, y});
+    builder.arc_to(rx, ry, 0.0, false, true, Vertex {x: x + width, y: y + ry});
+    builder.line_to(Vertex {x: x + width, y: y + height - ry});
+    builder.arc_to(rx, ry, 0.0, false, true, Vertex {x: x + width - rx, y: y + height});
+    builder.line_to(Vertex {x: x + rx, y: y + height});
+    builder.arc_to(rx, ry, 0.0, false, true, Vertex {x, y: y + height - ry});
+    builder.line_to(Vertex {x, y: y + ry});
+    builder.arc_to(rx, ry, 0.0, false, true, Vertex {x: x + rx, y});
+    builder.close();
+
+    Some(builder.finish())
+}
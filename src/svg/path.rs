@@ -0,0 +1,388 @@
+use std::f64::consts::PI;
+use crate::dxf::ellipse::Ellipse;
+use crate::dxf::polyline::PolyLine;
+use crate::dxf::vertex::Vertex;
+
+struct Scanner<'a> {
+    bytes: &'a [u8],
+    position: usize
+}
+
+pub struct PathBuilder {
+    lines: Vec<PolyLine>,
+    current: Vec<Vertex>,
+    scale: f64
+}
+
+impl<'a> Scanner<'a> {
+    fn new(value: &'a str) -> Scanner<'a> {
+        Scanner {bytes: value.as_bytes(), position: 0}
+    }
+
+    fn skip_separators(&mut self) {
+        while self.position < self.bytes.len() && (self.bytes[self.position].is_ascii_whitespace() || self.bytes[self.position] == b',') {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_separators();
+        self.bytes.get(self.position).copied()
+    }
+
+    fn at_number(&mut self) -> bool {
+        matches!(self.peek(), Some(b'0'..=b'9' | b'-' | b'+' | b'.'))
+    }
+
+    fn number(&mut self) -> Option<f64> {
+        self.skip_separators();
+
+        let start = self.position;
+        let digits = |s: &mut Scanner| {
+            let from = s.position;
+
+            while s.position < s.bytes.len() && s.bytes[s.position].is_ascii_digit() {
+                s.position += 1;
+            }
+
+            s.position > from
+        };
+
+        if matches!(self.bytes.get(self.position), Some(b'-' | b'+')) {
+            self.position += 1;
+        }
+
+        let mut found = digits(self);
+
+        // A second decimal point starts the next number, so "1.5.5" is two values
+        if self.bytes.get(self.position) == Some(&b'.') {
+            self.position += 1;
+            found |= digits(self);
+        }
+
+        if !found {
+            self.position = start;
+            return None;
+        }
+
+        if matches!(self.bytes.get(self.position), Some(b'e' | b'E')) {
+            let mantissa_end = self.position;
+            self.position += 1;
+
+            if matches!(self.bytes.get(self.position), Some(b'-' | b'+')) {
+                self.position += 1;
+            }
+
+            if !digits(self) {
+                self.position = mantissa_end;
+            }
+        }
+
+        std::str::from_utf8(&self.bytes[start..self.position]).ok()?.parse().ok()
+    }
+
+    fn flag(&mut self) -> Option<bool> {
+        // Arc flags are single characters and may be written without separators
+        let flag = match self.peek()? {
+            b'0' => false,
+            b'1' => true,
+            _ => return None
+        };
+
+        self.position += 1;
+        Some(flag)
+    }
+
+    fn point(&mut self) -> Option<Vertex> {
+        Some(Vertex {x: self.number()?, y: self.number()?})
+    }
+}
+
+pub fn parse_numbers(value: &str) -> Vec<f64> {
+    let mut scanner = Scanner::new(value);
+
+    std::iter::from_fn(|| scanner.number()).collect()
+}
+
+pub fn parse_points(value: &str) -> Vec<Vertex> {
+    parse_numbers(value)
+        .chunks_exact(2)
+        .map(|p| Vertex {x: p[0], y: p[1]})
+        .collect()
+}
+
+fn segments(length: f64, scale: f64) -> usize {
+    ((length * scale * 1.5) as usize).clamp(6, 90)
+}
+
+impl PathBuilder {
+    pub fn new(scale: f64) -> PathBuilder {
+        PathBuilder {lines: vec![], current: vec![], scale: if scale > 0.0 { scale } else { 1.0 }}
+    }
+
+    fn current_point(&self) -> Vertex {
+        self.current.last().copied().unwrap_or(Vertex {x: 0.0, y: 0.0})
+    }
+
+    fn finish_subpath(&mut self, closed: bool) {
+        let mut vertices = std::mem::take(&mut self.current);
+
+        if closed && vertices.len() > 2 && vertices[0].distance_to(&vertices[vertices.len() - 1]) < 1e-9 {
+            vertices.pop();
+        }
+
+        if vertices.len() > 1 {
+            self.lines.push(PolyLine {vertices, closed});
+        }
+    }
+
+    pub fn move_to(&mut self, point: Vertex) {
+        self.finish_subpath(false);
+        self.current.push(point);
+    }
+
+    pub fn line_to(&mut self, point: Vertex) {
+        if self.current.is_empty() {
+            self.current.push(Vertex {x: 0.0, y: 0.0});
+        }
+
+        self.current.push(point);
+    }
+
+    pub fn cubic_to(&mut self, c1: Vertex, c2: Vertex, end: Vertex) {
+        let start = self.current_point();
+        let count = segments(start.distance_to(&c1) + c1.distance_to(&c2) + c2.distance_to(&end), self.scale);
+
+        for i in 1..=count {
+            let t = i as f64 / count as f64;
+            let u = 1.0 - t;
+
+            self.line_to(Vertex {
+                x: u * u * u * start.x + 3.0 * u * u * t * c1.x + 3.0 * u * t * t * c2.x + t * t * t * end.x,
+                y: u * u * u * start.y + 3.0 * u * u * t * c1.y + 3.0 * u * t * t * c2.y + t * t * t * end.y
+            });
+        }
+    }
+
+    pub fn quadratic_to(&mut self, control: Vertex, end: Vertex) {
+        let start = self.current_point();
+        let count = segments(start.distance_to(&control) + control.distance_to(&end), self.scale);
+
+        for i in 1..=count {
+            let t = i as f64 / count as f64;
+            let u = 1.0 - t;
+
+            self.line_to(Vertex {
+                x: u * u * start.x + 2.0 * u * t * control.x + t * t * end.x,
+                y: u * u * start.y + 2.0 * u * t * control.y + t * t * end.y
+            });
+        }
+    }
+
+    pub fn arc_to(&mut self, rx: f64, ry: f64, rotation: f64, large_arc: bool, sweep: bool, end: Vertex) {
+        let start = self.current_point();
+
+        if start.distance_to(&end) < 1e-9 {
+            return;
+        }
+
+        if rx == 0.0 || ry == 0.0 {
+            self.line_to(end);
+            return;
+        }
+
+        if self.current.is_empty() {
+            self.current.push(start);
+        }
+
+        // Endpoint to centre parameterisation from the SVG implementation notes
+        let (mut rx, mut ry) = (rx.abs(), ry.abs());
+        let phi = rotation.to_radians();
+        let (sin, cos) = phi.sin_cos();
+
+        let dx = (start.x - end.x) / 2.0;
+        let dy = (start.y - end.y) / 2.0;
+        let x1 = cos * dx + sin * dy;
+        let y1 = -sin * dx + cos * dy;
+
+        let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+
+        if lambda > 1.0 {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+
+        let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+        let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+        let sign = if large_arc == sweep { -1.0 } else { 1.0 };
+        let coefficient = sign * (numerator / denominator).max(0.0).sqrt();
+
+        let cx1 = coefficient * rx * y1 / ry;
+        let cy1 = -coefficient * ry * x1 / rx;
+
+        let centre = Vertex {
+            x: cos * cx1 - sin * cy1 + (start.x + end.x) / 2.0,
+            y: sin * cx1 + cos * cy1 + (start.y + end.y) / 2.0
+        };
+
+        let start_angle = ((y1 - cy1) / ry).atan2((x1 - cx1) / rx);
+        let mut sweep_angle = ((-y1 - cy1) / ry).atan2((-x1 - cx1) / rx) - start_angle;
+
+        if sweep && sweep_angle < 0.0 {
+            sweep_angle += 2.0 * PI;
+        } else if !sweep && sweep_angle > 0.0 {
+            sweep_angle -= 2.0 * PI;
+        }
+
+        let mut points = ellipse_points(centre, rx, ry, phi, start_angle, start_angle + sweep_angle, self.scale);
+        points.remove(0);
+        points.pop();
+
+        self.current.append(&mut points);
+        self.current.push(end);
+    }
+
+    pub fn close(&mut self) {
+        let start = self.current.first().copied();
+
+        self.finish_subpath(true);
+        self.current.extend(start);
+    }
+
+    pub fn finish(mut self) -> Vec<PolyLine> {
+        self.finish_subpath(false);
+        self.lines
+    }
+}
+
+// The ellipse is flattened at output size so the segment count matches DXF curves of the same size
+pub fn ellipse_points(centre: Vertex, rx: f64, ry: f64, rotation: f64, start: f64, end: f64, scale: f64) -> Vec<Vertex> {
+    Ellipse {
+        centre,
+        major_axis: Vertex::from_polar(rx, rotation),
+        ratio: ry / rx,
+        start_parameter: start,
+        end_parameter: end
+    }.into_polyline(scale).vertices
+}
+
+fn reflect(point: Vertex, about: Vertex) -> Vertex {
+    Vertex {x: 2.0 * about.x - point.x, y: 2.0 * about.y - point.y}
+}
+
+pub fn parse_path(data: &str, scale: f64) -> Vec<PolyLine> {
+    let mut scanner = Scanner::new(data);
+    let mut builder = PathBuilder::new(scale);
+    let mut command = None;
+    let mut position = Vertex {x: 0.0, y: 0.0};
+    let mut subpath_start = position;
+    let mut last_control: Option<(u8, Vertex)> = None;
+
+    while let Some(next) = scanner.peek() {
+        if next.is_ascii_alphabetic() {
+            scanner.position += 1;
+            command = Some(next);
+        } else if !scanner.at_number() {
+            break;
+        }
+
+        let Some(c) = command else {
+            break;
+        };
+
+        let relative = c.is_ascii_lowercase();
+        let origin = if relative { position } else { Vertex {x: 0.0, y: 0.0} };
+        let mut point = || scanner.point().map(|p| p + origin);
+        let mut control = None;
+
+        // Any error ends the path, keeping everything drawn up to that point
+        match c.to_ascii_uppercase() {
+            b'M' => {
+                let Some(p) = point() else { break };
+                builder.move_to(p);
+                position = p;
+                subpath_start = p;
+
+                // Coordinates following a move are implicit line commands
+                command = Some(if relative { b'l' } else { b'L' });
+            },
+
+            b'L' => {
+                let Some(p) = point() else { break };
+                builder.line_to(p);
+                position = p;
+            },
+
+            b'H' => {
+                let Some(x) = scanner.number() else { break };
+                position = Vertex {x: if relative { position.x + x } else { x }, y: position.y};
+                builder.line_to(position);
+            },
+
+            b'V' => {
+                let Some(y) = scanner.number() else { break };
+                position = Vertex {x: position.x, y: if relative { position.y + y } else { y }};
+                builder.line_to(position);
+            },
+
+            b'C' => {
+                let (Some(c1), Some(c2), Some(p)) = (point(), point(), point()) else { break };
+                builder.cubic_to(c1, c2, p);
+                control = Some((b'C', c2));
+                position = p;
+            },
+
+            b'S' => {
+                let (Some(c2), Some(p)) = (point(), point()) else { break };
+                let c1 = match last_control {
+                    Some((b'C', previous)) => reflect(previous, position),
+                    _ => position
+                };
+
+                builder.cubic_to(c1, c2, p);
+                control = Some((b'C', c2));
+                position = p;
+            },
+
+            b'Q' => {
+                let (Some(c1), Some(p)) = (point(), point()) else { break };
+                builder.quadratic_to(c1, p);
+                control = Some((b'Q', c1));
+                position = p;
+            },
+
+            b'T' => {
+                let Some(p) = point() else { break };
+                let c1 = match last_control {
+                    Some((b'Q', previous)) => reflect(previous, position),
+                    _ => position
+                };
+
+                builder.quadratic_to(c1, p);
+                control = Some((b'Q', c1));
+                position = p;
+            },
+
+            b'A' => {
+                let (Some(rx), Some(ry), Some(rotation)) = (scanner.number(), scanner.number(), scanner.number()) else { break };
+                let (Some(large_arc), Some(sweep)) = (scanner.flag(), scanner.flag()) else { break };
+                let Some(p) = scanner.point().map(|p| p + origin) else { break };
+
+                builder.arc_to(rx, ry, rotation, large_arc, sweep, p);
+                position = p;
+            },
+
+            b'Z' => {
+                builder.close();
+                position = subpath_start;
+                command = None;
+            },
+
+            _ => break
+        }
+
+        last_control = control;
+    }
+
+    builder.finish()
+}
@@ -0,0 +1,96 @@
+use roxmltree::Node;
+use crate::bmp::Colour;
+
+const MM_PER_PX: f64 = 25.4 / 96.0;
+
+pub fn property<'a>(node: &Node<'a, '_>, name: &str) -> Option<&'a str> {
+    // Inline styles take precedence over presentation attributes
+    let styled = node.attribute("style").and_then(|style| {
+        style.rsplit(';')
+            .filter_map(|declaration| declaration.split_once(':'))
+            .find(|(property, _)| property.trim() == name)
+            .map(|(_, value)| value.trim())
+    });
+
+    styled.or_else(|| node.attribute(name).map(str::trim))
+}
+
+pub fn parse_length(value: &str) -> Option<f64> {
+    let value = value.trim();
+
+    let units = [
+        ("mm", 1.0),
+        ("cm", 10.0),
+        ("Q", 0.25),
+        ("in", 25.4),
+        ("pt", 25.4 / 72.0),
+        ("pc", 25.4 / 6.0),
+        ("px", MM_PER_PX)
+    ];
+
+    let (number, mm_per_unit) = units.iter()
+        .find_map(|(unit, scale)| value.strip_suffix(unit).map(|number| (number, *scale)))
+        .unwrap_or((value, MM_PER_PX));
+
+    number.trim().parse::<f64>().ok().map(|n| n * mm_per_unit)
+}
+
+pub fn px_to_mm(px: f64) -> f64 {
+    px * MM_PER_PX
+}
+
+pub fn parse_colour(value: &str) -> Option<Colour> {
+    let value = value.trim().to_ascii_lowercase();
+
+    if value == "none" || value == "transparent" {
+        return None;
+    }
+
+    if let Some(hex) = value.strip_prefix('#') {
+        let digit = |i: usize, len: usize| u8::from_str_radix(hex.get(i..i + len)?, 16).ok();
+
+        return match hex.len() {
+            3 => Some(Colour {r: digit(0, 1)? * 17, g: digit(1, 1)? * 17, b: digit(2, 1)? * 17}),
+            6 => Some(Colour {r: digit(0, 2)?, g: digit(2, 2)?, b: digit(4, 2)?}),
+            _ => Some(Colour::grey(0))
+        };
+    }
+
+    if let Some(arguments) = value.strip_prefix("rgb(").and_then(|v| v.strip_suffix(')')) {
+        let channel = |c: &str| match c.trim().strip_suffix('%') {
+            Some(percent) => percent.trim().parse::<f64>().ok().map(|p| (p * 2.55).round().clamp(0.0, 255.0) as u8),
+            None => c.trim().parse::<f64>().ok().map(|v| v.round().clamp(0.0, 255.0) as u8)
+        };
+
+        let channels: Vec<u8> = arguments.split(',').filter_map(channel).collect();
+
+        return match channels[..] {
+            [r, g, b, ..] => Some(Colour {r, g, b}),
+            _ => Some(Colour::grey(0))
+        };
+    }
+
+    let (r, g, b) = match value.as_str() {
+        "white" => (255, 255, 255),
+        "silver" => (192, 192, 192),
+        "gray" | "grey" => (128, 128, 128),
+        "red" => (255, 0, 0),
+        "maroon" => (128, 0, 0),
+        "yellow" => (255, 255, 0),
+        "olive" => (128, 128, 0),
+        "lime" => (0, 255, 0),
+        "green" => (0, 128, 0),
+        "aqua" | "cyan" => (0, 255, 255),
+        "teal" => (0, 128, 128),
+        "blue" => (0, 0, 255),
+        "navy" => (0, 0, 128),
+        "fuchsia" | "magenta" => (255, 0, 255),
+        "purple" => (128, 0, 128),
+        "orange" => (255, 165, 0),
+
+        // Gradients, currentColor and unknown names still draw a visible stroke
+        _ => (0, 0, 0)
+    };
+
+    Some(Colour {r, g, b})
+}
@@ -0,0 +1,99 @@
+use crate::dxf::polyline::PolyLine;
+use crate::dxf::vertex::Vertex;
+use crate::svg::path::parse_numbers;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+    e: f64,
+    f: f64
+}
+
+impl Transform {
+    pub fn identity() -> Transform {
+        Transform::matrix(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+    }
+
+    pub fn matrix(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> Transform {
+        Transform {a, b, c, d, e, f}
+    }
+
+    pub fn translate(x: f64, y: f64) -> Transform {
+        Transform::matrix(1.0, 0.0, 0.0, 1.0, x, y)
+    }
+
+    pub fn scale(x: f64, y: f64) -> Transform {
+        Transform::matrix(x, 0.0, 0.0, y, 0.0, 0.0)
+    }
+
+    pub fn rotate(degrees: f64) -> Transform {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+
+        Transform::matrix(cos, sin, -sin, cos, 0.0, 0.0)
+    }
+
+    pub fn then(&self, inner: &Transform) -> Transform {
+        Transform {
+            a: self.a * inner.a + self.c * inner.b,
+            b: self.b * inner.a + self.d * inner.b,
+            c: self.a * inner.c + self.c * inner.d,
+            d: self.b * inner.c + self.d * inner.d,
+            e: self.a * inner.e + self.c * inner.f + self.e,
+            f: self.b * inner.e + self.d * inner.f + self.f
+        }
+    }
+
+    pub fn apply(&self, point: &Vertex) -> Vertex {
+        Vertex {
+            x: self.a * point.x + self.c * point.y + self.e,
+            y: self.b * point.x + self.d * point.y + self.f
+        }
+    }
+
+    pub fn scale_factor(&self) -> f64 {
+        (self.a * self.d - self.b * self.c).abs().sqrt()
+    }
+
+    pub fn apply_polyline(&self, line: &PolyLine) -> PolyLine {
+        PolyLine {
+            vertices: line.vertices.iter().map(|v| self.apply(v)).collect(),
+            closed: line.closed
+        }
+    }
+
+    pub fn parse(value: &str) -> Transform {
+        let mut transform = Transform::identity();
+
+        for item in value.split_inclusive(')') {
+            let Some((name, arguments)) = item.split_once('(') else {
+                break;
+            };
+
+            let args = parse_numbers(arguments.trim_end_matches(')'));
+            let arg = |i: usize| args.get(i).copied().unwrap_or(0.0);
+
+            let next = match (name.trim_matches(|c: char| c.is_whitespace() || c == ','), args.len()) {
+                ("matrix", 6) => Transform::matrix(arg(0), arg(1), arg(2), arg(3), arg(4), arg(5)),
+                ("translate", _) => Transform::translate(arg(0), arg(1)),
+                ("scale", 1) => Transform::scale(arg(0), arg(0)),
+                ("scale", _) => Transform::scale(arg(0), arg(1)),
+                ("rotate", 3) => Transform::translate(arg(1), arg(2))
+                    .then(&Transform::rotate(arg(0)))
+                    .then(&Transform::translate(-arg(1), -arg(2))),
+                ("rotate", _) => Transform::rotate(arg(0)),
+                ("skewX", _) => Transform::matrix(1.0, 0.0, arg(0).to_radians().tan(), 1.0, 0.0, 0.0),
+                ("skewY", _) => Transform::matrix(1.0, arg(0).to_radians().tan(), 0.0, 1.0, 0.0, 0.0),
+
+                // An invalid transform list disables the whole attribute
+                _ => return Transform::identity()
+            };
+
+            transform = transform.then(&next);
+        }
+
+        transform
+    }
+}
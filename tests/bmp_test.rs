@@ -1,5 +1,5 @@
 use std::fs::read_to_string;
-use rust_cnc::process_bmp;
+use rust_cnc::{process, process_bmp, process_bmp_with_options, process_with_image, process_with_options, Anchor, Colour, ColourPass, DotShape, DxfOptions, GreyscaleMode, Halftone, ImageError, OutputUnits, RasterMode, RasterOptions, ScanDirection};
 
 const DATA_PATH: &str = "tests/data/bmp/";
 
@@ -8,9 +8,150 @@ fn bmp_test() {
     test_bmp("test.bmp", "test.nc", 200);
 }
 
+#[test]
+fn png_with_transparency_test() {
+    test_bmp("test.png", "test.nc", 200);
+}
+
+#[test]
+fn pgm_test() {
+    test_bmp("test.pgm", "test.nc", 200);
+}
+
+#[test]
+fn pbm_test() {
+    let gcode = process_bmp(b"P1\n# comment\n4 1\n0110", 254).unwrap();
+
+    assert_eq!("G01\nM05 F2000 X0.10 Y0.00\nM03 F1000 X0.30 Y0.00 S255\nM05 F2000 X0 Y0", gcode);
+}
+
+#[test]
+fn luminance_greyscale_test() {
+    let mut options = RasterOptions::new(254);
+    options.greyscale = GreyscaleMode::Rec601;
+
+    let gcode = process_bmp_with_options(b"P3 2 1 255 255 0 0 255 255 255", &options).unwrap();
+
+    assert_eq!("G01\nM05 F2000 X0.00 Y0.00\nM03 F1000 X0.10 Y0.00 S179\nM05 F2000 X0 Y0", gcode);
+}
+
+#[test]
+fn colour_pass_test() {
+    let mut options = RasterOptions::new(254);
+    options.colour_passes = vec![
+        ColourPass { colour: Colour { r: 255, g: 0, b: 0 }, tolerance: 10, power: 200 },
+        ColourPass { colour: Colour { r: 0, g: 0, b: 255 }, tolerance: 10, power: 100 }
+    ];
+
+    let gcode = process_bmp_with_options(b"P3 3 1 255 250 5 0 0 0 255 255 255 255", &options).unwrap();
+
+    assert_eq!(
+        "G01\n\
+        M05 F2000 X0.00 Y0.00\nM03 F1000 X0.10 Y0.00 S200\n\
+        M05 F2000 X0.10 Y0.00\nM03 F1000 X0.20 Y0.00 S100\n\
+        M05 F2000 X0 Y0",
+        gcode
+    );
+}
+
+#[test]
+fn halftone_test() {
+    let mut options = RasterOptions::new(200);
+    options.mode = RasterMode::Halftone(Halftone::new(0.5, 45.0, DotShape::Round));
+
+    test_bmp_with_options("test.bmp", "halftone.nc", &options);
+}
+
+#[test]
+fn anchored_placement_test() {
+    let mut options = RasterOptions::new(254);
+    options.anchor = Anchor::Centre;
+    options.offset_x = 10.0;
+    options.offset_y = 20.0;
+
+    let gcode = process_bmp_with_options(b"P1 4 1 0110", &options).unwrap();
+
+    assert_eq!("G01\nM05 F2000 X9.95 Y20.00\nM03 F1000 X10.15 Y20.00 S255\nM05 F2000 X0 Y0", gcode);
+}
+
+#[test]
+fn combined_job_test() {
+    let dxf = read_to_string("tests/data/dxf/bulge.dxf").unwrap();
+    let options = RasterOptions::new(254);
+
+    let engraving = process_bmp_with_options(b"P1 4 1 0110", &options).unwrap();
+    let cut = process(&dxf);
+    let expected = engraving.lines().take(3).collect::<Vec<&str>>().join("\n") + "\n" + cut.strip_prefix("G01\n").unwrap();
+
+    assert_eq!(expected, process_with_image(&dxf, b"P1 4 1 0110", &DxfOptions::default(), &options).unwrap());
+}
+
+#[test]
+fn combined_job_with_dxf_options_test() {
+    let dxf = read_to_string("tests/data/dxf/bulge.dxf").unwrap();
+    let options = RasterOptions::new(254);
+    let dxf_options = DxfOptions {output_units: OutputUnits::Inches, unit_command: true, ..DxfOptions::default()};
+
+    let cut = process_with_options(&dxf, &dxf_options);
+    let gcode = process_with_image(&dxf, b"P1 4 1 0110", &dxf_options, &options).unwrap();
+
+    // The unit command comes before the engraving, which is written in inches like the cut
+    let engraving = "G01\nG20\nM05 F78.74 X0.0039 Y0.0000\nM03 F39.37 X0.0118 Y0.0000 S255\n";
+
+    assert_eq!(engraving.to_owned() + cut.strip_prefix("G01\nG20\n").unwrap(), gcode);
+    assert_eq!(1, gcode.matches("G20").count());
+    assert!(!gcode.contains("F1000") && !gcode.contains("F2000"));
+}
+
+#[test]
+fn undecodable_image_test() {
+    assert!(matches!(process_bmp(b"not an image", 200), Err(ImageError::UnsupportedFormat)));
+    assert!(matches!(process_bmp(b"P5\n10 10\n255\n\x00", 200), Err(ImageError::Decode(_))));
+    assert!(matches!(process_bmp(b"\x89PNG\r\n\x1a\n", 200), Err(ImageError::Decode(_))));
+    assert!(matches!(process_bmp(b"P6\n4294967295 4294967295\n65535\n\x00", 200), Err(ImageError::Decode(_))));
+    assert!(matches!(process_bmp(b"P3\n4294967295 4294967295\n255\n0", 200), Err(ImageError::Decode(_))));
+}
+
+#[test]
+fn bmp_vertical_unidirectional_test() {
+    let mut options = RasterOptions::new(200);
+    options.direction = ScanDirection::Vertical;
+    options.bidirectional = false;
+
+    test_bmp_with_options("test.bmp", "vertical.nc", &options);
+}
+
+#[test]
+fn bidirectional_line_start_test() {
+    let gcode = process_bmp(b"P1 2 2 1111", 254).unwrap();
+
+    assert_eq!(
+        "G01\n\
+        M05 F2000 X0.00 Y0.10\nM03 F1000 X0.10 Y0.10 S255\n\
+        M05 F2000 X0.10 Y0.00\nM03 F1000 X0.00 Y0.00 S255\n\
+        M05 F2000 X0 Y0",
+        gcode
+    );
+}
+
+#[test]
+fn bmp_angle_test() {
+    let mut options = RasterOptions::new(200);
+    options.direction = ScanDirection::Angle(45.0);
+
+    test_bmp_with_options("test.bmp", "angle.nc", &options);
+}
+
 fn test_bmp(bmp_file: &str, nc_file: &str, dpi: u16) {
     let bmp = std::fs::read(DATA_PATH.to_owned() + bmp_file).unwrap();
     let gcode = read_to_string(DATA_PATH.to_owned() + nc_file).unwrap().replace('\r', "");
 
-    assert_eq!(gcode.trim_end(), process_bmp(&bmp, dpi).trim_end());
+    assert_eq!(gcode.trim_end(), process_bmp(&bmp, dpi).unwrap().trim_end());
+}
+
+fn test_bmp_with_options(bmp_file: &str, nc_file: &str, options: &RasterOptions) {
+    let bmp = std::fs::read(DATA_PATH.to_owned() + bmp_file).unwrap();
+    let gcode = read_to_string(DATA_PATH.to_owned() + nc_file).unwrap().replace('\r', "");
+
+    assert_eq!(gcode.trim_end(), process_bmp_with_options(&bmp, options).unwrap().trim_end());
 }
@@ -0,0 +1,530 @@
+G01
+M05 F2000 X2.51 Y5.39
+M03 F1000 X2.42 Y5.30 S65
+M03 F1000 X2.33 Y5.21 S11
+M03 F1000 X2.15 Y5.03 S68
+M05 F2000 X1.88 Y4.76
+M03 F1000 X1.79 Y4.67 S68
+M05 F2000 X1.88 Y4.58
+M03 F1000 X1.97 Y4.67 S68
+M03 F1000 X2.15 Y4.85 S255
+M05 F2000 X2.24 Y4.94
+M03 F1000 X2.42 Y5.12 S68
+M03 F1000 X2.60 Y5.30 S255
+M03 F1000 X2.69 Y5.39 S65
+M03 F1000 X2.78 Y5.48 S141
+M05 F2000 X2.87 Y5.39
+M03 F1000 X2.78 Y5.30 S11
+M03 F1000 X2.69 Y5.21 S155
+M03 F1000 X2.33 Y4.85 S255
+M03 F1000 X2.24 Y4.76 S68
+M03 F1000 X1.88 Y4.40 S255
+M03 F1000 X1.79 Y4.31 S68
+M05 F2000 X1.97 Y4.31
+M03 F1000 X2.06 Y4.40 S32
+M03 F1000 X2.78 Y5.12 S255
+M03 F1000 X2.87 Y5.21 S179
+M03 F1000 X3.05 Y5.39 S11
+M05 F2000 X2.96 Y5.12
+M03 F1000 X2.78 Y4.94 S11
+M03 F1000 X2.69 Y4.85 S179
+M03 F1000 X2.15 Y4.31 S255
+M03 F1000 X2.06 Y4.22 S32
+M05 F2000 X2.24 Y4.22
+M03 F1000 X2.33 Y4.31 S68
+M03 F1000 X2.42 Y4.40 S79
+M03 F1000 X2.87 Y4.85 S255
+M03 F1000 X3.05 Y5.03 S11
+M05 F2000 X2.96 Y4.76
+M03 F1000 X2.33 Y4.13 S255
+M03 F1000 X2.15 Y3.95 S68
+M05 F2000 X2.24 Y3.86
+M03 F1000 X2.42 Y4.04 S68
+M03 F1000 X3.23 Y4.85 S255
+M05 F2000 X3.32 Y4.76
+M03 F1000 X2.87 Y4.31 S255
+M03 F1000 X2.78 Y4.22 S43
+M03 F1000 X2.69 Y4.13 S179
+M03 F1000 X2.33 Y3.77 S255
+M03 F1000 X2.15 Y3.59 S68
+M05 F2000 X2.24 Y3.50
+M03 F1000 X2.42 Y3.68 S68
+M03 F1000 X2.78 Y4.04 S255
+M03 F1000 X2.87 Y4.13 S179
+M03 F1000 X3.05 Y4.31 S11
+M03 F1000 X3.14 Y4.40 S32
+M03 F1000 X3.41 Y4.67 S255
+M03 F1000 X3.50 Y4.76 S68
+M05 F2000 X3.41 Y4.49
+M03 F1000 X3.32 Y4.40 S68
+M03 F1000 X3.23 Y4.31 S255
+M03 F1000 X3.14 Y4.22 S32
+M05 F2000 X2.96 Y4.04
+M03 F1000 X2.78 Y3.86 S11
+M03 F1000 X2.69 Y3.77 S179
+M03 F1000 X2.33 Y3.41 S255
+M03 F1000 X2.15 Y3.23 S68
+M05 F2000 X2.24 Y3.14
+M03 F1000 X2.42 Y3.32 S68
+M03 F1000 X2.78 Y3.68 S255
+M03 F1000 X2.87 Y3.77 S179
+M03 F1000 X3.05 Y3.95 S11
+M05 F2000 X2.96 Y3.68
+M03 F1000 X2.78 Y3.50 S11
+M03 F1000 X2.69 Y3.41 S179
+M03 F1000 X2.33 Y3.05 S255
+M03 F1000 X2.15 Y2.87 S68
+M05 F2000 X2.24 Y2.78
+M03 F1000 X2.33 Y2.87 S44
+M03 F1000 X2.42 Y2.96 S68
+M03 F1000 X2.78 Y3.32 S255
+M03 F1000 X2.87 Y3.41 S179
+M03 F1000 X3.05 Y3.59 S11
+M05 F2000 X4.40 Y4.76
+M03 F1000 X4.31 Y4.67 S88
+M03 F1000 X4.22 Y4.58 S11
+M03 F1000 X4.13 Y4.49 S79
+M03 F1000 X4.04 Y4.40 S255
+M03 F1000 X3.95 Y4.31 S129
+M03 F1000 X3.86 Y4.22 S105
+M03 F1000 X3.68 Y4.04 S44
+M05 F2000 X2.96 Y3.32
+M03 F1000 X2.78 Y3.14 S11
+M03 F1000 X2.69 Y3.05 S179
+M03 F1000 X2.42 Y2.78 S255
+M03 F1000 X2.33 Y2.70 S231
+M03 F1000 X2.15 Y2.52 S11
+M05 F2000 X2.42 Y2.61
+M03 F1000 X2.51 Y2.70 S179
+M03 F1000 X2.78 Y2.96 S255
+M03 F1000 X2.87 Y3.05 S231
+M03 F1000 X2.96 Y3.14 S22
+M03 F1000 X3.05 Y3.23 S11
+M05 F2000 X3.68 Y3.86
+M03 F1000 X3.77 Y3.95 S44
+M03 F1000 X3.86 Y4.04 S217
+M03 F1000 X3.95 Y4.13 S153
+M03 F1000 X4.04 Y4.22 S231
+M03 F1000 X4.49 Y4.67 S255
+M03 F1000 X4.58 Y4.76 S88
+M03 F1000 X4.67 Y4.85 S169
+M05 F2000 X4.76 Y4.76
+M03 F1000 X3.86 Y3.86 S255
+M03 F1000 X3.68 Y3.68 S231
+M03 F1000 X3.59 Y3.59 S68
+M05 F2000 X2.96 Y2.96
+M03 F1000 X2.78 Y2.78 S44
+M03 F1000 X2.51 Y2.52 S255
+M03 F1000 X2.42 Y2.43 S231
+M05 F2000 X2.60 Y2.43
+M03 F1000 X2.69 Y2.52 S22
+M03 F1000 X2.96 Y2.78 S255
+M03 F1000 X3.05 Y2.87 S231
+M05 F2000 X3.68 Y3.50
+M03 F1000 X3.77 Y3.59 S68
+M03 F1000 X4.13 Y3.95 S255
+M03 F1000 X4.22 Y4.04 S231
+M03 F1000 X4.40 Y4.22 S255
+M03 F1000 X4.49 Y4.31 S79
+M03 F1000 X5.03 Y4.85 S255
+M05 F2000 X5.12 Y4.76
+M03 F1000 X4.94 Y4.58 S207
+M03 F1000 X4.67 Y4.31 S255
+M03 F1000 X4.58 Y4.22 S32
+M05 F2000 X4.49 Y4.13
+M03 F1000 X4.40 Y4.04 S79
+M05 F2000 X4.31 Y3.95
+M03 F1000 X4.22 Y3.86 S44
+M03 F1000 X4.04 Y3.68 S179
+M03 F1000 X3.68 Y3.32 S255
+M03 F1000 X3.59 Y3.23 S68
+M05 F2000 X3.14 Y2.78
+M03 F1000 X3.05 Y2.70 S64
+M03 F1000 X2.78 Y2.43 S255
+M03 F1000 X2.69 Y2.34 S231
+M05 F2000 X2.96 Y2.43
+M03 F1000 X3.32 Y2.78 S255
+M03 F1000 X3.41 Y2.87 S101
+M05 F2000 X3.68 Y3.14
+M03 F1000 X3.77 Y3.23 S22
+M03 F1000 X3.86 Y3.32 S217
+M03 F1000 X4.31 Y3.77 S255
+M03 F1000 X4.40 Y3.86 S11
+M05 F2000 X4.94 Y4.40
+M03 F1000 X5.21 Y4.67 S255
+M03 F1000 X5.30 Y4.76 S141
+M03 F1000 X5.39 Y4.85 S32
+M05 F2000 X5.39 Y4.67
+M03 F1000 X5.30 Y4.58 S32
+M03 F1000 X5.03 Y4.31 S255
+M03 F1000 X4.94 Y4.22 S116
+M05 F2000 X4.40 Y3.68
+M03 F1000 X3.86 Y3.14 S255
+M03 F1000 X3.77 Y3.05 S217
+M03 F1000 X3.68 Y2.96 S179
+M05 F2000 X3.41 Y2.70
+M03 F1000 X3.32 Y2.61 S68
+M03 F1000 X3.14 Y2.43 S255
+M03 F1000 X3.05 Y2.34 S231
+M05 F2000 X3.32 Y2.43
+M03 F1000 X3.41 Y2.52 S169
+M03 F1000 X3.50 Y2.61 S68
+M05 F2000 X3.77 Y2.87
+M03 F1000 X3.95 Y3.05 S68
+M03 F1000 X4.13 Y3.23 S255
+M03 F1000 X4.22 Y3.32 S217
+M03 F1000 X4.31 Y3.41 S179
+M03 F1000 X4.67 Y3.77 S255
+M05 F2000 X5.21 Y4.31
+M03 F1000 X5.48 Y4.58 S255
+M03 F1000 X5.57 Y4.67 S129
+M05 F2000 X5.57 Y4.49
+M03 F1000 X5.48 Y4.40 S153
+M03 F1000 X5.21 Y4.13 S255
+M03 F1000 X5.12 Y4.04 S68
+M05 F2000 X4.76 Y3.68
+M03 F1000 X4.40 Y3.32 S255
+M05 F2000 X4.31 Y3.23
+M03 F1000 X4.22 Y3.14 S22
+M03 F1000 X4.13 Y3.05 S217
+M03 F1000 X3.86 Y2.78 S255
+M03 F1000 X3.77 Y2.70 S11
+M05 F2000 X3.95 Y2.70
+M03 F1000 X4.04 Y2.78 S22
+M03 F1000 X4.31 Y3.05 S255
+M03 F1000 X4.40 Y3.14 S105
+M05 F2000 X4.67 Y3.41
+M03 F1000 X5.03 Y3.77 S255
+M05 F2000 X5.30 Y4.04
+M03 F1000 X5.39 Y4.13 S179
+M03 F1000 X5.66 Y4.40 S255
+M05 F2000 X5.75 Y4.31
+M03 F1000 X5.66 Y4.22 S105
+M03 F1000 X5.57 Y4.13 S217
+M03 F1000 X5.39 Y3.95 S255
+M03 F1000 X5.30 Y3.86 S105
+M03 F1000 X5.21 Y3.77 S68
+M05 F2000 X5.12 Y3.68
+M03 F1000 X4.76 Y3.32 S255
+M05 F2000 X4.40 Y2.96
+M03 F1000 X4.31 Y2.87 S79
+M03 F1000 X4.04 Y2.61 S255
+M03 F1000 X3.95 Y2.52 S217
+M05 F2000 X4.13 Y2.52
+M03 F1000 X4.22 Y2.61 S141
+M03 F1000 X4.49 Y2.87 S255
+M03 F1000 X4.58 Y2.96 S79
+M05 F2000 X5.03 Y3.41
+M03 F1000 X5.39 Y3.77 S255
+M03 F1000 X5.48 Y3.86 S68
+M03 F1000 X5.75 Y4.13 S255
+M03 F1000 X5.84 Y4.22 S217
+M03 F1000 X5.93 Y4.31 S22
+M05 F2000 X6.55 Y4.76
+M03 F1000 X6.46 Y4.67 S11
+M05 F2000 X5.84 Y4.04
+M03 F1000 X5.75 Y3.95 S68
+M03 F1000 X5.12 Y3.32 S255
+M05 F2000 X4.58 Y2.78
+M03 F1000 X4.49 Y2.70 S207
+M03 F1000 X4.22 Y2.43 S255
+M05 F2000 X4.40 Y2.43
+M03 F1000 X4.49 Y2.52 S141
+M03 F1000 X4.76 Y2.78 S255
+M03 F1000 X4.85 Y2.87 S101
+M05 F2000 X5.39 Y3.41
+M03 F1000 X5.84 Y3.86 S255
+M03 F1000 X5.93 Y3.95 S153
+M05 F2000 X6.20 Y4.22
+M03 F1000 X6.29 Y4.31 S68
+M03 F1000 X6.37 Y4.40 S22
+M03 F1000 X6.46 Y4.49 S68
+M03 F1000 X6.55 Y4.58 S255
+M03 F1000 X6.64 Y4.67 S231
+M03 F1000 X6.73 Y4.76 S11
+M03 F1000 X6.82 Y4.85 S65
+M05 F2000 X6.91 Y4.76
+M03 F1000 X6.73 Y4.58 S169
+M03 F1000 X6.29 Y4.13 S255
+M03 F1000 X6.20 Y4.04 S68
+M03 F1000 X6.11 Y3.95 S105
+M05 F2000 X5.84 Y3.68
+M03 F1000 X5.75 Y3.59 S179
+M03 F1000 X5.48 Y3.32 S255
+M05 F2000 X4.94 Y2.78
+M03 F1000 X4.85 Y2.70 S32
+M03 F1000 X4.58 Y2.43 S255
+M03 F1000 X4.49 Y2.34 S207
+M05 F2000 X4.76 Y2.43
+M03 F1000 X5.12 Y2.78 S255
+M03 F1000 X5.21 Y2.87 S32
+M05 F2000 X5.75 Y3.41
+M03 F1000 X5.84 Y3.50 S255
+M03 F1000 X5.93 Y3.59 S179
+M03 F1000 X6.11 Y3.77 S11
+M05 F2000 X6.20 Y3.86
+M03 F1000 X6.29 Y3.95 S22
+M03 F1000 X6.37 Y4.04 S68
+M03 F1000 X7.00 Y4.67 S255
+M03 F1000 X7.09 Y4.76 S231
+M03 F1000 X7.18 Y4.85 S255
+M05 F2000 X7.27 Y4.76
+M03 F1000 X6.82 Y4.31 S255
+M03 F1000 X6.73 Y4.22 S193
+M03 F1000 X6.64 Y4.13 S217
+M03 F1000 X6.37 Y3.86 S255
+M03 F1000 X6.29 Y3.77 S217
+M03 F1000 X6.20 Y3.68 S22
+M05 F2000 X6.02 Y3.50
+M03 F1000 X5.84 Y3.32 S11
+M05 F2000 X5.48 Y2.96
+M03 F1000 X5.39 Y2.87 S11
+M05 F2000 X5.30 Y2.78
+M03 F1000 X5.21 Y2.70 S141
+M03 F1000 X4.85 Y2.34 S255
+M05 F2000 X5.12 Y2.43
+M03 F1000 X5.21 Y2.52 S231
+M03 F1000 X5.48 Y2.78 S255
+M03 F1000 X5.57 Y2.87 S231
+M03 F1000 X5.66 Y2.96 S79
+M03 F1000 X5.75 Y3.05 S55
+M05 F2000 X6.37 Y3.68
+M03 F1000 X6.46 Y3.77 S68
+M03 F1000 X6.73 Y4.04 S255
+M03 F1000 X6.82 Y4.13 S179
+M03 F1000 X7.00 Y4.31 S22
+M03 F1000 X7.09 Y4.40 S65
+M03 F1000 X7.45 Y4.76 S255
+M03 F1000 X7.54 Y4.85 S207
+M05 F2000 X7.63 Y4.76
+M03 F1000 X7.54 Y4.67 S141
+M03 F1000 X7.45 Y4.58 S207
+M03 F1000 X7.18 Y4.31 S255
+M03 F1000 X7.09 Y4.22 S32
+M05 F2000 X6.91 Y4.04
+M03 F1000 X6.82 Y3.95 S11
+M03 F1000 X6.73 Y3.86 S44
+M03 F1000 X6.46 Y3.59 S255
+M03 F1000 X6.37 Y3.50 S44
+M05 F2000 X5.75 Y2.87
+M03 F1000 X5.66 Y2.78 S55
+M03 F1000 X5.57 Y2.70 S68
+M03 F1000 X5.30 Y2.43 S255
+M03 F1000 X5.21 Y2.34 S126
+M05 F2000 X5.48 Y2.43
+M03 F1000 X5.57 Y2.52 S43
+M03 F1000 X5.66 Y2.61 S193
+M03 F1000 X5.84 Y2.78 S68
+M05 F2000 X6.55 Y3.50
+M03 F1000 X6.73 Y3.68 S193
+M03 F1000 X7.00 Y3.95 S255
+M05 F2000 X7.36 Y4.31
+M03 F1000 X7.45 Y4.40 S32
+M03 F1000 X7.72 Y4.67 S255
+M03 F1000 X7.81 Y4.76 S65
+M05 F2000 X8.62 Y5.39
+M03 F1000 X8.53 Y5.30 S11
+M05 F2000 X7.81 Y4.58
+M03 F1000 X7.72 Y4.49 S11
+M03 F1000 X7.63 Y4.40 S179
+M03 F1000 X7.54 Y4.31 S255
+M03 F1000 X7.45 Y4.22 S54
+M05 F2000 X7.09 Y3.86
+M03 F1000 X7.00 Y3.77 S65
+M03 F1000 X6.91 Y3.68 S169
+M03 F1000 X6.64 Y3.41 S255
+M05 F2000 X6.20 Y2.96
+M03 F1000 X6.11 Y2.87 S90
+M05 F2000 X5.75 Y2.52
+M03 F1000 X5.66 Y2.43 S44
+M05 F2000 X6.20 Y2.78
+M03 F1000 X6.29 Y2.87 S105
+M03 F1000 X6.37 Y2.96 S90
+M03 F1000 X6.46 Y3.05 S79
+M05 F2000 X6.82 Y3.41
+M03 F1000 X6.91 Y3.50 S193
+M03 F1000 X7.18 Y3.77 S255
+M05 F2000 X7.72 Y4.31
+M03 F1000 X7.81 Y4.40 S155
+M03 F1000 X7.99 Y4.58 S11
+M05 F2000 X8.08 Y4.67
+M03 F1000 X8.26 Y4.85 S68
+M05 F2000 X8.53 Y5.12
+M03 F1000 X8.62 Y5.21 S68
+M03 F1000 X8.71 Y5.30 S255
+M03 F1000 X8.89 Y5.48 S65
+M05 F2000 X8.98 Y5.39
+M03 F1000 X8.89 Y5.30 S155
+M03 F1000 X8.80 Y5.21 S141
+M03 F1000 X8.53 Y4.94 S255
+M03 F1000 X8.44 Y4.85 S68
+M05 F2000 X8.35 Y4.76
+M03 F1000 X8.17 Y4.58 S255
+M03 F1000 X7.99 Y4.40 S68
+M05 F2000 X7.90 Y4.31
+M03 F1000 X7.81 Y4.22 S11
+M05 F2000 X7.27 Y3.68
+M03 F1000 X7.18 Y3.59 S231
+M03 F1000 X6.91 Y3.32 S255
+M05 F2000 X6.37 Y2.78
+M03 F1000 X6.29 Y2.70 S255
+M03 F1000 X6.11 Y2.52 S105
+M05 F2000 X6.29 Y2.52
+M03 F1000 X6.37 Y2.61 S105
+M03 F1000 X6.55 Y2.78 S255
+M03 F1000 X6.64 Y2.87 S101
+M05 F2000 X7.09 Y3.32
+M03 F1000 X7.18 Y3.41 S101
+M03 F1000 X7.45 Y3.68 S255
+M03 F1000 X7.54 Y3.77 S22
+M05 F2000 X8.17 Y4.40
+M03 F1000 X8.26 Y4.49 S68
+M03 F1000 X8.98 Y5.21 S255
+M03 F1000 X9.07 Y5.30 S179
+M03 F1000 X9.16 Y5.39 S155
+M03 F1000 X9.25 Y5.48 S11
+M05 F2000 X9.16 Y5.21
+M03 F1000 X9.07 Y5.12 S11
+M03 F1000 X8.89 Y4.94 S179
+M03 F1000 X8.26 Y4.31 S255
+M03 F1000 X8.17 Y4.22 S32
+M05 F2000 X7.54 Y3.59
+M03 F1000 X7.36 Y3.41 S231
+M03 F1000 X7.27 Y3.32 S255
+M03 F1000 X7.18 Y3.23 S231
+M03 F1000 X7.09 Y3.14 S11
+M05 F2000 X6.73 Y2.78
+M03 F1000 X6.64 Y2.70 S32
+M03 F1000 X6.37 Y2.43 S255
+M03 F1000 X6.29 Y2.34 S43
+M05 F2000 X6.55 Y2.43
+M03 F1000 X6.64 Y2.52 S207
+M03 F1000 X6.91 Y2.78 S255
+M03 F1000 X7.00 Y2.87 S32
+M05 F2000 X7.27 Y3.14
+M03 F1000 X7.36 Y3.23 S11
+M03 F1000 X7.45 Y3.32 S179
+M03 F1000 X7.63 Y3.50 S255
+M03 F1000 X7.72 Y3.59 S90
+M05 F2000 X8.44 Y4.31
+M03 F1000 X8.53 Y4.40 S32
+M03 F1000 X8.98 Y4.85 S255
+M03 F1000 X9.16 Y5.03 S179
+M03 F1000 X9.25 Y5.12 S11
+M05 F2000 X9.16 Y4.85
+M03 F1000 X9.07 Y4.76 S11
+M03 F1000 X8.53 Y4.22 S255
+M03 F1000 X8.44 Y4.13 S68
+M05 F2000 X7.72 Y3.41
+M03 F1000 X7.63 Y3.32 S153
+M03 F1000 X7.36 Y3.05 S255
+M03 F1000 X7.27 Y2.96 S105
+M05 F2000 X7.09 Y2.78
+M03 F1000 X7.00 Y2.70 S43
+M03 F1000 X6.64 Y2.34 S255
+M05 F2000 X6.91 Y2.43
+M03 F1000 X7.27 Y2.78 S255
+M03 F1000 X7.36 Y2.87 S141
+M03 F1000 X7.45 Y2.96 S105
+M03 F1000 X7.81 Y3.32 S255
+M03 F1000 X7.90 Y3.41 S68
+M05 F2000 X8.53 Y4.04
+M03 F1000 X8.62 Y4.13 S68
+M03 F1000 X9.34 Y4.85 S255
+M05 F2000 X9.43 Y4.76
+M03 F1000 X8.98 Y4.31 S255
+M03 F1000 X8.89 Y4.22 S179
+M03 F1000 X8.53 Y3.86 S255
+M03 F1000 X8.44 Y3.77 S68
+M05 F2000 X7.90 Y3.23
+M03 F1000 X7.72 Y3.05 S153
+M03 F1000 X7.09 Y2.43 S255
+M03 F1000 X7.00 Y2.34 S231
+M05 F2000 X7.27 Y2.43
+M03 F1000 X7.36 Y2.52 S169
+M03 F1000 X7.81 Y2.96 S255
+M03 F1000 X7.90 Y3.05 S153
+M03 F1000 X7.99 Y3.14 S179
+M05 F2000 X8.53 Y3.68
+M03 F1000 X8.62 Y3.77 S68
+M03 F1000 X8.98 Y4.13 S255
+M03 F1000 X9.16 Y4.31 S179
+M03 F1000 X9.25 Y4.40 S43
+M03 F1000 X9.61 Y4.76 S255
+M03 F1000 X9.70 Y4.85 S68
+M05 F2000 X9.70 Y4.67
+M03 F1000 X9.52 Y4.49 S68
+M03 F1000 X9.34 Y4.31 S255
+M03 F1000 X9.25 Y4.22 S32
+M05 F2000 X9.16 Y4.13
+M03 F1000 X9.07 Y4.04 S11
+M03 F1000 X8.89 Y3.86 S179
+M03 F1000 X8.53 Y3.50 S255
+M03 F1000 X8.44 Y3.41 S68
+M05 F2000 X7.90 Y2.87
+M03 F1000 X7.81 Y2.78 S153
+M03 F1000 X7.72 Y2.70 S68
+M03 F1000 X7.63 Y2.61 S153
+M03 F1000 X7.45 Y2.43 S255
+M03 F1000 X7.36 Y2.34 S22
+M05 F2000 X7.72 Y2.52
+M03 F1000 X7.81 Y2.61 S11
+M05 F2000 X8.53 Y3.32
+M03 F1000 X8.62 Y3.41 S68
+M03 F1000 X8.98 Y3.77 S255
+M03 F1000 X9.16 Y3.95 S179
+M03 F1000 X9.25 Y4.04 S11
+M05 F2000 X9.52 Y4.31
+M03 F1000 X9.61 Y4.40 S32
+M03 F1000 X9.79 Y4.58 S68
+M05 F2000 X9.16 Y3.77
+M03 F1000 X9.07 Y3.68 S11
+M03 F1000 X8.89 Y3.50 S179
+M03 F1000 X8.53 Y3.14 S255
+M03 F1000 X8.44 Y3.05 S68
+M05 F2000 X8.53 Y2.96
+M03 F1000 X8.62 Y3.05 S68
+M03 F1000 X8.98 Y3.41 S255
+M03 F1000 X9.16 Y3.59 S179
+M03 F1000 X9.25 Y3.68 S11
+M05 F2000 X9.16 Y3.41
+M03 F1000 X9.07 Y3.32 S11
+M03 F1000 X8.89 Y3.14 S179
+M03 F1000 X8.53 Y2.78 S255
+M03 F1000 X8.44 Y2.70 S44
+M05 F2000 X8.53 Y2.61
+M03 F1000 X8.62 Y2.70 S11
+M03 F1000 X8.71 Y2.78 S179
+M03 F1000 X8.98 Y3.05 S255
+M03 F1000 X9.07 Y3.14 S217
+M03 F1000 X9.16 Y3.23 S179
+M03 F1000 X9.25 Y3.32 S11
+M05 F2000 X9.16 Y3.05
+M03 F1000 X9.07 Y2.96 S22
+M03 F1000 X8.89 Y2.78 S231
+M03 F1000 X8.62 Y2.52 S255
+M03 F1000 X8.53 Y2.43 S44
+M05 F2000 X8.71 Y2.43
+M03 F1000 X8.80 Y2.52 S22
+M03 F1000 X8.89 Y2.61 S231
+M03 F1000 X9.16 Y2.87 S255
+M03 F1000 X9.25 Y2.96 S44
+M05 F2000 X9.25 Y2.78
+M03 F1000 X9.16 Y2.70 S101
+M03 F1000 X8.89 Y2.43 S255
+M03 F1000 X8.80 Y2.34 S129
+M05 F2000 X9.07 Y2.43
+M03 F1000 X9.16 Y2.52 S231
+M03 F1000 X9.43 Y2.78 S255
+M03 F1000 X9.52 Y2.87 S64
+M05 F2000 X9.61 Y2.78
+M03 F1000 X9.52 Y2.70 S55
+M03 F1000 X9.16 Y2.34 S255
+M05 F2000 X9.43 Y2.43
+M03 F1000 X9.52 Y2.52 S231
+M03 F1000 X9.61 Y2.61 S255
+M03 F1000 X9.79 Y2.78 S68
+M05 F2000 X9.70 Y2.52
+M03 F1000 X9.61 Y2.43 S68
+M03 F1000 X9.52 Y2.34 S32
+M05 F2000 X0 Y0
\ No newline at end of file
@@ -0,0 +1,272 @@
+G01
+M05 F2000 X2.92 Y5.46
+M03 F1000 X2.54 Y5.46 S255
+M05 F2000 X2.67 Y5.33
+M03 F1000 X3.05 Y5.33 S255
+M05 F2000 X9.14 Y5.33
+M03 F1000 X9.27 Y5.33 S255
+M05 F2000 X8.89 Y5.21
+M03 F1000 X8.64 Y5.21 S255
+M05 F2000 X2.92 Y5.21
+M03 F1000 X2.29 Y5.21 S255
+M05 F2000 X2.29 Y5.08
+M03 F1000 X2.79 Y5.08 S255
+M05 F2000 X8.64 Y5.08
+M03 F1000 X9.14 Y5.08 S255
+M05 F2000 X9.14 Y4.95
+M03 F1000 X8.38 Y4.95 S255
+M05 F2000 X2.79 Y4.95
+M03 F1000 X2.03 Y4.95 S255
+M05 F2000 X2.03 Y4.83
+M03 F1000 X3.05 Y4.83 S255
+M05 F2000 X4.95 Y4.83
+M03 F1000 X5.08 Y4.83 S255
+M05 F2000 X6.99 Y4.83
+M03 F1000 X7.24 Y4.83 S255
+M05 F2000 X7.87 Y4.83
+M03 F1000 X8.00 Y4.83 S255
+M05 F2000 X8.38 Y4.83
+M03 F1000 X9.40 Y4.83 S255
+M05 F2000 X9.40 Y4.70
+M03 F1000 X8.13 Y4.70 S255
+M05 F2000 X8.00 Y4.70
+M03 F1000 X7.49 Y4.70 S255
+M05 F2000 X7.24 Y4.70
+M03 F1000 X6.73 Y4.70 S255
+M05 F2000 X5.08 Y4.70
+M03 F1000 X4.70 Y4.70 S255
+M05 F2000 X4.32 Y4.70
+M03 F1000 X4.06 Y4.70 S255
+M05 F2000 X3.05 Y4.70
+M03 F1000 X1.78 Y4.70 S255
+M05 F2000 X1.91 Y4.57
+M03 F1000 X3.18 Y4.57 S255
+M05 F2000 X4.19 Y4.57
+M03 F1000 X4.45 Y4.57 S255
+M05 F2000 X4.70 Y4.57
+M03 F1000 X5.33 Y4.57 S255
+M05 F2000 X6.86 Y4.57
+M03 F1000 X7.49 Y4.57 S255
+M05 F2000 X7.62 Y4.57
+M03 F1000 X8.00 Y4.57 S255
+M05 F2000 X8.26 Y4.57
+M03 F1000 X9.53 Y4.57 S255
+M05 F2000 X9.27 Y4.45
+M03 F1000 X8.26 Y4.45 S255
+M05 F2000 X7.87 Y4.45
+M03 F1000 X7.49 Y4.45 S255
+M05 F2000 X7.24 Y4.45
+M03 F1000 X6.48 Y4.45 S255
+M05 F2000 X5.33 Y4.45
+M03 F1000 X4.70 Y4.45 S255
+M05 F2000 X3.94 Y4.45
+M03 F1000 X3.68 Y4.45 S255
+M05 F2000 X2.92 Y4.45
+M03 F1000 X1.91 Y4.45 S255
+M05 F2000 X2.16 Y4.32
+M03 F1000 X2.92 Y4.32 S255
+M05 F2000 X3.68 Y4.32
+M03 F1000 X4.19 Y4.32 S255
+M05 F2000 X4.95 Y4.32
+M03 F1000 X5.59 Y4.32 S255
+M05 F2000 X6.48 Y4.32
+M03 F1000 X7.24 Y4.32 S255
+M05 F2000 X8.51 Y4.32
+M03 F1000 X9.27 Y4.32 S255
+M05 F2000 X9.02 Y4.19
+M03 F1000 X8.51 Y4.19 S255
+M05 F2000 X6.86 Y4.19
+M03 F1000 X6.22 Y4.19 S255
+M05 F2000 X5.59 Y4.19
+M03 F1000 X4.95 Y4.19 S255
+M05 F2000 X4.06 Y4.19
+M03 F1000 X3.56 Y4.19 S255
+M05 F2000 X2.67 Y4.19
+M03 F1000 X2.16 Y4.19 S255
+M05 F2000 X2.41 Y4.06
+M03 F1000 X3.05 Y4.06 S255
+M05 F2000 X3.81 Y4.06
+M03 F1000 X4.45 Y4.06 S255
+M05 F2000 X5.21 Y4.06
+M03 F1000 X5.84 Y4.06 S255
+M05 F2000 X6.22 Y4.06
+M03 F1000 X6.99 Y4.06 S255
+M05 F2000 X8.76 Y4.06
+M03 F1000 X9.02 Y4.06 S255
+M05 F2000 X8.51 Y3.94
+M03 F1000 X8.26 Y3.94 S255
+M05 F2000 X6.60 Y3.94
+M03 F1000 X5.97 Y3.94 S255
+M05 F2000 X5.84 Y3.94
+M03 F1000 X5.21 Y3.94 S255
+M05 F2000 X4.45 Y3.94
+M03 F1000 X3.94 Y3.94 S255
+M05 F2000 X2.92 Y3.94
+M03 F1000 X2.54 Y3.94 S255
+M05 F2000 X2.41 Y3.81
+M03 F1000 X3.18 Y3.81 S255
+M05 F2000 X3.94 Y3.81
+M03 F1000 X4.70 Y3.81 S255
+M05 F2000 X5.33 Y3.81
+M03 F1000 X5.97 Y3.81 S255
+M05 F2000 X6.10 Y3.81
+M03 F1000 X6.86 Y3.81 S255
+M05 F2000 X7.37 Y3.81
+M03 F1000 X7.62 Y3.81 S255
+M05 F2000 X8.38 Y3.81
+M03 F1000 X8.64 Y3.81 S255
+M05 F2000 X8.76 Y3.81
+M03 F1000 X9.02 Y3.81 S255
+M05 F2000 X9.02 Y3.68
+M03 F1000 X8.51 Y3.68 S255
+M05 F2000 X7.62 Y3.68
+M03 F1000 X7.11 Y3.68 S255
+M05 F2000 X6.86 Y3.68
+M03 F1000 X6.10 Y3.68 S255
+M05 F2000 X5.72 Y3.68
+M03 F1000 X5.08 Y3.68 S255
+M05 F2000 X4.70 Y3.68
+M03 F1000 X3.68 Y3.68 S255
+M05 F2000 X2.92 Y3.68
+M03 F1000 X2.79 Y3.68 S255
+M05 F2000 X2.67 Y3.68
+M03 F1000 X2.16 Y3.68 S255
+M05 F2000 X2.16 Y3.56
+M03 F1000 X2.92 Y3.56 S255
+M05 F2000 X3.68 Y3.56
+M03 F1000 X4.95 Y3.56 S255
+M05 F2000 X5.08 Y3.56
+M03 F1000 X5.72 Y3.56 S255
+M05 F2000 X5.97 Y3.56
+M03 F1000 X6.10 Y3.56 S255
+M05 F2000 X6.35 Y3.56
+M03 F1000 X7.87 Y3.56 S255
+M05 F2000 X8.51 Y3.56
+M03 F1000 X9.27 Y3.56 S255
+M05 F2000 X9.02 Y3.43
+M03 F1000 X8.51 Y3.43 S255
+M05 F2000 X7.87 Y3.43
+M03 F1000 X7.11 Y3.43 S255
+M05 F2000 X6.86 Y3.43
+M03 F1000 X6.35 Y3.43 S255
+M05 F2000 X5.46 Y3.43
+M03 F1000 X4.95 Y3.43 S255
+M05 F2000 X4.83 Y3.43
+M03 F1000 X3.56 Y3.43 S255
+M05 F2000 X2.67 Y3.43
+M03 F1000 X2.16 Y3.43 S255
+M05 F2000 X2.41 Y3.30
+M03 F1000 X3.18 Y3.30 S255
+M05 F2000 X3.81 Y3.30
+M03 F1000 X4.83 Y3.30 S255
+M05 F2000 X5.21 Y3.30
+M03 F1000 X5.46 Y3.30 S255
+M05 F2000 X6.60 Y3.30
+M03 F1000 X6.86 Y3.30 S255
+M05 F2000 X7.37 Y3.30
+M03 F1000 X8.13 Y3.30 S255
+M05 F2000 X8.51 Y3.30
+M03 F1000 X8.64 Y3.30 S255
+M05 F2000 X8.76 Y3.30
+M03 F1000 X9.02 Y3.30 S255
+M05 F2000 X9.14 Y3.18
+M03 F1000 X9.02 Y3.18 S255
+M05 F2000 X8.51 Y3.18
+M03 F1000 X8.26 Y3.18 S255
+M05 F2000 X8.00 Y3.18
+M03 F1000 X7.37 Y3.18 S255
+M05 F2000 X4.45 Y3.18
+M03 F1000 X3.94 Y3.18 S255
+M05 F2000 X2.92 Y3.18
+M03 F1000 X2.54 Y3.18 S255
+M05 F2000 X2.41 Y3.05
+M03 F1000 X3.18 Y3.05 S255
+M05 F2000 X3.81 Y3.05
+M03 F1000 X3.94 Y3.05 S255
+M05 F2000 X4.06 Y3.05
+M03 F1000 X4.83 Y3.05 S255
+M05 F2000 X7.37 Y3.05
+M03 F1000 X8.00 Y3.05 S255
+M05 F2000 X8.51 Y3.05
+M03 F1000 X8.64 Y3.05 S255
+M05 F2000 X8.76 Y3.05
+M03 F1000 X9.02 Y3.05 S255
+M05 F2000 X9.65 Y2.92
+M03 F1000 X9.27 Y2.92 S255
+M05 F2000 X9.02 Y2.92
+M03 F1000 X8.51 Y2.92 S255
+M05 F2000 X7.75 Y2.92
+M03 F1000 X7.11 Y2.92 S255
+M05 F2000 X6.73 Y2.92
+M03 F1000 X6.60 Y2.92 S255
+M05 F2000 X5.46 Y2.92
+M03 F1000 X5.08 Y2.92 S255
+M05 F2000 X4.83 Y2.92
+M03 F1000 X4.19 Y2.92 S255
+M05 F2000 X4.06 Y2.92
+M03 F1000 X3.56 Y2.92 S255
+M05 F2000 X3.18 Y2.92
+M03 F1000 X3.05 Y2.92 S255
+M05 F2000 X2.67 Y2.92
+M03 F1000 X2.16 Y2.92 S255
+M05 F2000 X2.29 Y2.79
+M03 F1000 X2.79 Y2.79 S255
+M05 F2000 X3.05 Y2.79
+M03 F1000 X3.43 Y2.79 S255
+M05 F2000 X3.81 Y2.79
+M03 F1000 X4.19 Y2.79 S255
+M05 F2000 X4.45 Y2.79
+M03 F1000 X5.08 Y2.79 S255
+M05 F2000 X5.21 Y2.79
+M03 F1000 X5.59 Y2.79 S255
+M05 F2000 X6.73 Y2.79
+M03 F1000 X6.86 Y2.79 S255
+M05 F2000 X7.11 Y2.79
+M03 F1000 X7.75 Y2.79 S255
+M05 F2000 X8.64 Y2.79
+M03 F1000 X9.27 Y2.79 S255
+M05 F2000 X9.40 Y2.79
+M03 F1000 X9.78 Y2.79 S255
+M05 F2000 X9.53 Y2.67
+M03 F1000 X9.40 Y2.67 S255
+M05 F2000 X9.27 Y2.67
+M03 F1000 X8.64 Y2.67 S255
+M05 F2000 X7.49 Y2.67
+M03 F1000 X6.86 Y2.67 S255
+M05 F2000 X5.46 Y2.67
+M03 F1000 X4.32 Y2.67 S255
+M05 F2000 X4.06 Y2.67
+M03 F1000 X3.68 Y2.67 S255
+M05 F2000 X2.92 Y2.67
+M03 F1000 X2.16 Y2.67 S255
+M05 F2000 X2.41 Y2.54
+M03 F1000 X3.18 Y2.54 S255
+M05 F2000 X4.70 Y2.54
+M03 F1000 X5.33 Y2.54 S255
+M05 F2000 X6.35 Y2.54
+M03 F1000 X6.48 Y2.54 S255
+M05 F2000 X6.86 Y2.54
+M03 F1000 X7.49 Y2.54 S255
+M05 F2000 X8.89 Y2.54
+M03 F1000 X9.53 Y2.54 S255
+M05 F2000 X9.40 Y2.41
+M03 F1000 X8.89 Y2.41 S255
+M05 F2000 X7.24 Y2.41
+M03 F1000 X6.73 Y2.41 S255
+M05 F2000 X6.35 Y2.41
+M03 F1000 X6.22 Y2.41 S255
+M05 F2000 X5.21 Y2.41
+M03 F1000 X4.57 Y2.41 S255
+M05 F2000 X3.05 Y2.41
+M03 F1000 X2.41 Y2.41 S255
+M05 F2000 X2.67 Y2.29
+M03 F1000 X3.05 Y2.29 S255
+M05 F2000 X4.83 Y2.29
+M03 F1000 X5.21 Y2.29 S255
+M05 F2000 X6.99 Y2.29
+M03 F1000 X7.24 Y2.29 S255
+M05 F2000 X9.14 Y2.29
+M03 F1000 X9.40 Y2.29 S255
+M05 F2000 X4.95 Y2.16
+M03 F1000 X4.83 Y2.16 S255
+M05 F2000 X0 Y0
\ No newline at end of file
@@ -0,0 +1,446 @@
+G01
+M05 F2000 X1.91 Y4.45
+M03 F1000 X1.91 Y4.83 S68
+M05 F2000 X2.03 Y4.32
+M03 F1000 X2.03 Y4.45 S32
+M03 F1000 X2.03 Y4.83 S255
+M05 F2000 X2.16 Y4.32
+M03 F1000 X2.16 Y4.45 S32
+M03 F1000 X2.16 Y4.83 S255
+M05 F2000 X2.29 Y2.67
+M03 F1000 X2.29 Y2.79 S11
+M03 F1000 X2.29 Y2.92 S44
+M03 F1000 X2.29 Y4.32 S68
+M03 F1000 X2.29 Y4.45 S79
+M03 F1000 X2.29 Y4.83 S255
+M03 F1000 X2.29 Y5.33 S68
+M05 F2000 X2.41 Y2.54
+M03 F1000 X2.41 Y2.67 S44
+M03 F1000 X2.41 Y2.79 S179
+M03 F1000 X2.41 Y2.92 S231
+M03 F1000 X2.41 Y5.33 S255
+M03 F1000 X2.41 Y5.46 S11
+M05 F2000 X2.54 Y2.41
+M03 F1000 X2.54 Y2.54 S22
+M03 F1000 X2.54 Y2.67 S231
+M03 F1000 X2.54 Y5.33 S255
+M03 F1000 X2.54 Y5.46 S65
+M05 F2000 X2.67 Y2.41
+M03 F1000 X2.67 Y2.54 S129
+M03 F1000 X2.67 Y5.33 S255
+M03 F1000 X2.67 Y5.46 S141
+M05 F2000 X2.79 Y2.41
+M03 F1000 X2.79 Y2.54 S231
+M03 F1000 X2.79 Y2.92 S255
+M03 F1000 X2.79 Y3.05 S231
+M03 F1000 X2.79 Y3.18 S217
+M03 F1000 X2.79 Y4.45 S179
+M03 F1000 X2.79 Y4.83 S255
+M03 F1000 X2.79 Y5.33 S179
+M03 F1000 X2.79 Y5.46 S155
+M05 F2000 X2.92 Y2.41
+M03 F1000 X2.92 Y2.79 S255
+M03 F1000 X2.92 Y2.92 S231
+M03 F1000 X2.92 Y3.05 S44
+M03 F1000 X2.92 Y3.18 S22
+M03 F1000 X2.92 Y4.32 S11
+M03 F1000 X2.92 Y4.45 S43
+M03 F1000 X2.92 Y4.83 S255
+M03 F1000 X2.92 Y5.46 S11
+M05 F2000 X3.05 Y2.41
+M03 F1000 X3.05 Y2.79 S255
+M03 F1000 X3.05 Y2.92 S101
+M05 F2000 X3.05 Y4.32
+M03 F1000 X3.05 Y4.45 S32
+M03 F1000 X3.05 Y4.83 S255
+M05 F2000 X3.18 Y2.41
+M03 F1000 X3.18 Y2.54 S231
+M03 F1000 X3.18 Y2.79 S255
+M03 F1000 X3.18 Y2.92 S64
+M05 F2000 X3.18 Y4.32
+M03 F1000 X3.18 Y4.45 S32
+M03 F1000 X3.18 Y4.83 S255
+M05 F2000 X3.30 Y2.41
+M03 F1000 X3.30 Y2.54 S169
+M03 F1000 X3.30 Y2.79 S255
+M03 F1000 X3.30 Y2.92 S101
+M05 F2000 X3.30 Y4.32
+M03 F1000 X3.30 Y4.45 S32
+M03 F1000 X3.30 Y4.83 S255
+M05 F2000 X3.43 Y2.41
+M03 F1000 X3.43 Y2.54 S32
+M03 F1000 X3.43 Y2.79 S68
+M03 F1000 X3.43 Y2.92 S55
+M05 F2000 X3.43 Y4.45
+M03 F1000 X3.43 Y4.83 S68
+M05 F2000 X3.68 Y3.05
+M03 F1000 X3.68 Y3.18 S11
+M03 F1000 X3.68 Y3.30 S22
+M03 F1000 X3.68 Y3.81 S68
+M03 F1000 X3.68 Y3.94 S44
+M03 F1000 X3.68 Y4.06 S22
+M05 F2000 X3.81 Y2.79
+M03 F1000 X3.81 Y2.92 S11
+M03 F1000 X3.81 Y3.05 S68
+M03 F1000 X3.81 Y3.18 S179
+M03 F1000 X3.81 Y3.30 S217
+M03 F1000 X3.81 Y3.81 S255
+M03 F1000 X3.81 Y3.94 S231
+M03 F1000 X3.81 Y4.06 S217
+M03 F1000 X3.81 Y4.19 S153
+M03 F1000 X3.81 Y4.32 S44
+M05 F2000 X3.94 Y2.67
+M03 F1000 X3.94 Y2.79 S22
+M03 F1000 X3.94 Y2.92 S179
+M03 F1000 X3.94 Y4.19 S255
+M03 F1000 X3.94 Y4.32 S231
+M03 F1000 X3.94 Y4.45 S105
+M05 F2000 X4.06 Y2.54
+M03 F1000 X4.06 Y2.67 S11
+M03 F1000 X4.06 Y2.79 S217
+M03 F1000 X4.06 Y4.45 S255
+M03 F1000 X4.06 Y4.57 S129
+M05 F2000 X4.19 Y2.54
+M03 F1000 X4.19 Y2.67 S141
+M03 F1000 X4.19 Y3.18 S255
+M03 F1000 X4.19 Y3.30 S217
+M03 F1000 X4.19 Y3.43 S179
+M03 F1000 X4.19 Y3.81 S255
+M03 F1000 X4.19 Y3.94 S179
+M03 F1000 X4.19 Y4.06 S231
+M03 F1000 X4.19 Y4.57 S255
+M03 F1000 X4.19 Y4.70 S79
+M05 F2000 X4.32 Y2.41
+M03 F1000 X4.32 Y2.54 S32
+M03 F1000 X4.32 Y3.05 S255
+M03 F1000 X4.32 Y3.18 S105
+M03 F1000 X4.32 Y3.30 S22
+M03 F1000 X4.32 Y3.43 S11
+M03 F1000 X4.32 Y3.81 S255
+M03 F1000 X4.32 Y3.94 S11
+M03 F1000 X4.32 Y4.06 S44
+M03 F1000 X4.32 Y4.19 S153
+M03 F1000 X4.32 Y4.57 S255
+M03 F1000 X4.32 Y4.70 S231
+M03 F1000 X4.32 Y4.83 S11
+M05 F2000 X4.45 Y2.41
+M03 F1000 X4.45 Y2.54 S141
+M03 F1000 X4.45 Y2.92 S255
+M03 F1000 X4.45 Y3.05 S79
+M05 F2000 X4.45 Y3.43
+M03 F1000 X4.45 Y3.81 S255
+M05 F2000 X4.45 Y4.19
+M03 F1000 X4.45 Y4.32 S79
+M03 F1000 X4.45 Y4.70 S255
+M03 F1000 X4.45 Y4.83 S88
+M05 F2000 X4.57 Y2.41
+M03 F1000 X4.57 Y2.54 S207
+M03 F1000 X4.57 Y2.79 S255
+M03 F1000 X4.57 Y2.92 S207
+M05 F2000 X4.57 Y3.43
+M03 F1000 X4.57 Y3.81 S255
+M05 F2000 X4.57 Y4.32
+M03 F1000 X4.57 Y4.45 S129
+M03 F1000 X4.57 Y4.70 S255
+M03 F1000 X4.57 Y4.83 S169
+M05 F2000 X4.70 Y2.41
+M03 F1000 X4.70 Y2.79 S255
+M03 F1000 X4.70 Y2.92 S101
+M05 F2000 X4.70 Y3.43
+M03 F1000 X4.70 Y3.81 S255
+M05 F2000 X4.70 Y4.32
+M03 F1000 X4.70 Y4.45 S32
+M03 F1000 X4.70 Y4.83 S255
+M05 F2000 X4.83 Y2.41
+M03 F1000 X4.83 Y2.79 S255
+M03 F1000 X4.83 Y2.92 S43
+M05 F2000 X4.83 Y3.43
+M03 F1000 X4.83 Y3.81 S255
+M05 F2000 X4.83 Y4.45
+M03 F1000 X4.83 Y4.83 S255
+M05 F2000 X4.95 Y2.41
+M03 F1000 X4.95 Y2.79 S255
+M03 F1000 X4.95 Y2.92 S32
+M05 F2000 X4.95 Y3.43
+M03 F1000 X4.95 Y3.81 S255
+M05 F2000 X4.95 Y4.32
+M03 F1000 X4.95 Y4.45 S32
+M03 F1000 X4.95 Y4.83 S255
+M05 F2000 X5.08 Y2.41
+M03 F1000 X5.08 Y2.54 S231
+M03 F1000 X5.08 Y2.79 S255
+M03 F1000 X5.08 Y2.92 S32
+M05 F2000 X5.08 Y3.43
+M03 F1000 X5.08 Y3.81 S255
+M05 F2000 X5.08 Y4.32
+M03 F1000 X5.08 Y4.45 S116
+M03 F1000 X5.08 Y4.70 S255
+M03 F1000 X5.08 Y4.83 S207
+M05 F2000 X5.21 Y2.41
+M03 F1000 X5.21 Y2.54 S183
+M03 F1000 X5.21 Y2.79 S255
+M03 F1000 X5.21 Y2.92 S54
+M05 F2000 X5.21 Y3.43
+M03 F1000 X5.21 Y3.81 S255
+M05 F2000 X5.21 Y4.06
+M03 F1000 X5.21 Y4.19 S11
+M03 F1000 X5.21 Y4.32 S68
+M03 F1000 X5.21 Y4.70 S255
+M03 F1000 X5.21 Y4.83 S141
+M05 F2000 X5.33 Y2.41
+M03 F1000 X5.33 Y2.54 S126
+M03 F1000 X5.33 Y2.79 S255
+M03 F1000 X5.33 Y2.92 S141
+M05 F2000 X5.33 Y3.43
+M03 F1000 X5.33 Y3.81 S255
+M03 F1000 X5.33 Y3.94 S68
+M03 F1000 X5.33 Y4.06 S105
+M03 F1000 X5.33 Y4.19 S179
+M03 F1000 X5.33 Y4.70 S255
+M03 F1000 X5.33 Y4.83 S32
+M05 F2000 X5.46 Y2.41
+M03 F1000 X5.46 Y2.54 S43
+M03 F1000 X5.46 Y2.79 S255
+M03 F1000 X5.46 Y2.92 S231
+M03 F1000 X5.46 Y3.05 S11
+M05 F2000 X5.46 Y3.43
+M03 F1000 X5.46 Y4.57 S255
+M03 F1000 X5.46 Y4.70 S129
+M05 F2000 X5.59 Y2.54
+M03 F1000 X5.59 Y2.67 S193
+M03 F1000 X5.59 Y2.92 S255
+M03 F1000 X5.59 Y3.05 S79
+M05 F2000 X5.59 Y3.43
+M03 F1000 X5.59 Y4.45 S255
+M03 F1000 X5.59 Y4.57 S153
+M03 F1000 X5.59 Y4.70 S11
+M05 F2000 X5.72 Y2.54
+M03 F1000 X5.72 Y2.67 S44
+M03 F1000 X5.72 Y2.92 S68
+M03 F1000 X5.72 Y3.05 S55
+M05 F2000 X5.72 Y3.43
+M03 F1000 X5.72 Y4.19 S255
+M03 F1000 X5.72 Y4.32 S217
+M03 F1000 X5.72 Y4.45 S105
+M05 F2000 X5.84 Y3.43
+M03 F1000 X5.84 Y3.81 S179
+M03 F1000 X5.84 Y3.94 S153
+M03 F1000 X5.84 Y4.06 S105
+M03 F1000 X5.84 Y4.19 S68
+M03 F1000 X5.84 Y4.32 S22
+M05 F2000 X5.97 Y3.43
+M03 F1000 X5.97 Y3.81 S11
+M05 F2000 X6.22 Y2.54
+M03 F1000 X6.22 Y2.92 S105
+M03 F1000 X6.22 Y3.05 S90
+M05 F2000 X6.22 Y3.81
+M03 F1000 X6.22 Y3.94 S22
+M03 F1000 X6.22 Y4.06 S68
+M03 F1000 X6.22 Y4.19 S105
+M03 F1000 X6.22 Y4.32 S68
+M03 F1000 X6.22 Y4.45 S22
+M05 F2000 X6.35 Y2.41
+M03 F1000 X6.35 Y2.54 S43
+M03 F1000 X6.35 Y2.92 S255
+M03 F1000 X6.35 Y3.05 S79
+M05 F2000 X6.35 Y3.68
+M03 F1000 X6.35 Y3.81 S68
+M03 F1000 X6.35 Y3.94 S217
+M03 F1000 X6.35 Y4.32 S255
+M03 F1000 X6.35 Y4.45 S217
+M03 F1000 X6.35 Y4.57 S68
+M05 F2000 X6.48 Y2.41
+M03 F1000 X6.48 Y2.54 S141
+M03 F1000 X6.48 Y2.79 S255
+M03 F1000 X6.48 Y2.92 S207
+M05 F2000 X6.48 Y3.56
+M03 F1000 X6.48 Y3.68 S44
+M03 F1000 X6.48 Y4.57 S255
+M03 F1000 X6.48 Y4.70 S79
+M05 F2000 X6.60 Y2.41
+M03 F1000 X6.60 Y2.54 S207
+M03 F1000 X6.60 Y2.79 S255
+M03 F1000 X6.60 Y2.92 S101
+M05 F2000 X6.60 Y3.56
+M03 F1000 X6.60 Y3.68 S193
+M03 F1000 X6.60 Y4.57 S255
+M03 F1000 X6.60 Y4.70 S231
+M03 F1000 X6.60 Y4.83 S11
+M05 F2000 X6.73 Y2.41
+M03 F1000 X6.73 Y2.79 S255
+M03 F1000 X6.73 Y2.92 S32
+M05 F2000 X6.73 Y3.43
+M03 F1000 X6.73 Y3.56 S65
+M03 F1000 X6.73 Y3.94 S255
+M03 F1000 X6.73 Y4.06 S231
+M03 F1000 X6.73 Y4.19 S179
+M03 F1000 X6.73 Y4.32 S217
+M03 F1000 X6.73 Y4.70 S255
+M03 F1000 X6.73 Y4.83 S65
+M05 F2000 X6.86 Y2.41
+M03 F1000 X6.86 Y2.79 S255
+M03 F1000 X6.86 Y2.92 S32
+M05 F2000 X6.86 Y3.43
+M03 F1000 X6.86 Y3.56 S193
+M03 F1000 X6.86 Y3.94 S255
+M03 F1000 X6.86 Y4.06 S44
+M03 F1000 X6.86 Y4.19 S11
+M03 F1000 X6.86 Y4.32 S22
+M03 F1000 X6.86 Y4.45 S193
+M03 F1000 X6.86 Y4.70 S255
+M03 F1000 X6.86 Y4.83 S169
+M05 F2000 X6.99 Y2.41
+M03 F1000 X6.99 Y2.79 S255
+M03 F1000 X6.99 Y2.92 S32
+M05 F2000 X6.99 Y3.30
+M03 F1000 X6.99 Y3.43 S11
+M03 F1000 X6.99 Y3.81 S255
+M03 F1000 X6.99 Y3.94 S169
+M05 F2000 X6.99 Y4.32
+M03 F1000 X6.99 Y4.45 S65
+M03 F1000 X6.99 Y4.70 S255
+M03 F1000 X6.99 Y4.83 S231
+M05 F2000 X7.11 Y2.41
+M03 F1000 X7.11 Y2.54 S231
+M03 F1000 X7.11 Y2.79 S255
+M03 F1000 X7.11 Y2.92 S43
+M05 F2000 X7.11 Y3.30
+M03 F1000 X7.11 Y3.43 S101
+M03 F1000 X7.11 Y3.81 S255
+M03 F1000 X7.11 Y3.94 S65
+M05 F2000 X7.11 Y4.32
+M03 F1000 X7.11 Y4.45 S32
+M03 F1000 X7.11 Y4.83 S255
+M05 F2000 X7.24 Y2.41
+M03 F1000 X7.24 Y2.54 S169
+M03 F1000 X7.24 Y2.79 S255
+M03 F1000 X7.24 Y2.92 S141
+M05 F2000 X7.24 Y3.18
+M03 F1000 X7.24 Y3.30 S11
+M03 F1000 X7.24 Y3.43 S231
+M03 F1000 X7.24 Y3.68 S255
+M03 F1000 X7.24 Y3.81 S231
+M05 F2000 X7.24 Y4.32
+M03 F1000 X7.24 Y4.45 S32
+M03 F1000 X7.24 Y4.83 S255
+M05 F2000 X7.37 Y2.41
+M03 F1000 X7.37 Y2.54 S101
+M03 F1000 X7.37 Y2.92 S255
+M03 F1000 X7.37 Y3.18 S105
+M03 F1000 X7.37 Y3.30 S179
+M03 F1000 X7.37 Y3.68 S255
+M03 F1000 X7.37 Y3.81 S129
+M05 F2000 X7.37 Y4.32
+M03 F1000 X7.37 Y4.45 S32
+M03 F1000 X7.37 Y4.83 S255
+M05 F2000 X7.49 Y2.41
+M03 F1000 X7.49 Y2.54 S22
+M03 F1000 X7.49 Y3.56 S255
+M03 F1000 X7.49 Y3.68 S231
+M03 F1000 X7.49 Y3.81 S22
+M05 F2000 X7.49 Y4.32
+M03 F1000 X7.49 Y4.45 S54
+M03 F1000 X7.49 Y4.70 S255
+M03 F1000 X7.49 Y4.83 S207
+M05 F2000 X7.62 Y2.54
+M03 F1000 X7.62 Y2.67 S129
+M03 F1000 X7.62 Y3.56 S255
+M03 F1000 X7.62 Y3.68 S90
+M05 F2000 X7.62 Y4.32
+M03 F1000 X7.62 Y4.45 S141
+M03 F1000 X7.62 Y4.70 S255
+M03 F1000 X7.62 Y4.83 S141
+M05 F2000 X7.75 Y2.54
+M03 F1000 X7.75 Y2.67 S11
+M03 F1000 X7.75 Y2.79 S153
+M03 F1000 X7.75 Y3.43 S255
+M03 F1000 X7.75 Y3.56 S153
+M05 F2000 X7.75 Y4.32
+M03 F1000 X7.75 Y4.45 S155
+M03 F1000 X7.75 Y4.70 S179
+M03 F1000 X7.75 Y4.83 S65
+M05 F2000 X7.87 Y2.79
+M03 F1000 X7.87 Y2.92 S68
+M03 F1000 X7.87 Y3.05 S153
+M03 F1000 X7.87 Y3.18 S179
+M03 F1000 X7.87 Y3.30 S153
+M03 F1000 X7.87 Y3.43 S68
+M05 F2000 X7.87 Y4.32
+M03 F1000 X7.87 Y4.70 S11
+M05 F2000 X8.00 Y3.05
+M03 F1000 X8.00 Y3.18 S11
+M05 F2000 X8.13 Y4.45
+M03 F1000 X8.13 Y4.83 S68
+M05 F2000 X8.26 Y4.32
+M03 F1000 X8.26 Y4.45 S32
+M03 F1000 X8.26 Y4.83 S255
+M05 F2000 X8.38 Y4.32
+M03 F1000 X8.38 Y4.45 S32
+M03 F1000 X8.38 Y4.83 S255
+M05 F2000 X8.51 Y2.67
+M03 F1000 X8.51 Y2.79 S11
+M03 F1000 X8.51 Y2.92 S44
+M03 F1000 X8.51 Y4.32 S68
+M03 F1000 X8.51 Y4.45 S79
+M03 F1000 X8.51 Y4.83 S255
+M03 F1000 X8.51 Y5.33 S68
+M05 F2000 X8.64 Y2.54
+M03 F1000 X8.64 Y2.67 S44
+M03 F1000 X8.64 Y2.79 S179
+M03 F1000 X8.64 Y2.92 S231
+M03 F1000 X8.64 Y5.33 S255
+M03 F1000 X8.64 Y5.46 S11
+M05 F2000 X8.76 Y2.41
+M03 F1000 X8.76 Y2.54 S22
+M03 F1000 X8.76 Y2.67 S231
+M03 F1000 X8.76 Y5.33 S255
+M03 F1000 X8.76 Y5.46 S65
+M05 F2000 X8.89 Y2.41
+M03 F1000 X8.89 Y2.54 S129
+M03 F1000 X8.89 Y5.33 S255
+M03 F1000 X8.89 Y5.46 S141
+M05 F2000 X9.02 Y2.41
+M03 F1000 X9.02 Y2.54 S231
+M03 F1000 X9.02 Y2.92 S255
+M03 F1000 X9.02 Y3.05 S231
+M03 F1000 X9.02 Y3.18 S217
+M03 F1000 X9.02 Y4.45 S179
+M03 F1000 X9.02 Y4.83 S255
+M03 F1000 X9.02 Y5.33 S179
+M03 F1000 X9.02 Y5.46 S155
+M05 F2000 X9.14 Y2.41
+M03 F1000 X9.14 Y2.79 S255
+M03 F1000 X9.14 Y2.92 S231
+M03 F1000 X9.14 Y3.05 S44
+M03 F1000 X9.14 Y3.18 S22
+M03 F1000 X9.14 Y4.32 S11
+M03 F1000 X9.14 Y4.45 S43
+M03 F1000 X9.14 Y4.83 S255
+M03 F1000 X9.14 Y5.46 S11
+M05 F2000 X9.27 Y2.41
+M03 F1000 X9.27 Y2.79 S255
+M03 F1000 X9.27 Y2.92 S101
+M05 F2000 X9.27 Y4.32
+M03 F1000 X9.27 Y4.45 S32
+M03 F1000 X9.27 Y4.83 S255
+M05 F2000 X9.40 Y2.41
+M03 F1000 X9.40 Y2.54 S231
+M03 F1000 X9.40 Y2.79 S255
+M03 F1000 X9.40 Y2.92 S64
+M05 F2000 X9.40 Y4.32
+M03 F1000 X9.40 Y4.45 S32
+M03 F1000 X9.40 Y4.83 S255
+M05 F2000 X9.53 Y2.41
+M03 F1000 X9.53 Y2.54 S169
+M03 F1000 X9.53 Y2.79 S255
+M03 F1000 X9.53 Y2.92 S101
+M05 F2000 X9.53 Y4.32
+M03 F1000 X9.53 Y4.45 S32
+M03 F1000 X9.53 Y4.83 S255
+M05 F2000 X9.65 Y2.41
+M03 F1000 X9.65 Y2.54 S32
+M03 F1000 X9.65 Y2.79 S68
+M03 F1000 X9.65 Y2.92 S55
+M05 F2000 X9.65 Y4.45
+M03 F1000 X9.65 Y4.83 S68
+M05 F2000 X0 Y0
\ No newline at end of file
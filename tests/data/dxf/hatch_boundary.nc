@@ -0,0 +1,161 @@
+G01
+M05 F2000 X0.00 Y0.00
+M03 F1000 X40.00 Y0.00 S255
+M03 F1000 X40.00 Y0.00 S255
+M03 F1000 X41.44 Y1.17 S255
+M03 F1000 X42.79 Y2.44 S255
+M03 F1000 X44.04 Y3.80 S255
+M03 F1000 X45.19 Y5.26 S255
+M03 F1000 X46.23 Y6.80 S255
+M03 F1000 X47.15 Y8.41 S255
+M03 F1000 X47.95 Y10.08 S255
+M03 F1000 X48.62 Y11.81 S255
+M03 F1000 X49.16 Y13.58 S255
+M03 F1000 X49.57 Y15.39 S255
+M03 F1000 X49.85 Y17.22 S255
+M03 F1000 X49.98 Y19.07 S255
+M03 F1000 X49.98 Y20.93 S255
+M03 F1000 X49.85 Y22.78 S255
+M03 F1000 X49.57 Y24.61 S255
+M03 F1000 X49.16 Y26.42 S255
+M03 F1000 X48.62 Y28.19 S255
+M03 F1000 X47.95 Y29.92 S255
+M03 F1000 X47.15 Y31.59 S255
+M03 F1000 X46.23 Y33.20 S255
+M03 F1000 X45.19 Y34.74 S255
+M03 F1000 X44.04 Y36.20 S255
+M03 F1000 X42.79 Y37.56 S255
+M03 F1000 X41.44 Y38.83 S255
+M03 F1000 X40.00 Y40.00 S255
+M03 F1000 X40.00 Y40.00 S255
+M03 F1000 X0.00 Y40.00 S255
+M03 F1000 X0.00 Y0.00 S255
+M05 F2000 X10.00 Y10.00
+M03 F1000 X20.00 Y10.00 S255
+M03 F1000 X20.00 Y20.00 S255
+M03 F1000 X10.00 Y20.00 S255
+M03 F1000 X10.00 Y10.00 S255
+M05 F2000 X50.00 Y0.00
+M03 F1000 X80.00 Y0.00 S255
+M03 F1000 X81.25 Y0.08 S255
+M03 F1000 X82.49 Y0.31 S255
+M03 F1000 X83.68 Y0.70 S255
+M03 F1000 X84.82 Y1.24 S255
+M03 F1000 X85.88 Y1.91 S255
+M03 F1000 X86.85 Y2.71 S255
+M03 F1000 X87.71 Y3.63 S255
+M03 F1000 X88.44 Y4.64 S255
+M03 F1000 X89.05 Y5.74 S255
+M03 F1000 X89.51 Y6.91 S255
+M03 F1000 X89.82 Y8.13 S255
+M03 F1000 X89.98 Y9.37 S255
+M03 F1000 X89.98 Y10.63 S255
+M03 F1000 X89.82 Y11.87 S255
+M03 F1000 X89.51 Y13.09 S255
+M03 F1000 X89.05 Y14.26 S255
+M03 F1000 X88.44 Y15.36 S255
+M03 F1000 X87.71 Y16.37 S255
+M03 F1000 X86.85 Y17.29 S255
+M03 F1000 X85.88 Y18.09 S255
+M03 F1000 X84.82 Y18.76 S255
+M03 F1000 X83.68 Y19.30 S255
+M03 F1000 X82.49 Y19.69 S255
+M03 F1000 X81.25 Y19.92 S255
+M03 F1000 X80.00 Y20.00 S255
+M03 F1000 X60.00 Y20.00 S255
+M03 F1000 X59.72 Y20.91 S255
+M03 F1000 X59.43 Y21.76 S255
+M03 F1000 X59.13 Y22.55 S255
+M03 F1000 X58.83 Y23.28 S255
+M03 F1000 X58.53 Y23.96 S255
+M03 F1000 X58.22 Y24.57 S255
+M03 F1000 X57.91 Y25.13 S255
+M03 F1000 X57.59 Y25.62 S255
+M03 F1000 X57.28 Y26.06 S255
+M03 F1000 X56.96 Y26.45 S255
+M03 F1000 X56.63 Y26.77 S255
+M03 F1000 X56.31 Y27.03 S255
+M03 F1000 X55.98 Y27.24 S255
+M03 F1000 X55.66 Y27.38 S255
+M03 F1000 X55.33 Y27.47 S255
+M03 F1000 X55.00 Y27.50 S255
+M03 F1000 X54.67 Y27.47 S255
+M03 F1000 X54.34 Y27.38 S255
+M03 F1000 X54.02 Y27.24 S255
+M03 F1000 X53.69 Y27.03 S255
+M03 F1000 X53.37 Y26.77 S255
+M03 F1000 X53.04 Y26.45 S255
+M03 F1000 X52.72 Y26.06 S255
+M03 F1000 X52.41 Y25.62 S255
+M03 F1000 X52.09 Y25.13 S255
+M03 F1000 X51.78 Y24.57 S255
+M03 F1000 X51.47 Y23.96 S255
+M03 F1000 X51.17 Y23.28 S255
+M03 F1000 X50.87 Y22.55 S255
+M03 F1000 X50.57 Y21.76 S255
+M03 F1000 X50.28 Y20.91 S255
+M03 F1000 X50.00 Y20.00 S255
+M03 F1000 X49.55 Y19.96 S255
+M03 F1000 X49.11 Y19.84 S255
+M03 F1000 X48.67 Y19.64 S255
+M03 F1000 X48.24 Y19.36 S255
+M03 F1000 X47.83 Y19.01 S255
+M03 F1000 X47.44 Y18.58 S255
+M03 F1000 X47.06 Y18.09 S255
+M03 F1000 X46.71 Y17.53 S255
+M03 F1000 X46.39 Y16.91 S255
+M03 F1000 X46.09 Y16.23 S255
+M03 F1000 X45.83 Y15.51 S255
+M03 F1000 X45.60 Y14.74 S255
+M03 F1000 X45.40 Y13.93 S255
+M03 F1000 X45.24 Y13.09 S255
+M03 F1000 X45.13 Y12.23 S255
+M03 F1000 X45.05 Y11.34 S255
+M03 F1000 X45.01 Y10.45 S255
+M03 F1000 X45.01 Y9.55 S255
+M03 F1000 X45.05 Y8.66 S255
+M03 F1000 X45.13 Y7.77 S255
+M03 F1000 X45.24 Y6.91 S255
+M03 F1000 X45.40 Y6.07 S255
+M03 F1000 X45.60 Y5.26 S255
+M03 F1000 X45.83 Y4.49 S255
+M03 F1000 X46.09 Y3.77 S255
+M03 F1000 X46.39 Y3.09 S255
+M03 F1000 X46.71 Y2.47 S255
+M03 F1000 X47.06 Y1.91 S255
+M03 F1000 X47.44 Y1.42 S255
+M03 F1000 X47.83 Y0.99 S255
+M03 F1000 X48.24 Y0.64 S255
+M03 F1000 X48.67 Y0.36 S255
+M03 F1000 X49.11 Y0.16 S255
+M03 F1000 X49.55 Y0.04 S255
+M03 F1000 X50.00 Y0.00 S255
+M03 F1000 X50.00 Y0.00 S255
+M05 F2000 X105.00 Y10.00
+M03 F1000 X104.84 Y8.76 S255
+M03 F1000 X104.38 Y7.59 S255
+M03 F1000 X103.64 Y6.58 S255
+M03 F1000 X102.68 Y5.78 S255
+M03 F1000 X101.55 Y5.24 S255
+M03 F1000 X100.31 Y5.01 S255
+M03 F1000 X99.06 Y5.09 S255
+M03 F1000 X97.87 Y5.48 S255
+M03 F1000 X96.81 Y6.15 S255
+M03 F1000 X95.95 Y7.06 S255
+M03 F1000 X95.35 Y8.16 S255
+M03 F1000 X95.04 Y9.37 S255
+M03 F1000 X95.04 Y10.63 S255
+M03 F1000 X95.35 Y11.84 S255
+M03 F1000 X95.95 Y12.94 S255
+M03 F1000 X96.81 Y13.85 S255
+M03 F1000 X97.87 Y14.52 S255
+M03 F1000 X99.06 Y14.91 S255
+M03 F1000 X100.31 Y14.99 S255
+M03 F1000 X101.55 Y14.76 S255
+M03 F1000 X102.68 Y14.22 S255
+M03 F1000 X103.64 Y13.42 S255
+M03 F1000 X104.38 Y12.41 S255
+M03 F1000 X104.84 Y11.24 S255
+M03 F1000 X105.00 Y10.00 S255
+M03 F1000 X105.00 Y10.00 S255
+M05 F2000 X0 Y0
\ No newline at end of file
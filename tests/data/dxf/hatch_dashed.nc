@@ -0,0 +1,32 @@
+G01
+M05 F2000 X0.00 Y0.00
+M03 F1000 X3.00 Y0.00 S255
+M05 F2000 X4.00 Y0.00
+M03 F1000 X7.00 Y0.00 S255
+M05 F2000 X8.00 Y0.00
+M03 F1000 X10.00 Y0.00 S255
+M05 F2000 X10.00 Y2.00
+M03 F1000 X9.00 Y2.00 S255
+M05 F2000 X8.00 Y2.00
+M03 F1000 X5.00 Y2.00 S255
+M05 F2000 X4.00 Y2.00
+M03 F1000 X1.00 Y2.00 S255
+M05 F2000 X0.00 Y4.00
+M03 F1000 X1.00 Y4.00 S255
+M05 F2000 X2.00 Y4.00
+M03 F1000 X5.00 Y4.00 S255
+M05 F2000 X6.00 Y4.00
+M03 F1000 X9.00 Y4.00 S255
+M05 F2000 X10.00 Y6.00
+M03 F1000 X7.00 Y6.00 S255
+M05 F2000 X6.00 Y6.00
+M03 F1000 X3.00 Y6.00 S255
+M05 F2000 X2.00 Y6.00
+M03 F1000 X0.00 Y6.00 S255
+M05 F2000 X0.00 Y8.00
+M03 F1000 X3.00 Y8.00 S255
+M05 F2000 X4.00 Y8.00
+M03 F1000 X7.00 Y8.00 S255
+M05 F2000 X8.00 Y8.00
+M03 F1000 X10.00 Y8.00 S255
+M05 F2000 X0 Y0
\ No newline at end of file
@@ -0,0 +1,148 @@
+G01
+M05 F2000 X0.00 Y0.50
+M03 F1000 X40.62 Y0.50 S255
+M05 F2000 X41.80 Y1.50
+M03 F1000 X0.00 Y1.50 S255
+M05 F2000 X0.00 Y2.50
+M03 F1000 X42.85 Y2.50 S255
+M05 F2000 X43.77 Y3.50
+M03 F1000 X0.00 Y3.50 S255
+M05 F2000 X0.00 Y4.50
+M03 F1000 X44.59 Y4.50 S255
+M05 F2000 X45.35 Y5.50
+M03 F1000 X0.00 Y5.50 S255
+M05 F2000 X0.00 Y6.50
+M03 F1000 X46.03 Y6.50 S255
+M05 F2000 X46.63 Y7.50
+M03 F1000 X0.00 Y7.50 S255
+M05 F2000 X0.00 Y8.50
+M03 F1000 X47.19 Y8.50 S255
+M05 F2000 X47.67 Y9.50
+M03 F1000 X0.00 Y9.50 S255
+M05 F2000 X0.00 Y10.50
+M03 F1000 X10.00 Y10.50 S255
+M05 F2000 X20.00 Y10.50
+M03 F1000 X48.11 Y10.50 S255
+M05 F2000 X48.50 Y11.50
+M03 F1000 X20.00 Y11.50 S255
+M05 F2000 X10.00 Y11.50
+M03 F1000 X0.00 Y11.50 S255
+M05 F2000 X0.00 Y12.50
+M03 F1000 X10.00 Y12.50 S255
+M05 F2000 X20.00 Y12.50
+M03 F1000 X48.83 Y12.50 S255
+M05 F2000 X49.14 Y13.50
+M03 F1000 X20.00 Y13.50 S255
+M05 F2000 X10.00 Y13.50
+M03 F1000 X0.00 Y13.50 S255
+M05 F2000 X0.00 Y14.50
+M03 F1000 X10.00 Y14.50 S255
+M05 F2000 X20.00 Y14.50
+M03 F1000 X49.37 Y14.50 S255
+M05 F2000 X49.59 Y15.50
+M03 F1000 X20.00 Y15.50 S255
+M05 F2000 X10.00 Y15.50
+M03 F1000 X0.00 Y15.50 S255
+M05 F2000 X0.00 Y16.50
+M03 F1000 X10.00 Y16.50 S255
+M05 F2000 X20.00 Y16.50
+M03 F1000 X49.74 Y16.50 S255
+M05 F2000 X49.87 Y17.50
+M03 F1000 X20.00 Y17.50 S255
+M05 F2000 X10.00 Y17.50
+M03 F1000 X0.00 Y17.50 S255
+M05 F2000 X0.00 Y18.50
+M03 F1000 X10.00 Y18.50 S255
+M05 F2000 X20.00 Y18.50
+M03 F1000 X49.94 Y18.50 S255
+M05 F2000 X49.98 Y19.50
+M03 F1000 X20.00 Y19.50 S255
+M05 F2000 X10.00 Y19.50
+M03 F1000 X0.00 Y19.50 S255
+M05 F2000 X0.00 Y20.50
+M03 F1000 X49.98 Y20.50 S255
+M05 F2000 X49.94 Y21.50
+M03 F1000 X0.00 Y21.50 S255
+M05 F2000 X0.00 Y22.50
+M03 F1000 X49.87 Y22.50 S255
+M05 F2000 X49.74 Y23.50
+M03 F1000 X0.00 Y23.50 S255
+M05 F2000 X0.00 Y24.50
+M03 F1000 X49.59 Y24.50 S255
+M05 F2000 X49.37 Y25.50
+M03 F1000 X0.00 Y25.50 S255
+M05 F2000 X0.00 Y26.50
+M03 F1000 X49.14 Y26.50 S255
+M05 F2000 X48.83 Y27.50
+M03 F1000 X0.00 Y27.50 S255
+M05 F2000 X0.00 Y28.50
+M03 F1000 X48.50 Y28.50 S255
+M05 F2000 X48.11 Y29.50
+M03 F1000 X0.00 Y29.50 S255
+M05 F2000 X0.00 Y30.50
+M03 F1000 X47.67 Y30.50 S255
+M05 F2000 X47.19 Y31.50
+M03 F1000 X0.00 Y31.50 S255
+M05 F2000 X0.00 Y32.50
+M03 F1000 X46.63 Y32.50 S255
+M05 F2000 X46.03 Y33.50
+M03 F1000 X0.00 Y33.50 S255
+M05 F2000 X0.00 Y34.50
+M03 F1000 X45.35 Y34.50 S255
+M05 F2000 X44.59 Y35.50
+M03 F1000 X0.00 Y35.50 S255
+M05 F2000 X0.00 Y36.50
+M03 F1000 X43.77 Y36.50 S255
+M05 F2000 X42.85 Y37.50
+M03 F1000 X0.00 Y37.50 S255
+M05 F2000 X0.00 Y38.50
+M03 F1000 X41.80 Y38.50 S255
+M05 F2000 X40.62 Y39.50
+M03 F1000 X0.00 Y39.50 S255
+M05 F2000 X80.88 Y0.06
+M03 F1000 X89.95 Y9.13 S255
+M05 F2000 X89.49 Y13.15
+M03 F1000 X76.33 Y0.00 S255
+M05 F2000 X71.84 Y0.00
+M03 F1000 X87.92 Y16.08 S255
+M05 F2000 X85.61 Y18.26
+M03 F1000 X67.35 Y0.00 S255
+M05 F2000 X62.86 Y0.00
+M03 F1000 X82.53 Y19.67 S255
+M05 F2000 X78.37 Y20.00
+M03 F1000 X58.37 Y0.00 S255
+M05 F2000 X53.88 Y0.00
+M03 F1000 X73.88 Y20.00 S255
+M05 F2000 X69.39 Y20.00
+M03 F1000 X49.46 Y0.07 S255
+M05 F2000 X46.96 Y2.06
+M03 F1000 X64.90 Y20.00 S255
+M05 F2000 X60.41 Y20.00
+M03 F1000 X45.61 Y5.20 S255
+M05 F2000 X45.03 Y9.10
+M03 F1000 X58.94 Y23.02 S255
+M05 F2000 X57.37 Y25.94
+M03 F1000 X45.42 Y13.98 S255
+M05 F2000 X52.13 Y25.19
+M03 F1000 X54.31 Y27.37 S255
+M05 F2000 X97.83 Y5.50
+M03 F1000 X102.09 Y5.50 S255
+M05 F2000 X103.55 Y6.50
+M03 F1000 X96.48 Y6.50 S255
+M05 F2000 X95.71 Y7.50
+M03 F1000 X104.32 Y7.50 S255
+M05 F2000 X104.74 Y8.50
+M03 F1000 X95.26 Y8.50 S255
+M05 F2000 X95.04 Y9.50
+M03 F1000 X104.94 Y9.50 S255
+M05 F2000 X104.94 Y10.50
+M03 F1000 X95.04 Y10.50 S255
+M05 F2000 X95.26 Y11.50
+M03 F1000 X104.74 Y11.50 S255
+M05 F2000 X104.32 Y12.50
+M03 F1000 X95.71 Y12.50 S255
+M05 F2000 X96.48 Y13.50
+M03 F1000 X103.55 Y13.50 S255
+M05 F2000 X102.09 Y14.50
+M03 F1000 X97.83 Y14.50 S255
+M05 F2000 X0 Y0
\ No newline at end of file
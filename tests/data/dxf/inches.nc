@@ -0,0 +1,13 @@
+G01
+M05 F2000 X25.40 Y19.05
+M03 S255
+G04 P0.100
+M05
+M05 F2000 X0.00 Y0.00
+M03 F1000 X50.80 Y0.00 S255
+M03 F1000 X50.80 Y25.40 S255
+M03 F1000 X0.00 Y25.40 S255
+M03 F1000 X0.00 Y0.00 S255
+M05 F2000 X6.35 Y12.70
+M03 F1000 X44.45 Y12.70 S255
+M05 F2000 X0 Y0
\ No newline at end of file
@@ -0,0 +1,14 @@
+G01
+G20
+M05 F78.74 X1.0000 Y0.7500
+M03 S255
+G04 P0.100
+M05
+M05 F78.74 X0.0000 Y0.0000
+M03 F39.37 X2.0000 Y0.0000 S255
+M03 F39.37 X2.0000 Y1.0000 S255
+M03 F39.37 X0.0000 Y1.0000 S255
+M03 F39.37 X0.0000 Y0.0000 S255
+M05 F78.74 X0.2500 Y0.5000
+M03 F39.37 X1.7500 Y0.5000 S255
+M05 F78.74 X0 Y0
\ No newline at end of file
@@ -0,0 +1,13 @@
+G01
+M05 F2000 X1.00 Y0.75
+M03 S255
+G04 P0.100
+M05
+M05 F2000 X0.00 Y0.00
+M03 F1000 X2.00 Y0.00 S255
+M03 F1000 X2.00 Y1.00 S255
+M03 F1000 X0.00 Y1.00 S255
+M03 F1000 X0.00 Y0.00 S255
+M05 F2000 X0.25 Y0.50
+M03 F1000 X1.75 Y0.50 S255
+M05 F2000 X0 Y0
\ No newline at end of file
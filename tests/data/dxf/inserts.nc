@@ -0,0 +1,35 @@
+G01
+M05 F2000 X50.00 Y0.00
+M03 F1000 X50.00 Y20.00 S255
+M03 F1000 X47.50 Y20.00 S255
+M03 F1000 X47.50 Y0.00 S255
+M03 F1000 X50.00 Y0.00 S255
+M05 F2000 X0.00 Y40.00
+M03 F1000 X10.00 Y40.00 S255
+M03 F1000 X10.00 Y45.00 S255
+M03 F1000 X0.00 Y45.00 S255
+M03 F1000 X0.00 Y40.00 S255
+M05 F2000 X0.00 Y40.00
+M03 F1000 X0.00 Y60.00 S255
+M05 F2000 X30.00 Y40.00
+M03 F1000 X40.00 Y40.00 S255
+M03 F1000 X40.00 Y45.00 S255
+M03 F1000 X30.00 Y45.00 S255
+M03 F1000 X30.00 Y40.00 S255
+M05 F2000 X30.00 Y40.00
+M03 F1000 X30.00 Y60.00 S255
+M05 F2000 X0.00 Y65.00
+M03 F1000 X10.00 Y65.00 S255
+M03 F1000 X10.00 Y70.00 S255
+M03 F1000 X0.00 Y70.00 S255
+M03 F1000 X0.00 Y65.00 S255
+M05 F2000 X0.00 Y65.00
+M03 F1000 X0.00 Y85.00 S255
+M05 F2000 X30.00 Y65.00
+M03 F1000 X40.00 Y65.00 S255
+M03 F1000 X40.00 Y70.00 S255
+M03 F1000 X30.00 Y70.00 S255
+M03 F1000 X30.00 Y65.00 S255
+M05 F2000 X30.00 Y65.00
+M03 F1000 X30.00 Y85.00 S255
+M05 F2000 X0 Y0
\ No newline at end of file
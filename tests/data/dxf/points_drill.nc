@@ -0,0 +1,9 @@
+G01
+G00 Z2.00
+G83 X5.00 Y5.00 Z-3.00 R2.00 Q1.25 F100
+G83 X45.00 Y5.00 Z-3.00 R2.00 Q1.25 F100
+G83 X45.00 Y25.00 Z-3.00 R2.00 Q1.25 F100
+G83 X5.00 Y25.00 Z-3.00 R2.00 Q1.25 F100
+G80
+G01
+M05 F2000 X0 Y0
\ No newline at end of file
@@ -0,0 +1,12 @@
+G01
+G20
+G00 Z0.0787
+M03 S12000
+G83 X0.1969 Y0.1969 Z-0.0787 R0.0787 Q0.0197 F3.94
+G83 X1.7717 Y0.1969 Z-0.0787 R0.0787 Q0.0197 F3.94
+G83 X1.7717 Y0.9843 Z-0.0787 R0.0787 Q0.0197 F3.94
+G83 X0.1969 Y0.9843 Z-0.0787 R0.0787 Q0.0197 F3.94
+G80
+M05
+G01
+M05 F78.74 X0 Y0
\ No newline at end of file
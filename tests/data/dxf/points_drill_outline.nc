@@ -0,0 +1,14 @@
+G01
+G00 Z2.00
+G81 X5.00 Y5.00 Z-3.00 R2.00 F100
+G81 X45.00 Y5.00 Z-3.00 R2.00 F100
+G81 X45.00 Y25.00 Z-3.00 R2.00 F100
+G81 X5.00 Y25.00 Z-3.00 R2.00 F100
+G80
+G01
+M05 F2000 X0.00 Y0.00
+M03 F1000 X50.00 Y0.00 S255
+M03 F1000 X50.00 Y30.00 S255
+M03 F1000 X0.00 Y30.00 S255
+M03 F1000 X0.00 Y0.00 S255
+M05 F2000 X0 Y0
\ No newline at end of file
@@ -0,0 +1,32 @@
+G01
+G00 Z1.00
+G00 X5.00 Y5.00
+G01 Z-1.25 F100
+G00 Z1.00
+G01 Z-2.50 F100
+G00 Z1.00
+G01 Z-3.00 F100
+G00 Z1.00
+G00 X45.00 Y5.00
+G01 Z-1.25 F100
+G00 Z1.00
+G01 Z-2.50 F100
+G00 Z1.00
+G01 Z-3.00 F100
+G00 Z1.00
+G00 X45.00 Y25.00
+G01 Z-1.25 F100
+G00 Z1.00
+G01 Z-2.50 F100
+G00 Z1.00
+G01 Z-3.00 F100
+G00 Z1.00
+G00 X5.00 Y25.00
+G01 Z-1.25 F100
+G00 Z1.00
+G01 Z-2.50 F100
+G00 Z1.00
+G01 Z-3.00 F100
+G00 Z1.00
+G01
+M05 F2000 X0 Y0
\ No newline at end of file
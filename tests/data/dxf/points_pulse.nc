@@ -0,0 +1,23 @@
+G01
+M05 F2000 X5.00 Y5.00
+M03 S255
+G04 P0.100
+M05
+M05 F2000 X45.00 Y5.00
+M03 S255
+G04 P0.100
+M05
+M05 F2000 X45.00 Y25.00
+M03 S255
+G04 P0.100
+M05
+M05 F2000 X5.00 Y25.00
+M03 S255
+G04 P0.100
+M05
+M05 F2000 X0.00 Y0.00
+M03 F1000 X50.00 Y0.00 S255
+M03 F1000 X50.00 Y30.00 S255
+M03 F1000 X0.00 Y30.00 S255
+M03 F1000 X0.00 Y0.00 S255
+M05 F2000 X0 Y0
\ No newline at end of file
@@ -0,0 +1,79 @@
+G01
+M05 F2000 X0.00 Y0.00
+M03 F1000 X40.00 Y0.00 S255
+M05 F2000 X10.00 Y10.00
+M03 F1000 X30.00 Y10.00 S255
+M03 F1000 X30.00 Y10.00 S255
+M03 F1000 X30.68 Y10.05 S255
+M03 F1000 X31.35 Y10.19 S255
+M03 F1000 X31.99 Y10.41 S255
+M03 F1000 X32.60 Y10.73 S255
+M03 F1000 X33.16 Y11.12 S255
+M03 F1000 X33.65 Y11.59 S255
+M03 F1000 X34.08 Y12.12 S255
+M03 F1000 X34.44 Y12.70 S255
+M03 F1000 X34.71 Y13.33 S255
+M03 F1000 X34.90 Y13.98 S255
+M03 F1000 X34.99 Y14.66 S255
+M03 F1000 X34.99 Y15.34 S255
+M03 F1000 X34.90 Y16.02 S255
+M03 F1000 X34.71 Y16.67 S255
+M03 F1000 X34.44 Y17.30 S255
+M03 F1000 X34.08 Y17.88 S255
+M03 F1000 X33.65 Y18.41 S255
+M03 F1000 X33.16 Y18.88 S255
+M03 F1000 X32.60 Y19.27 S255
+M03 F1000 X31.99 Y19.59 S255
+M03 F1000 X31.35 Y19.81 S255
+M03 F1000 X30.68 Y19.95 S255
+M03 F1000 X30.00 Y20.00 S255
+M03 F1000 X30.00 Y20.00 S255
+M03 F1000 X10.00 Y20.00 S255
+M03 F1000 X10.00 Y10.00 S255
+M05 F2000 X25.00 Y30.00
+M03 F1000 X24.95 Y30.68 S255
+M03 F1000 X24.81 Y31.35 S255
+M03 F1000 X24.59 Y31.99 S255
+M03 F1000 X24.27 Y32.60 S255
+M03 F1000 X23.88 Y33.16 S255
+M03 F1000 X23.41 Y33.65 S255
+M03 F1000 X22.88 Y34.08 S255
+M03 F1000 X22.30 Y34.44 S255
+M03 F1000 X21.67 Y34.71 S255
+M03 F1000 X21.02 Y34.90 S255
+M03 F1000 X20.34 Y34.99 S255
+M03 F1000 X19.66 Y34.99 S255
+M03 F1000 X18.98 Y34.90 S255
+M03 F1000 X18.33 Y34.71 S255
+M03 F1000 X17.70 Y34.44 S255
+M03 F1000 X17.12 Y34.08 S255
+M03 F1000 X16.59 Y33.65 S255
+M03 F1000 X16.12 Y33.16 S255
+M03 F1000 X15.73 Y32.60 S255
+M03 F1000 X15.41 Y31.99 S255
+M03 F1000 X15.19 Y31.35 S255
+M03 F1000 X15.05 Y30.68 S255
+M03 F1000 X15.00 Y30.00 S255
+M05 F2000 X53.00 Y50.00
+M03 F1000 X52.84 Y50.98 S255
+M03 F1000 X52.36 Y51.85 S255
+M03 F1000 X51.63 Y52.52 S255
+M03 F1000 X50.73 Y52.91 S255
+M03 F1000 X49.74 Y52.99 S255
+M03 F1000 X48.78 Y52.74 S255
+M03 F1000 X47.95 Y52.19 S255
+M03 F1000 X47.35 Y51.41 S255
+M03 F1000 X47.04 Y50.47 S255
+M03 F1000 X47.05 Y49.48 S255
+M03 F1000 X47.38 Y48.55 S255
+M03 F1000 X47.99 Y47.77 S255
+M03 F1000 X48.83 Y47.24 S255
+M03 F1000 X49.79 Y47.01 S255
+M03 F1000 X50.78 Y47.10 S255
+M03 F1000 X51.68 Y47.51 S255
+M03 F1000 X52.40 Y48.19 S255
+M03 F1000 X52.85 Y49.07 S255
+M03 F1000 X53.00 Y50.00 S255
+M05 F2000 X47.00 Y50.00
+M03 F1000 X53.00 Y50.00 S255
+M05 F2000 X0 Y0
\ No newline at end of file
@@ -0,0 +1,119 @@
+G01
+M05 F2000 X0.00 Y0.00
+M03 F1000 X30.00 Y10.00 S255
+M05 F2000 X21.00 Y25.00
+M03 F1000 X20.93 Y25.94 S255
+M03 F1000 X20.71 Y26.85 S255
+M03 F1000 X20.35 Y27.72 S255
+M03 F1000 X19.85 Y28.53 S255
+M03 F1000 X19.24 Y29.24 S255
+M03 F1000 X18.53 Y29.85 S255
+M03 F1000 X17.72 Y30.35 S255
+M03 F1000 X16.85 Y30.71 S255
+M03 F1000 X15.94 Y30.93 S255
+M03 F1000 X15.00 Y31.00 S255
+M03 F1000 X14.06 Y30.93 S255
+M03 F1000 X13.15 Y30.71 S255
+M03 F1000 X12.28 Y30.35 S255
+M03 F1000 X11.47 Y29.85 S255
+M03 F1000 X10.76 Y29.24 S255
+M03 F1000 X10.15 Y28.53 S255
+M03 F1000 X9.65 Y27.72 S255
+M03 F1000 X9.29 Y26.85 S255
+M03 F1000 X9.07 Y25.94 S255
+M03 F1000 X9.00 Y25.00 S255
+M03 F1000 X9.07 Y24.06 S255
+M03 F1000 X9.29 Y23.15 S255
+M03 F1000 X9.65 Y22.28 S255
+M03 F1000 X10.15 Y21.47 S255
+M03 F1000 X10.76 Y20.76 S255
+M03 F1000 X11.47 Y20.15 S255
+M03 F1000 X12.28 Y19.65 S255
+M03 F1000 X13.15 Y19.29 S255
+M03 F1000 X14.06 Y19.07 S255
+M03 F1000 X15.00 Y19.00 S255
+M03 F1000 X15.94 Y19.07 S255
+M03 F1000 X16.85 Y19.29 S255
+M03 F1000 X17.72 Y19.65 S255
+M03 F1000 X18.53 Y20.15 S255
+M03 F1000 X19.24 Y20.76 S255
+M03 F1000 X19.85 Y21.47 S255
+M03 F1000 X20.35 Y22.28 S255
+M03 F1000 X20.71 Y23.15 S255
+M03 F1000 X20.93 Y24.06 S255
+M03 F1000 X21.00 Y25.00 S255
+M05 F2000 X44.00 Y25.00
+M03 F1000 X43.94 Y25.69 S255
+M03 F1000 X43.76 Y26.37 S255
+M03 F1000 X43.46 Y27.00 S255
+M03 F1000 X43.06 Y27.57 S255
+M03 F1000 X42.57 Y28.06 S255
+M03 F1000 X42.00 Y28.46 S255
+M03 F1000 X41.37 Y28.76 S255
+M03 F1000 X40.69 Y28.94 S255
+M03 F1000 X40.00 Y29.00 S255
+M03 F1000 X39.31 Y28.94 S255
+M03 F1000 X38.63 Y28.76 S255
+M03 F1000 X38.00 Y28.46 S255
+M03 F1000 X37.43 Y28.06 S255
+M03 F1000 X36.94 Y27.57 S255
+M03 F1000 X36.54 Y27.00 S255
+M03 F1000 X36.24 Y26.37 S255
+M03 F1000 X36.06 Y25.69 S255
+M03 F1000 X36.00 Y25.00 S255
+M05 F2000 X50.00 Y0.00
+M03 F1000 X50.00 Y0.00 S255
+M03 F1000 X50.58 Y-0.72 S255
+M03 F1000 X51.22 Y-1.40 S255
+M03 F1000 X51.90 Y-2.02 S255
+M03 F1000 X52.63 Y-2.60 S255
+M03 F1000 X53.40 Y-3.11 S255
+M03 F1000 X54.20 Y-3.57 S255
+M03 F1000 X55.04 Y-3.97 S255
+M03 F1000 X55.90 Y-4.31 S255
+M03 F1000 X56.79 Y-4.58 S255
+M03 F1000 X57.70 Y-4.79 S255
+M03 F1000 X58.61 Y-4.92 S255
+M03 F1000 X59.54 Y-4.99 S255
+M03 F1000 X60.46 Y-4.99 S255
+M03 F1000 X61.39 Y-4.92 S255
+M03 F1000 X62.30 Y-4.79 S255
+M03 F1000 X63.21 Y-4.58 S255
+M03 F1000 X64.10 Y-4.31 S255
+M03 F1000 X64.96 Y-3.97 S255
+M03 F1000 X65.80 Y-3.57 S255
+M03 F1000 X66.60 Y-3.11 S255
+M03 F1000 X67.37 Y-2.60 S255
+M03 F1000 X68.10 Y-2.02 S255
+M03 F1000 X68.78 Y-1.40 S255
+M03 F1000 X69.42 Y-0.72 S255
+M03 F1000 X70.00 Y0.00 S255
+M03 F1000 X70.00 Y0.00 S255
+M03 F1000 X70.00 Y15.00 S255
+M03 F1000 X50.00 Y15.00 S255
+M03 F1000 X50.00 Y0.00 S255
+M05 F2000 X0.00 Y40.00
+M03 F1000 X1.25 Y42.20 S255
+M03 F1000 X2.50 Y43.82 S255
+M03 F1000 X3.75 Y44.92 S255
+M03 F1000 X5.00 Y45.56 S255
+M03 F1000 X6.25 Y45.77 S255
+M03 F1000 X7.50 Y45.62 S255
+M03 F1000 X8.75 Y45.16 S255
+M03 F1000 X10.00 Y44.44 S255
+M03 F1000 X11.25 Y43.52 S255
+M03 F1000 X12.50 Y42.43 S255
+M03 F1000 X13.75 Y41.24 S255
+M03 F1000 X15.00 Y40.00 S255
+M03 F1000 X16.25 Y38.76 S255
+M03 F1000 X17.50 Y37.57 S255
+M03 F1000 X18.75 Y36.48 S255
+M03 F1000 X20.00 Y35.56 S255
+M03 F1000 X21.25 Y34.84 S255
+M03 F1000 X22.50 Y34.38 S255
+M03 F1000 X23.75 Y34.23 S255
+M03 F1000 X25.00 Y34.44 S255
+M03 F1000 X26.25 Y35.08 S255
+M03 F1000 X27.50 Y36.18 S255
+M03 F1000 X30.00 Y40.00 S255
+M05 F2000 X0 Y0
\ No newline at end of file
@@ -0,0 +1,359 @@
+G01
+M05 F2000 X37.85 Y2.84
+M03 F1000 X40.17 Y5.17 S255
+M05 F2000 X40.79 Y6.49
+M03 F1000 X36.27 Y1.98 S255
+M05 F2000 X35.11 Y1.53
+M03 F1000 X40.99 Y7.40 S255
+M05 F2000 X41.01 Y8.13
+M03 F1000 X34.12 Y1.24 S255
+M05 F2000 X33.21 Y1.04
+M03 F1000 X40.95 Y8.78 S255
+M05 F2000 X40.84 Y9.37
+M03 F1000 X32.38 Y0.92 S255
+M05 F2000 X31.60 Y0.84
+M03 F1000 X40.66 Y9.90 S255
+M05 F2000 X40.44 Y10.39
+M03 F1000 X30.86 Y0.81 S255
+M05 F2000 X30.15 Y0.81
+M03 F1000 X40.19 Y10.84 S255
+M05 F2000 X39.90 Y11.27
+M03 F1000 X29.48 Y0.84 S255
+M05 F2000 X28.83 Y0.90
+M03 F1000 X39.59 Y11.66 S255
+M05 F2000 X39.25 Y12.03
+M03 F1000 X28.21 Y0.98 S255
+M05 F2000 X27.61 Y1.09
+M03 F1000 X38.89 Y12.38 S255
+M05 F2000 X38.51 Y12.70
+M03 F1000 X27.03 Y1.22 S255
+M05 F2000 X26.47 Y1.37
+M03 F1000 X38.11 Y13.01 S255
+M05 F2000 X37.69 Y13.29
+M03 F1000 X25.93 Y1.53 S255
+M05 F2000 X25.41 Y1.72
+M03 F1000 X37.25 Y13.56 S255
+M05 F2000 X36.79 Y13.81
+M03 F1000 X24.91 Y1.93 S255
+M05 F2000 X24.43 Y2.15
+M03 F1000 X36.31 Y14.04 S255
+M05 F2000 X35.82 Y14.25
+M03 F1000 X23.96 Y2.40 S255
+M05 F2000 X23.52 Y2.66
+M03 F1000 X35.31 Y14.45 S255
+M05 F2000 X34.77 Y14.62
+M03 F1000 X23.09 Y2.94 S255
+M05 F2000 X22.68 Y3.24
+M03 F1000 X34.22 Y14.77 S255
+M05 F2000 X33.65 Y14.91
+M03 F1000 X22.29 Y3.56 S255
+M05 F2000 X21.93 Y3.90
+M03 F1000 X33.05 Y15.02 S255
+M05 F2000 X32.44 Y15.11
+M03 F1000 X21.58 Y4.26 S255
+M05 F2000 X21.26 Y4.65
+M03 F1000 X31.80 Y15.18 S255
+M05 F2000 X31.13 Y15.22
+M03 F1000 X20.97 Y5.06 S255
+M05 F2000 X15.34 Y0.13
+M03 F1000 X15.77 Y0.56 S255
+M05 F2000 X20.71 Y5.50
+M03 F1000 X30.43 Y15.23 S255
+M05 F2000 X29.69 Y15.20
+M03 F1000 X20.47 Y5.98 S255
+M05 F2000 X15.77 Y1.27
+M03 F1000 X14.63 Y0.13 S255
+M05 F2000 X13.92 Y0.13
+M03 F1000 X15.77 Y1.98 S255
+M05 F2000 X20.28 Y6.49
+M03 F1000 X28.94 Y15.15 S255
+M05 F2000 X28.12 Y15.04
+M03 F1000 X20.14 Y7.06 S255
+M05 F2000 X15.77 Y2.69
+M03 F1000 X13.21 Y0.13 S255
+M05 F2000 X12.51 Y0.13
+M03 F1000 X15.77 Y3.39 S255
+M05 F2000 X20.07 Y7.69
+M03 F1000 X27.24 Y14.87 S255
+M05 F2000 X26.28 Y14.61
+M03 F1000 X20.06 Y8.40 S255
+M05 F2000 X15.77 Y4.10
+M03 F1000 X11.80 Y0.13 S255
+M05 F2000 X11.09 Y0.13
+M03 F1000 X15.77 Y4.81 S255
+M05 F2000 X20.20 Y9.24
+M03 F1000 X25.19 Y14.23 S255
+M05 F2000 X23.80 Y13.55
+M03 F1000 X20.61 Y10.36 S255
+M05 F2000 X15.77 Y5.51
+M03 F1000 X10.39 Y0.13 S255
+M05 F2000 X9.68 Y0.13
+M03 F1000 X15.77 Y6.22 S255
+M05 F2000 X15.77 Y6.93
+M03 F1000 X8.97 Y0.13 S255
+M05 F2000 X8.26 Y0.13
+M03 F1000 X15.77 Y7.64 S255
+M05 F2000 X15.77 Y8.34
+M03 F1000 X7.56 Y0.13 S255
+M05 F2000 X6.85 Y0.13
+M03 F1000 X15.77 Y9.05 S255
+M05 F2000 X15.77 Y9.76
+M03 F1000 X6.14 Y0.13 S255
+M05 F2000 X5.44 Y0.13
+M03 F1000 X15.77 Y10.46 S255
+M05 F2000 X15.77 Y11.17
+M03 F1000 X4.73 Y0.13 S255
+M05 F2000 X4.02 Y0.13
+M03 F1000 X8.99 Y5.10 S255
+M05 F2000 X10.10 Y6.21
+M03 F1000 X15.77 Y11.88 S255
+M05 F2000 X15.77 Y12.59
+M03 F1000 X10.46 Y7.28 S255
+M05 F2000 X7.94 Y4.76
+M03 F1000 X3.31 Y0.13 S255
+M05 F2000 X2.61 Y0.13
+M03 F1000 X7.23 Y4.75 S255
+M05 F2000 X10.44 Y7.97
+M03 F1000 X15.77 Y13.29 S255
+M05 F2000 X15.77 Y14.00
+M03 F1000 X10.34 Y8.57 S255
+M05 F2000 X6.66 Y4.89
+M03 F1000 X1.90 Y0.13 S255
+M05 F2000 X1.19 Y0.13
+M03 F1000 X6.15 Y5.09 S255
+M05 F2000 X10.12 Y9.06
+M03 F1000 X15.77 Y14.71 S255
+M05 F2000 X15.77 Y15.41
+M03 F1000 X9.84 Y9.48 S255
+M05 F2000 X5.72 Y5.37
+M03 F1000 X0.49 Y0.13 S255
+M05 F2000 X0.13 Y0.49
+M03 F1000 X5.37 Y5.72 S255
+M05 F2000 X9.49 Y9.85
+M03 F1000 X15.15 Y15.50 S255
+M05 F2000 X14.44 Y15.50
+M03 F1000 X9.09 Y10.15 S255
+M05 F2000 X5.07 Y6.13
+M03 F1000 X0.13 Y1.19 S255
+M05 F2000 X0.13 Y1.90
+M03 F1000 X4.83 Y6.60 S255
+M05 F2000 X8.59 Y10.36
+M03 F1000 X13.73 Y15.50 S255
+M05 F2000 X13.03 Y15.50
+M03 F1000 X8.06 Y10.53 S255
+M05 F2000 X4.70 Y7.18
+M03 F1000 X0.13 Y2.61 S255
+M05 F2000 X0.13 Y3.31
+M03 F1000 X4.64 Y7.83 S255
+M05 F2000 X7.39 Y10.58
+M03 F1000 X12.32 Y15.50 S255
+M05 F2000 X11.61 Y15.50
+M03 F1000 X6.49 Y10.38 S255
+M05 F2000 X4.85 Y8.73
+M03 F1000 X0.13 Y4.02 S255
+M05 F2000 X0.13 Y4.73
+M03 F1000 X10.90 Y15.50 S255
+M05 F2000 X10.20 Y15.50
+M03 F1000 X0.13 Y5.44 S255
+M05 F2000 X0.13 Y6.14
+M03 F1000 X9.49 Y15.50 S255
+M05 F2000 X8.78 Y15.50
+M03 F1000 X0.13 Y6.85 S255
+M05 F2000 X0.13 Y7.56
+M03 F1000 X8.08 Y15.50 S255
+M05 F2000 X7.37 Y15.50
+M03 F1000 X0.13 Y8.26 S255
+M05 F2000 X0.13 Y8.97
+M03 F1000 X6.66 Y15.50 S255
+M05 F2000 X5.95 Y15.50
+M03 F1000 X0.13 Y9.68 S255
+M05 F2000 X0.13 Y10.39
+M03 F1000 X5.25 Y15.50 S255
+M05 F2000 X4.54 Y15.50
+M03 F1000 X0.13 Y11.09 S255
+M05 F2000 X0.13 Y11.80
+M03 F1000 X3.83 Y15.50 S255
+M05 F2000 X3.13 Y15.50
+M03 F1000 X0.13 Y12.51 S255
+M05 F2000 X0.13 Y13.21
+M03 F1000 X2.42 Y15.50 S255
+M05 F2000 X1.71 Y15.50
+M03 F1000 X0.13 Y13.92 S255
+M05 F2000 X0.13 Y14.63
+M03 F1000 X1.00 Y15.50 S255
+M05 F2000 X0.30 Y15.50
+M03 F1000 X0.13 Y15.34 S255
+M05 F2000 X41.03 Y8.02
+M03 F1000 X41.02 Y7.72 S255
+M03 F1000 X40.99 Y7.42 S255
+M03 F1000 X40.94 Y7.12 S255
+M03 F1000 X40.88 Y6.82 S255
+M03 F1000 X40.80 Y6.53 S255
+M03 F1000 X40.70 Y6.24 S255
+M03 F1000 X40.59 Y5.95 S255
+M03 F1000 X40.46 Y5.67 S255
+M03 F1000 X40.31 Y5.39 S255
+M03 F1000 X40.14 Y5.12 S255
+M03 F1000 X39.96 Y4.85 S255
+M03 F1000 X39.76 Y4.59 S255
+M03 F1000 X39.55 Y4.33 S255
+M03 F1000 X39.32 Y4.08 S255
+M03 F1000 X39.08 Y3.83 S255
+M03 F1000 X38.82 Y3.59 S255
+M03 F1000 X38.55 Y3.36 S255
+M03 F1000 X37.95 Y2.92 S255
+M03 F1000 X37.70 Y2.75 S255
+M03 F1000 X37.45 Y2.59 S255
+M03 F1000 X37.18 Y2.44 S255
+M03 F1000 X36.91 Y2.29 S255
+M03 F1000 X36.64 Y2.15 S255
+M03 F1000 X36.36 Y2.01 S255
+M03 F1000 X36.07 Y1.89 S255
+M03 F1000 X35.77 Y1.77 S255
+M03 F1000 X35.48 Y1.65 S255
+M03 F1000 X35.17 Y1.54 S255
+M03 F1000 X34.86 Y1.44 S255
+M03 F1000 X34.55 Y1.35 S255
+M03 F1000 X34.23 Y1.26 S255
+M03 F1000 X33.91 Y1.19 S255
+M03 F1000 X33.59 Y1.11 S255
+M03 F1000 X33.26 Y1.05 S255
+M03 F1000 X32.93 Y0.99 S255
+M03 F1000 X32.59 Y0.94 S255
+M03 F1000 X32.25 Y0.90 S255
+M03 F1000 X31.91 Y0.86 S255
+M03 F1000 X31.57 Y0.84 S255
+M03 F1000 X31.23 Y0.82 S255
+M03 F1000 X30.54 Y0.80 S255
+M03 F1000 X30.19 Y0.81 S255
+M03 F1000 X29.84 Y0.82 S255
+M03 F1000 X29.50 Y0.84 S255
+M03 F1000 X29.16 Y0.86 S255
+M03 F1000 X28.82 Y0.90 S255
+M03 F1000 X28.48 Y0.94 S255
+M03 F1000 X28.14 Y0.99 S255
+M03 F1000 X27.81 Y1.05 S255
+M03 F1000 X27.48 Y1.11 S255
+M03 F1000 X27.16 Y1.19 S255
+M03 F1000 X26.84 Y1.26 S255
+M03 F1000 X26.52 Y1.35 S255
+M03 F1000 X26.21 Y1.44 S255
+M03 F1000 X25.90 Y1.54 S255
+M03 F1000 X25.60 Y1.65 S255
+M03 F1000 X25.30 Y1.77 S255
+M03 F1000 X25.00 Y1.89 S255
+M03 F1000 X24.72 Y2.01 S255
+M03 F1000 X24.43 Y2.15 S255
+M03 F1000 X24.16 Y2.29 S255
+M03 F1000 X23.89 Y2.44 S255
+M03 F1000 X23.62 Y2.59 S255
+M03 F1000 X23.12 Y2.92 S255
+M03 F1000 X22.81 Y3.13 S255
+M03 F1000 X22.53 Y3.36 S255
+M03 F1000 X22.25 Y3.59 S255
+M03 F1000 X21.99 Y3.83 S255
+M03 F1000 X21.75 Y4.08 S255
+M03 F1000 X21.52 Y4.33 S255
+M03 F1000 X21.31 Y4.59 S255
+M03 F1000 X21.11 Y4.85 S255
+M03 F1000 X20.93 Y5.12 S255
+M03 F1000 X20.76 Y5.39 S255
+M03 F1000 X20.61 Y5.67 S255
+M03 F1000 X20.48 Y5.95 S255
+M03 F1000 X20.37 Y6.24 S255
+M03 F1000 X20.27 Y6.53 S255
+M03 F1000 X20.19 Y6.82 S255
+M03 F1000 X20.13 Y7.12 S255
+M03 F1000 X20.08 Y7.42 S255
+M03 F1000 X20.05 Y8.02 S255
+M03 F1000 X20.07 Y8.51 S255
+M03 F1000 X20.14 Y9.00 S255
+M03 F1000 X20.26 Y9.47 S255
+M03 F1000 X20.42 Y9.94 S255
+M03 F1000 X20.62 Y10.39 S255
+M03 F1000 X20.87 Y10.83 S255
+M03 F1000 X21.15 Y11.25 S255
+M03 F1000 X21.48 Y11.66 S255
+M03 F1000 X21.84 Y12.05 S255
+M03 F1000 X22.23 Y12.43 S255
+M03 F1000 X22.66 Y12.78 S255
+M03 F1000 X23.12 Y13.12 S255
+M03 F1000 X23.61 Y13.44 S255
+M03 F1000 X24.13 Y13.73 S255
+M03 F1000 X24.67 Y14.00 S255
+M03 F1000 X25.24 Y14.25 S255
+M03 F1000 X25.84 Y14.47 S255
+M03 F1000 X26.45 Y14.67 S255
+M03 F1000 X27.09 Y14.84 S255
+M03 F1000 X27.75 Y14.98 S255
+M03 F1000 X28.42 Y15.09 S255
+M03 F1000 X29.11 Y15.17 S255
+M03 F1000 X30.54 Y15.23 S255
+M03 F1000 X31.25 Y15.22 S255
+M03 F1000 X31.96 Y15.17 S255
+M03 F1000 X32.65 Y15.09 S255
+M03 F1000 X33.32 Y14.98 S255
+M03 F1000 X33.98 Y14.84 S255
+M03 F1000 X34.62 Y14.67 S255
+M03 F1000 X35.24 Y14.47 S255
+M03 F1000 X35.83 Y14.25 S255
+M03 F1000 X36.40 Y14.00 S255
+M03 F1000 X36.95 Y13.73 S255
+M03 F1000 X37.46 Y13.44 S255
+M03 F1000 X37.95 Y13.12 S255
+M03 F1000 X38.41 Y12.78 S255
+M03 F1000 X38.84 Y12.43 S255
+M03 F1000 X39.23 Y12.05 S255
+M03 F1000 X39.59 Y11.66 S255
+M03 F1000 X39.92 Y11.25 S255
+M03 F1000 X40.20 Y10.83 S255
+M03 F1000 X40.45 Y10.39 S255
+M03 F1000 X40.65 Y9.94 S255
+M03 F1000 X40.81 Y9.47 S255
+M03 F1000 X40.93 Y9.00 S255
+M03 F1000 X41.03 Y8.02 S255
+M05 F2000 X0.13 Y15.50
+M03 F1000 X15.77 Y15.50 S255
+M03 F1000 X15.77 Y0.13 S255
+M03 F1000 X0.13 Y0.13 S255
+M03 F1000 X0.13 Y15.50 S255
+M05 F2000 X10.49 Y7.65
+M03 F1000 X10.46 Y7.21 S255
+M03 F1000 X10.36 Y6.78 S255
+M03 F1000 X10.20 Y6.38 S255
+M03 F1000 X9.98 Y6.01 S255
+M03 F1000 X9.71 Y5.67 S255
+M03 F1000 X9.02 Y5.12 S255
+M03 F1000 X8.62 Y4.92 S255
+M03 F1000 X8.21 Y4.80 S255
+M03 F1000 X7.78 Y4.73 S255
+M03 F1000 X7.34 Y4.73 S255
+M03 F1000 X6.91 Y4.80 S255
+M03 F1000 X6.09 Y5.12 S255
+M03 F1000 X5.73 Y5.37 S255
+M03 F1000 X5.41 Y5.67 S255
+M03 F1000 X5.14 Y6.01 S255
+M03 F1000 X4.92 Y6.38 S255
+M03 F1000 X4.76 Y6.78 S255
+M03 F1000 X4.63 Y7.65 S255
+M03 F1000 X4.66 Y8.10 S255
+M03 F1000 X4.76 Y8.52 S255
+M03 F1000 X4.92 Y8.93 S255
+M03 F1000 X5.14 Y9.30 S255
+M03 F1000 X5.41 Y9.64 S255
+M03 F1000 X6.09 Y10.19 S255
+M03 F1000 X6.49 Y10.38 S255
+M03 F1000 X6.91 Y10.51 S255
+M03 F1000 X7.34 Y10.58 S255
+M03 F1000 X7.78 Y10.58 S255
+M03 F1000 X8.21 Y10.51 S255
+M03 F1000 X9.02 Y10.19 S255
+M03 F1000 X9.39 Y9.94 S255
+M03 F1000 X9.71 Y9.64 S255
+M03 F1000 X9.98 Y9.30 S255
+M03 F1000 X10.20 Y8.93 S255
+M03 F1000 X10.36 Y8.52 S255
+M03 F1000 X10.49 Y7.65 S255
+M05 F2000 X17.57 Y14.57
+M03 F1000 X17.57 Y0.73 S255
+M05 F2000 X0 Y0
\ No newline at end of file
@@ -0,0 +1,25 @@
+G01
+M05 F2000 X0.00 Y0.00
+M03 F1000 X10.00 Y0.00 S255
+M03 F1000 X10.00 Y10.00 S255
+M03 F1000 X0.00 Y10.00 S255
+M03 F1000 X0.00 Y0.00 S255
+M05 F2000 X15.00 Y0.00
+M03 F1000 X25.00 Y0.00 S255
+M03 F1000 X20.00 Y10.00 S255
+M03 F1000 X15.00 Y0.00 S255
+M05 F2000 X30.00 Y0.00
+M03 F1000 X40.00 Y2.00 S255
+M03 F1000 X40.00 Y6.00 S255
+M03 F1000 X30.00 Y4.00 S255
+M03 F1000 X30.00 Y0.00 S255
+M05 F2000 X0.00 Y15.00
+M03 F1000 X10.00 Y15.00 S255
+M03 F1000 X12.00 Y25.00 S255
+M03 F1000 X-2.00 Y25.00 S255
+M03 F1000 X0.00 Y15.00 S255
+M05 F2000 X15.00 Y15.00
+M03 F1000 X25.00 Y15.00 S255
+M03 F1000 X20.00 Y25.00 S255
+M03 F1000 X15.00 Y15.00 S255
+M05 F2000 X0 Y0
\ No newline at end of file
@@ -0,0 +1,117 @@
+G01
+M05 F2000 X0.00 Y0.50
+M03 F1000 X10.00 Y0.50 S255
+M05 F2000 X15.25 Y0.50
+M03 F1000 X24.75 Y0.50 S255
+M05 F2000 X30.00 Y0.50
+M03 F1000 X32.50 Y0.50 S255
+M05 F2000 X37.50 Y1.50
+M03 F1000 X30.00 Y1.50 S255
+M05 F2000 X24.25 Y1.50
+M03 F1000 X15.75 Y1.50 S255
+M05 F2000 X10.00 Y1.50
+M03 F1000 X0.00 Y1.50 S255
+M05 F2000 X0.00 Y2.50
+M03 F1000 X10.00 Y2.50 S255
+M05 F2000 X16.25 Y2.50
+M03 F1000 X23.75 Y2.50 S255
+M05 F2000 X30.00 Y2.50
+M03 F1000 X40.00 Y2.50 S255
+M05 F2000 X40.00 Y3.50
+M03 F1000 X30.00 Y3.50 S255
+M05 F2000 X23.25 Y3.50
+M03 F1000 X16.75 Y3.50 S255
+M05 F2000 X10.00 Y3.50
+M03 F1000 X0.00 Y3.50 S255
+M05 F2000 X0.00 Y4.50
+M03 F1000 X10.00 Y4.50 S255
+M05 F2000 X17.25 Y4.50
+M03 F1000 X22.75 Y4.50 S255
+M05 F2000 X32.50 Y4.50
+M03 F1000 X40.00 Y4.50 S255
+M05 F2000 X40.00 Y5.50
+M03 F1000 X37.50 Y5.50 S255
+M05 F2000 X22.25 Y5.50
+M03 F1000 X17.75 Y5.50 S255
+M05 F2000 X10.00 Y5.50
+M03 F1000 X0.00 Y5.50 S255
+M05 F2000 X0.00 Y6.50
+M03 F1000 X10.00 Y6.50 S255
+M05 F2000 X18.25 Y6.50
+M03 F1000 X21.75 Y6.50 S255
+M05 F2000 X21.25 Y7.50
+M03 F1000 X18.75 Y7.50 S255
+M05 F2000 X10.00 Y7.50
+M03 F1000 X0.00 Y7.50 S255
+M05 F2000 X0.00 Y8.50
+M03 F1000 X10.00 Y8.50 S255
+M05 F2000 X19.25 Y8.50
+M03 F1000 X20.75 Y8.50 S255
+M05 F2000 X20.25 Y9.50
+M03 F1000 X19.75 Y9.50 S255
+M05 F2000 X10.00 Y9.50
+M03 F1000 X0.00 Y9.50 S255
+M05 F2000 X-0.10 Y15.50
+M03 F1000 X10.10 Y15.50 S255
+M05 F2000 X15.25 Y15.50
+M03 F1000 X24.75 Y15.50 S255
+M05 F2000 X24.25 Y16.50
+M03 F1000 X15.75 Y16.50 S255
+M05 F2000 X10.30 Y16.50
+M03 F1000 X-0.30 Y16.50 S255
+M05 F2000 X-0.50 Y17.50
+M03 F1000 X10.50 Y17.50 S255
+M05 F2000 X16.25 Y17.50
+M03 F1000 X23.75 Y17.50 S255
+M05 F2000 X23.25 Y18.50
+M03 F1000 X16.75 Y18.50 S255
+M05 F2000 X10.70 Y18.50
+M03 F1000 X-0.70 Y18.50 S255
+M05 F2000 X-0.90 Y19.50
+M03 F1000 X10.90 Y19.50 S255
+M05 F2000 X17.25 Y19.50
+M03 F1000 X22.75 Y19.50 S255
+M05 F2000 X22.25 Y20.50
+M03 F1000 X17.75 Y20.50 S255
+M05 F2000 X11.10 Y20.50
+M03 F1000 X-1.10 Y20.50 S255
+M05 F2000 X-1.30 Y21.50
+M03 F1000 X11.30 Y21.50 S255
+M05 F2000 X18.25 Y21.50
+M03 F1000 X21.75 Y21.50 S255
+M05 F2000 X21.25 Y22.50
+M03 F1000 X18.75 Y22.50 S255
+M05 F2000 X11.50 Y22.50
+M03 F1000 X-1.50 Y22.50 S255
+M05 F2000 X-1.70 Y23.50
+M03 F1000 X11.70 Y23.50 S255
+M05 F2000 X19.25 Y23.50
+M03 F1000 X20.75 Y23.50 S255
+M05 F2000 X20.25 Y24.50
+M03 F1000 X19.75 Y24.50 S255
+M05 F2000 X11.90 Y24.50
+M03 F1000 X-1.90 Y24.50 S255
+M05 F2000 X0.00 Y0.00
+M03 F1000 X10.00 Y0.00 S255
+M03 F1000 X10.00 Y10.00 S255
+M03 F1000 X0.00 Y10.00 S255
+M03 F1000 X0.00 Y0.00 S255
+M05 F2000 X15.00 Y0.00
+M03 F1000 X25.00 Y0.00 S255
+M03 F1000 X20.00 Y10.00 S255
+M03 F1000 X15.00 Y0.00 S255
+M05 F2000 X30.00 Y0.00
+M03 F1000 X40.00 Y2.00 S255
+M03 F1000 X40.00 Y6.00 S255
+M03 F1000 X30.00 Y4.00 S255
+M03 F1000 X30.00 Y0.00 S255
+M05 F2000 X0.00 Y15.00
+M03 F1000 X10.00 Y15.00 S255
+M03 F1000 X12.00 Y25.00 S255
+M03 F1000 X-2.00 Y25.00 S255
+M03 F1000 X0.00 Y15.00 S255
+M05 F2000 X15.00 Y15.00
+M03 F1000 X25.00 Y15.00 S255
+M03 F1000 X20.00 Y25.00 S255
+M03 F1000 X15.00 Y15.00 S255
+M05 F2000 X0 Y0
\ No newline at end of file
@@ -0,0 +1,672 @@
+G01
+M05 F2000 X0.00 Y0.00
+M03 F1000 X0.00 Y5.00 S255
+M05 F2000 X3.33 Y0.00
+M03 F1000 X3.33 Y5.00 S255
+M05 F2000 X0.00 Y2.50
+M03 F1000 X3.33 Y2.50 S255
+M05 F2000 X5.00 Y1.67
+M03 F1000 X7.50 Y1.67 S255
+M03 F1000 X7.50 Y2.50 S255
+M03 F1000 X6.67 Y3.33 S255
+M03 F1000 X5.83 Y3.33 S255
+M03 F1000 X5.00 Y2.50 S255
+M03 F1000 X5.00 Y0.83 S255
+M03 F1000 X5.83 Y0.00 S255
+M03 F1000 X7.50 Y0.00 S255
+M05 F2000 X10.00 Y5.00
+M03 F1000 X10.00 Y0.83 S255
+M03 F1000 X10.83 Y0.00 S255
+M05 F2000 X13.33 Y5.00
+M03 F1000 X13.33 Y0.83 S255
+M03 F1000 X14.17 Y0.00 S255
+M05 F2000 X16.67 Y0.00
+M03 F1000 X15.83 Y0.83 S255
+M03 F1000 X15.83 Y2.50 S255
+M03 F1000 X16.67 Y3.33 S255
+M03 F1000 X17.50 Y3.33 S255
+M03 F1000 X18.33 Y2.50 S255
+M03 F1000 X18.33 Y0.83 S255
+M03 F1000 X17.50 Y0.00 S255
+M03 F1000 X16.67 Y0.00 S255
+M05 F2000 X23.33 Y4.17
+M03 F1000 X24.17 Y5.00 S255
+M03 F1000 X25.83 Y5.00 S255
+M03 F1000 X26.67 Y4.17 S255
+M03 F1000 X26.67 Y3.33 S255
+M03 F1000 X23.33 Y0.00 S255
+M03 F1000 X26.67 Y0.00 S255
+M05 F2000 X31.67 Y5.00
+M03 F1000 X28.33 Y5.00 S255
+M03 F1000 X28.33 Y2.50 S255
+M03 F1000 X30.83 Y2.50 S255
+M03 F1000 X31.67 Y1.67 S255
+M03 F1000 X31.67 Y0.83 S255
+M03 F1000 X30.83 Y0.00 S255
+M03 F1000 X29.17 Y0.00 S255
+M03 F1000 X28.33 Y0.83 S255
+M05 F2000 X33.33 Y5.00
+M03 F1000 X35.00 Y5.00 S255
+M03 F1000 X35.00 Y3.33 S255
+M03 F1000 X33.33 Y3.33 S255
+M03 F1000 X33.33 Y5.00 S255
+M05 F2000 X40.00 Y4.17
+M03 F1000 X39.17 Y5.00 S255
+M03 F1000 X37.50 Y5.00 S255
+M03 F1000 X36.67 Y4.17 S255
+M03 F1000 X36.67 Y0.83 S255
+M03 F1000 X37.50 Y0.00 S255
+M03 F1000 X39.17 Y0.00 S255
+M03 F1000 X40.00 Y0.83 S255
+M05 F2000 X33.12 Y18.92
+M03 F1000 X32.44 Y19.10 S255
+M03 F1000 X31.57 Y18.60 S255
+M03 F1000 X31.39 Y17.92 S255
+M03 F1000 X32.39 Y16.18 S255
+M03 F1000 X33.07 Y16.00 S255
+M03 F1000 X33.94 Y16.50 S255
+M03 F1000 X34.12 Y17.18 S255
+M05 F2000 X34.74 Y18.12
+M03 F1000 X36.04 Y18.87 S255
+M03 F1000 X35.79 Y19.30 S255
+M03 F1000 X35.10 Y19.48 S255
+M03 F1000 X34.67 Y19.23 S255
+M03 F1000 X34.49 Y18.55 S255
+M03 F1000 X34.99 Y17.68 S255
+M03 F1000 X35.67 Y17.50 S255
+M03 F1000 X36.54 Y18.00 S255
+M05 F2000 X36.40 Y20.23
+M03 F1000 X37.40 Y18.50 S255
+M05 F2000 X36.65 Y19.80
+M03 F1000 X36.83 Y20.48 S255
+M03 F1000 X37.27 Y20.73 S255
+M03 F1000 X37.95 Y20.55 S255
+M03 F1000 X38.70 Y19.25 S255
+M05 F2000 X38.50 Y22.60
+M03 F1000 X39.75 Y20.43 S255
+M03 F1000 X40.43 Y20.25 S255
+M03 F1000 X40.87 Y20.50 S255
+M05 F2000 X38.57 Y21.48
+M03 F1000 X39.43 Y21.98 S255
+M05 F2000 X40.73 Y22.73
+M03 F1000 X41.73 Y21.00 S255
+M05 F2000 X41.23 Y21.87
+M03 F1000 X41.60 Y23.23 S255
+M03 F1000 X42.03 Y23.48 S255
+M05 F2000 X43.40 Y23.12
+M03 F1000 X44.70 Y23.87 S255
+M03 F1000 X44.45 Y24.30 S255
+M03 F1000 X43.76 Y24.48 S255
+M03 F1000 X43.33 Y24.23 S255
+M03 F1000 X43.15 Y23.55 S255
+M03 F1000 X43.65 Y22.68 S255
+M03 F1000 X44.33 Y22.50 S255
+M03 F1000 X45.20 Y23.00 S255
+M05 F2000 X45.86 Y26.85
+M03 F1000 X47.36 Y24.25 S255
+M05 F2000 X46.61 Y25.55
+M03 F1000 X45.93 Y25.73 S255
+M03 F1000 X45.50 Y25.48 S255
+M03 F1000 X45.31 Y24.80 S255
+M03 F1000 X45.81 Y23.93 S255
+M03 F1000 X46.50 Y23.75 S255
+M03 F1000 X46.93 Y24.00 S255
+M03 F1000 X47.11 Y24.68 S255
+M05 F2000 X0.00 Y-20.00
+M03 F1000 X3.75 Y-8.75 S255
+M03 F1000 X7.50 Y-20.00 S255
+M05 F2000 X1.88 Y-14.38
+M03 F1000 X5.62 Y-14.38 S255
+M05 F2000 X13.12 Y-8.75
+M03 F1000 X13.12 Y-18.12 S255
+M03 F1000 X15.00 Y-20.00 S255
+M05 F2000 X20.62 Y-12.50
+M03 F1000 X20.62 Y-20.00 S255
+M05 F2000 X20.62 Y-8.75
+M03 F1000 X20.62 Y-10.62 S255
+M05 F2000 X31.88 Y-12.50
+M03 F1000 X31.88 Y-21.88 S255
+M03 F1000 X30.00 Y-23.75 S255
+M03 F1000 X28.12 Y-23.75 S255
+M03 F1000 X26.25 Y-21.88 S255
+M05 F2000 X31.88 Y-14.38
+M03 F1000 X30.00 Y-12.50 S255
+M03 F1000 X28.12 Y-12.50 S255
+M03 F1000 X26.25 Y-14.38 S255
+M03 F1000 X26.25 Y-18.12 S255
+M03 F1000 X28.12 Y-20.00 S255
+M03 F1000 X30.00 Y-20.00 S255
+M03 F1000 X31.88 Y-18.12 S255
+M05 F2000 X35.62 Y-12.50
+M03 F1000 X35.62 Y-20.00 S255
+M05 F2000 X35.62 Y-14.38
+M03 F1000 X37.50 Y-12.50 S255
+M03 F1000 X39.38 Y-12.50 S255
+M03 F1000 X41.25 Y-14.38 S255
+M03 F1000 X41.25 Y-20.00 S255
+M05 F2000 X45.00 Y-16.25
+M03 F1000 X50.62 Y-16.25 S255
+M03 F1000 X50.62 Y-14.38 S255
+M03 F1000 X48.75 Y-12.50 S255
+M03 F1000 X46.88 Y-12.50 S255
+M03 F1000 X45.00 Y-14.38 S255
+M03 F1000 X45.00 Y-18.12 S255
+M03 F1000 X46.88 Y-20.00 S255
+M03 F1000 X50.62 Y-20.00 S255
+M05 F2000 X60.00 Y-8.75
+M03 F1000 X60.00 Y-20.00 S255
+M05 F2000 X60.00 Y-14.38
+M03 F1000 X58.12 Y-12.50 S255
+M03 F1000 X56.25 Y-12.50 S255
+M03 F1000 X54.38 Y-14.38 S255
+M03 F1000 X54.38 Y-18.12 S255
+M03 F1000 X56.25 Y-20.00 S255
+M03 F1000 X58.12 Y-20.00 S255
+M03 F1000 X60.00 Y-18.12 S255
+M05 F2000 X77.60 Y0.00
+M03 F1000 X79.73 Y0.00 S255
+M05 F2000 X78.67 Y0.00
+M03 F1000 X77.59 Y-4.00 S255
+M05 F2000 X80.26 Y-4.00
+M03 F1000 X79.91 Y-3.33 S255
+M03 F1000 X80.26 Y-2.00 S255
+M03 F1000 X80.98 Y-1.33 S255
+M03 F1000 X81.51 Y-1.33 S255
+M03 F1000 X81.86 Y-2.00 S255
+M03 F1000 X81.51 Y-3.33 S255
+M03 F1000 X80.79 Y-4.00 S255
+M03 F1000 X80.26 Y-4.00 S255
+M05 F2000 X83.11 Y-1.33
+M03 F1000 X82.04 Y-5.33 S255
+M05 F2000 X82.93 Y-2.00
+M03 F1000 X83.64 Y-1.33 S255
+M03 F1000 X84.18 Y-1.33 S255
+M03 F1000 X84.53 Y-2.00 S255
+M03 F1000 X84.17 Y-3.33 S255
+M03 F1000 X83.46 Y-4.00 S255
+M03 F1000 X82.93 Y-4.00 S255
+M03 F1000 X82.57 Y-3.33 S255
+M05 F2000 X87.91 Y-1.33
+M03 F1000 X87.19 Y-4.00 S255
+M05 F2000 X87.55 Y-2.67
+M03 F1000 X88.98 Y-1.33 S255
+M03 F1000 X89.51 Y-1.33 S255
+M05 F2000 X91.11 Y-1.33
+M03 F1000 X90.39 Y-4.00 S255
+M05 F2000 X91.47 Y0.00
+M03 F1000 X91.29 Y-0.67 S255
+M05 F2000 X94.31 Y-1.33
+M03 F1000 X93.42 Y-4.67 S255
+M03 F1000 X92.70 Y-5.33 S255
+M03 F1000 X92.17 Y-5.33 S255
+M03 F1000 X91.82 Y-4.67 S255
+M05 F2000 X94.13 Y-2.00
+M03 F1000 X93.78 Y-1.33 S255
+M03 F1000 X93.24 Y-1.33 S255
+M03 F1000 X92.53 Y-2.00 S255
+M03 F1000 X92.17 Y-3.33 S255
+M03 F1000 X92.53 Y-4.00 S255
+M03 F1000 X93.06 Y-4.00 S255
+M03 F1000 X93.77 Y-3.33 S255
+M05 F2000 X95.73 Y0.00
+M03 F1000 X94.66 Y-4.00 S255
+M05 F2000 X95.20 Y-2.00
+M03 F1000 X95.91 Y-1.33 S255
+M03 F1000 X96.44 Y-1.33 S255
+M03 F1000 X96.80 Y-2.00 S255
+M03 F1000 X96.26 Y-4.00 S255
+M05 F2000 X98.93 Y0.00
+M03 F1000 X98.04 Y-3.33 S255
+M03 F1000 X98.39 Y-4.00 S255
+M03 F1000 X98.93 Y-4.00 S255
+M05 F2000 X98.04 Y-1.33
+M03 F1000 X99.11 Y-1.33 S255
+M05 F2000 X41.75 Y-36.00
+M03 F1000 X41.75 Y-39.00 S255
+M03 F1000 X43.75 Y-39.00 S255
+M05 F2000 X45.25 Y-37.00
+M03 F1000 X45.25 Y-39.00 S255
+M05 F2000 X45.25 Y-36.00
+M03 F1000 X45.25 Y-36.50 S255
+M05 F2000 X46.75 Y-37.00
+M03 F1000 X46.75 Y-39.00 S255
+M05 F2000 X46.75 Y-37.50
+M03 F1000 X47.25 Y-37.00 S255
+M03 F1000 X47.75 Y-37.00 S255
+M03 F1000 X48.25 Y-37.50 S255
+M03 F1000 X48.25 Y-39.00 S255
+M05 F2000 X49.25 Y-38.00
+M03 F1000 X50.75 Y-38.00 S255
+M03 F1000 X50.75 Y-37.50 S255
+M03 F1000 X50.25 Y-37.00 S255
+M03 F1000 X49.75 Y-37.00 S255
+M03 F1000 X49.25 Y-37.50 S255
+M03 F1000 X49.25 Y-38.50 S255
+M03 F1000 X49.75 Y-39.00 S255
+M03 F1000 X50.75 Y-39.00 S255
+M05 F2000 X52.25 Y-39.00
+M03 F1000 X51.75 Y-38.50 S255
+M03 F1000 X51.75 Y-37.50 S255
+M03 F1000 X52.25 Y-37.00 S255
+M03 F1000 X52.75 Y-37.00 S255
+M03 F1000 X53.25 Y-37.50 S255
+M03 F1000 X53.25 Y-38.50 S255
+M03 F1000 X52.75 Y-39.00 S255
+M03 F1000 X52.25 Y-39.00 S255
+M05 F2000 X54.25 Y-37.00
+M03 F1000 X54.25 Y-39.00 S255
+M05 F2000 X54.25 Y-37.50
+M03 F1000 X54.75 Y-37.00 S255
+M03 F1000 X55.25 Y-37.00 S255
+M03 F1000 X55.75 Y-37.50 S255
+M03 F1000 X55.75 Y-39.00 S255
+M05 F2000 X56.75 Y-38.00
+M03 F1000 X58.25 Y-38.00 S255
+M03 F1000 X58.25 Y-37.50 S255
+M03 F1000 X57.75 Y-37.00 S255
+M03 F1000 X57.25 Y-37.00 S255
+M03 F1000 X56.75 Y-37.50 S255
+M03 F1000 X56.75 Y-38.50 S255
+M03 F1000 X57.25 Y-39.00 S255
+M03 F1000 X58.25 Y-39.00 S255
+M05 F2000 X22.00 Y-41.50
+M03 F1000 X21.50 Y-41.00 S255
+M03 F1000 X20.50 Y-41.00 S255
+M03 F1000 X20.00 Y-41.50 S255
+M03 F1000 X20.00 Y-42.00 S255
+M03 F1000 X20.50 Y-42.50 S255
+M03 F1000 X21.50 Y-42.50 S255
+M03 F1000 X22.00 Y-43.00 S255
+M03 F1000 X22.00 Y-43.50 S255
+M03 F1000 X21.50 Y-44.00 S255
+M03 F1000 X20.50 Y-44.00 S255
+M03 F1000 X20.00 Y-43.50 S255
+M05 F2000 X23.00 Y-43.00
+M03 F1000 X24.50 Y-43.00 S255
+M03 F1000 X24.50 Y-42.50 S255
+M03 F1000 X24.00 Y-42.00 S255
+M03 F1000 X23.50 Y-42.00 S255
+M03 F1000 X23.00 Y-42.50 S255
+M03 F1000 X23.00 Y-43.50 S255
+M03 F1000 X23.50 Y-44.00 S255
+M03 F1000 X24.50 Y-44.00 S255
+M05 F2000 X27.00 Y-42.50
+M03 F1000 X26.50 Y-42.00 S255
+M03 F1000 X26.00 Y-42.00 S255
+M03 F1000 X25.50 Y-42.50 S255
+M03 F1000 X25.50 Y-43.50 S255
+M03 F1000 X26.00 Y-44.00 S255
+M03 F1000 X26.50 Y-44.00 S255
+M03 F1000 X27.00 Y-43.50 S255
+M05 F2000 X28.50 Y-44.00
+M03 F1000 X28.00 Y-43.50 S255
+M03 F1000 X28.00 Y-42.50 S255
+M03 F1000 X28.50 Y-42.00 S255
+M03 F1000 X29.00 Y-42.00 S255
+M03 F1000 X29.50 Y-42.50 S255
+M03 F1000 X29.50 Y-43.50 S255
+M03 F1000 X29.00 Y-44.00 S255
+M03 F1000 X28.50 Y-44.00 S255
+M05 F2000 X30.50 Y-42.00
+M03 F1000 X30.50 Y-44.00 S255
+M05 F2000 X30.50 Y-42.50
+M03 F1000 X31.00 Y-42.00 S255
+M03 F1000 X31.50 Y-42.00 S255
+M03 F1000 X32.00 Y-42.50 S255
+M03 F1000 X32.00 Y-44.00 S255
+M05 F2000 X34.50 Y-41.00
+M03 F1000 X34.50 Y-44.00 S255
+M05 F2000 X34.50 Y-42.50
+M03 F1000 X34.00 Y-42.00 S255
+M03 F1000 X33.50 Y-42.00 S255
+M03 F1000 X33.00 Y-42.50 S255
+M03 F1000 X33.00 Y-43.50 S255
+M03 F1000 X33.50 Y-44.00 S255
+M03 F1000 X34.00 Y-44.00 S255
+M03 F1000 X34.50 Y-43.50 S255
+M05 F2000 X38.00 Y-41.00
+M03 F1000 X38.00 Y-43.50 S255
+M03 F1000 X38.50 Y-44.00 S255
+M05 F2000 X40.00 Y-42.00
+M03 F1000 X40.00 Y-44.00 S255
+M05 F2000 X40.00 Y-41.00
+M03 F1000 X40.00 Y-41.50 S255
+M05 F2000 X41.50 Y-42.00
+M03 F1000 X41.50 Y-44.00 S255
+M05 F2000 X41.50 Y-42.50
+M03 F1000 X42.00 Y-42.00 S255
+M03 F1000 X42.50 Y-42.00 S255
+M03 F1000 X43.00 Y-42.50 S255
+M03 F1000 X43.00 Y-44.00 S255
+M05 F2000 X44.00 Y-43.00
+M03 F1000 X45.50 Y-43.00 S255
+M03 F1000 X45.50 Y-42.50 S255
+M03 F1000 X45.00 Y-42.00 S255
+M03 F1000 X44.50 Y-42.00 S255
+M03 F1000 X44.00 Y-42.50 S255
+M03 F1000 X44.00 Y-43.50 S255
+M03 F1000 X44.50 Y-44.00 S255
+M03 F1000 X45.50 Y-44.00 S255
+M05 F2000 X48.50 Y-42.00
+M03 F1000 X49.00 Y-44.00 S255
+M03 F1000 X49.50 Y-42.50 S255
+M03 F1000 X50.00 Y-44.00 S255
+M03 F1000 X50.50 Y-42.00 S255
+M05 F2000 X52.00 Y-42.00
+M03 F1000 X52.00 Y-44.00 S255
+M05 F2000 X52.00 Y-41.00
+M03 F1000 X52.00 Y-41.50 S255
+M05 F2000 X54.00 Y-41.00
+M03 F1000 X54.00 Y-43.50 S255
+M03 F1000 X54.50 Y-44.00 S255
+M03 F1000 X55.00 Y-44.00 S255
+M05 F2000 X53.50 Y-42.00
+M03 F1000 X54.50 Y-42.00 S255
+M05 F2000 X56.00 Y-41.00
+M03 F1000 X56.00 Y-44.00 S255
+M05 F2000 X56.00 Y-42.50
+M03 F1000 X56.50 Y-42.00 S255
+M03 F1000 X57.00 Y-42.00 S255
+M03 F1000 X57.50 Y-42.50 S255
+M03 F1000 X57.50 Y-44.00 S255
+M05 F2000 X61.00 Y-41.50
+M03 F1000 X61.50 Y-41.00 S255
+M03 F1000 X61.50 Y-44.00 S255
+M05 F2000 X61.00 Y-44.00
+M03 F1000 X62.00 Y-44.00 S255
+M05 F2000 X63.50 Y-44.00
+M03 F1000 X65.50 Y-41.00 S255
+M05 F2000 X66.50 Y-41.50
+M03 F1000 X67.00 Y-41.00 S255
+M03 F1000 X68.00 Y-41.00 S255
+M03 F1000 X68.50 Y-41.50 S255
+M03 F1000 X68.50 Y-42.00 S255
+M03 F1000 X66.50 Y-44.00 S255
+M03 F1000 X68.50 Y-44.00 S255
+M05 F2000 X72.00 Y-42.00
+M03 F1000 X72.00 Y-44.00 S255
+M05 F2000 X72.00 Y-41.00
+M03 F1000 X72.00 Y-41.50 S255
+M05 F2000 X73.50 Y-42.00
+M03 F1000 X73.50 Y-44.00 S255
+M05 F2000 X73.50 Y-42.50
+M03 F1000 X74.00 Y-42.00 S255
+M03 F1000 X74.50 Y-42.00 S255
+M03 F1000 X75.00 Y-42.50 S255
+M03 F1000 X75.00 Y-44.00 S255
+M05 F2000 X77.50 Y-42.50
+M03 F1000 X77.00 Y-42.00 S255
+M03 F1000 X76.50 Y-42.00 S255
+M03 F1000 X76.00 Y-42.50 S255
+M03 F1000 X76.00 Y-43.50 S255
+M03 F1000 X76.50 Y-44.00 S255
+M03 F1000 X77.00 Y-44.00 S255
+M03 F1000 X77.50 Y-43.50 S255
+M05 F2000 X78.50 Y-41.00
+M03 F1000 X78.50 Y-44.00 S255
+M05 F2000 X78.50 Y-42.50
+M03 F1000 X79.00 Y-42.00 S255
+M03 F1000 X79.50 Y-42.00 S255
+M03 F1000 X80.00 Y-42.50 S255
+M03 F1000 X80.00 Y-44.00 S255
+M05 F2000 X0.00 Y-60.00
+M03 F1000 X1.93 Y-59.48 S255
+M05 F2000 X0.97 Y-59.74
+M03 F1000 X1.74 Y-62.64 S255
+M05 F2000 X2.90 Y-59.22
+M03 F1000 X3.67 Y-62.12 S255
+M05 F2000 X3.29 Y-60.67
+M03 F1000 X3.64 Y-60.06 S255
+M03 F1000 X4.12 Y-59.93 S255
+M03 F1000 X4.73 Y-60.28 S255
+M03 F1000 X5.12 Y-61.73 S255
+M05 F2000 X5.83 Y-60.51
+M03 F1000 X7.28 Y-60.12 S255
+M03 F1000 X7.15 Y-59.64 S255
+M03 F1000 X6.54 Y-59.28 S255
+M03 F1000 X6.05 Y-59.41 S255
+M03 F1000 X5.70 Y-60.03 S255
+M03 F1000 X5.96 Y-60.99 S255
+M03 F1000 X6.57 Y-61.34 S255
+M03 F1000 X7.54 Y-61.09 S255
+M05 F2000 X11.37 Y-57.99
+M03 F1000 X12.14 Y-60.89 S255
+M05 F2000 X11.50 Y-58.47
+M03 F1000 X10.88 Y-58.12 S255
+M03 F1000 X10.40 Y-58.25 S255
+M03 F1000 X10.05 Y-58.86 S255
+M03 F1000 X10.31 Y-59.83 S255
+M03 F1000 X10.92 Y-60.18 S255
+M03 F1000 X11.40 Y-60.05 S255
+M03 F1000 X11.76 Y-59.44 S255
+M05 F2000 X12.33 Y-57.73
+M03 F1000 X12.72 Y-59.18 S255
+M03 F1000 X13.33 Y-59.53 S255
+M03 F1000 X13.82 Y-59.40 S255
+M03 F1000 X14.17 Y-58.79 S255
+M05 F2000 X13.78 Y-57.34
+M03 F1000 X14.30 Y-59.27 S255
+M05 F2000 X15.23 Y-56.95
+M03 F1000 X15.75 Y-58.89 S255
+M05 F2000 X14.97 Y-55.99
+M03 F1000 X15.10 Y-56.47 S255
+M05 F2000 X18.26 Y-56.66
+M03 F1000 X17.65 Y-56.31 S255
+M03 F1000 X17.16 Y-56.44 S255
+M03 F1000 X16.81 Y-57.05 S255
+M03 F1000 X17.07 Y-58.01 S255
+M03 F1000 X17.68 Y-58.37 S255
+M03 F1000 X18.16 Y-58.24 S255
+M03 F1000 X18.52 Y-57.63 S255
+M05 F2000 X18.84 Y-54.95
+M03 F1000 X19.61 Y-57.85 S255
+M05 F2000 X20.54 Y-55.53
+M03 F1000 X19.48 Y-57.37 S255
+M05 F2000 X19.84 Y-56.76
+M03 F1000 X21.06 Y-57.46 S255
+M05 F2000 X23.18 Y-53.79
+M03 F1000 X23.96 Y-56.69 S255
+M05 F2000 X23.57 Y-55.24
+M03 F1000 X23.92 Y-54.62 S255
+M03 F1000 X24.41 Y-54.50 S255
+M03 F1000 X25.02 Y-54.85 S255
+M03 F1000 X25.28 Y-55.81 S255
+M03 F1000 X24.92 Y-56.43 S255
+M03 F1000 X24.44 Y-56.56 S255
+M03 F1000 X23.83 Y-56.20 S255
+M05 F2000 X25.86 Y-54.11
+M03 F1000 X26.37 Y-56.04 S255
+M05 F2000 X26.11 Y-55.07
+M03 F1000 X26.82 Y-53.85 S255
+M03 F1000 X27.30 Y-53.72 S255
+M05 F2000 X29.27 Y-55.26
+M03 F1000 X28.66 Y-54.91 S255
+M03 F1000 X28.40 Y-53.94 S255
+M03 F1000 X28.75 Y-53.33 S255
+M03 F1000 X29.24 Y-53.20 S255
+M03 F1000 X29.85 Y-53.55 S255
+M03 F1000 X30.11 Y-54.52 S255
+M03 F1000 X29.75 Y-55.13 S255
+M03 F1000 X29.27 Y-55.26 S255
+M05 F2000 X30.69 Y-52.81
+M03 F1000 X31.69 Y-54.62 S255
+M03 F1000 X31.78 Y-53.04 S255
+M03 F1000 X32.65 Y-54.36 S255
+M03 F1000 X32.62 Y-52.30 S255
+M05 F2000 X33.58 Y-52.04
+M03 F1000 X34.10 Y-53.97 S255
+M05 F2000 X33.71 Y-52.52
+M03 F1000 X34.07 Y-51.91 S255
+M03 F1000 X34.55 Y-51.78 S255
+M03 F1000 X35.16 Y-52.13 S255
+M03 F1000 X35.55 Y-53.58 S255
+M05 F2000 X2.74 Y-64.44
+M03 F1000 X2.26 Y-64.57 S255
+M03 F1000 X1.91 Y-65.18 S255
+M03 F1000 X2.55 Y-67.60 S255
+M05 F2000 X1.55 Y-65.80
+M03 F1000 X2.52 Y-65.54 S255
+M05 F2000 X4.97 Y-66.95
+M03 F1000 X4.36 Y-66.60 S255
+M03 F1000 X4.10 Y-65.63 S255
+M03 F1000 X4.45 Y-65.02 S255
+M03 F1000 X4.93 Y-64.89 S255
+M03 F1000 X5.55 Y-65.24 S255
+M03 F1000 X5.80 Y-66.21 S255
+M03 F1000 X5.45 Y-66.82 S255
+M03 F1000 X4.97 Y-66.95 S255
+M05 F2000 X6.38 Y-64.50
+M03 F1000 X8.35 Y-66.05 S255
+M05 F2000 X6.90 Y-66.43
+M03 F1000 X7.83 Y-64.11 S255
+M05 F2000 X9.76 Y-63.60
+M03 F1000 X10.41 Y-66.01 S255
+M03 F1000 X10.06 Y-66.62 S255
+M03 F1000 X9.57 Y-66.75 S255
+M05 F2000 X9.50 Y-62.63
+M03 F1000 X9.63 Y-63.11 S255
+M05 F2000 X10.73 Y-63.34
+M03 F1000 X11.12 Y-64.79 S255
+M03 F1000 X11.73 Y-65.14 S255
+M03 F1000 X12.21 Y-65.01 S255
+M03 F1000 X12.57 Y-64.40 S255
+M05 F2000 X12.18 Y-62.95
+M03 F1000 X12.70 Y-64.88 S255
+M05 F2000 X13.14 Y-62.69
+M03 F1000 X13.66 Y-64.62 S255
+M05 F2000 X13.27 Y-63.17
+M03 F1000 X13.63 Y-62.56 S255
+M03 F1000 X14.24 Y-62.91 S255
+M03 F1000 X14.63 Y-64.36 S255
+M05 F2000 X14.24 Y-62.91
+M03 F1000 X14.59 Y-62.30 S255
+M03 F1000 X15.21 Y-62.66 S255
+M03 F1000 X15.59 Y-64.10 S255
+M05 F2000 X16.04 Y-61.91
+M03 F1000 X16.82 Y-64.81 S255
+M05 F2000 X16.17 Y-62.40
+M03 F1000 X16.52 Y-61.78 S255
+M03 F1000 X17.01 Y-61.65 S255
+M03 F1000 X17.62 Y-62.01 S255
+M03 F1000 X17.88 Y-62.97 S255
+M03 F1000 X17.53 Y-63.59 S255
+M03 F1000 X17.04 Y-63.72 S255
+M03 F1000 X16.43 Y-63.36 S255
+M05 F2000 X20.03 Y-61.36
+M03 F1000 X19.42 Y-61.01 S255
+M03 F1000 X18.94 Y-61.14 S255
+M03 F1000 X18.59 Y-61.75 S255
+M03 F1000 X19.20 Y-62.10 S255
+M03 F1000 X19.68 Y-61.97 S255
+M03 F1000 X20.29 Y-62.33 S255
+M03 F1000 X19.94 Y-62.94 S255
+M03 F1000 X19.46 Y-63.07 S255
+M03 F1000 X18.84 Y-62.72 S255
+M05 F2000 X23.80 Y-61.90
+M03 F1000 X23.19 Y-61.55 S255
+M03 F1000 X22.93 Y-60.58 S255
+M03 F1000 X23.29 Y-59.97 S255
+M03 F1000 X23.77 Y-59.84 S255
+M03 F1000 X24.38 Y-60.20 S255
+M03 F1000 X24.64 Y-61.16 S255
+M03 F1000 X24.29 Y-61.77 S255
+M03 F1000 X23.80 Y-61.90 S255
+M05 F2000 X25.22 Y-59.45
+M03 F1000 X26.70 Y-61.13 S255
+M03 F1000 X27.15 Y-58.94 S255
+M05 F2000 X28.37 Y-59.64
+M03 F1000 X29.82 Y-59.26 S255
+M03 F1000 X29.69 Y-58.77 S255
+M03 F1000 X29.08 Y-58.42 S255
+M03 F1000 X28.60 Y-58.55 S255
+M03 F1000 X28.25 Y-59.16 S255
+M03 F1000 X28.50 Y-60.13 S255
+M03 F1000 X29.12 Y-60.48 S255
+M03 F1000 X30.08 Y-60.22 S255
+M05 F2000 X30.53 Y-58.03
+M03 F1000 X31.05 Y-59.96 S255
+M05 F2000 X30.79 Y-59.00
+M03 F1000 X31.50 Y-57.77 S255
+M03 F1000 X31.98 Y-57.64 S255
+M05 F2000 X3.07 Y-69.53
+M03 F1000 X3.72 Y-71.94 S255
+M03 F1000 X4.33 Y-72.30 S255
+M03 F1000 X4.81 Y-72.17 S255
+M05 F2000 X2.85 Y-70.63
+M03 F1000 X3.81 Y-70.37 S255
+M05 F2000 X5.00 Y-69.01
+M03 F1000 X5.78 Y-71.91 S255
+M05 F2000 X5.39 Y-70.46
+M03 F1000 X5.74 Y-69.85 S255
+M03 F1000 X6.23 Y-69.72 S255
+M03 F1000 X6.84 Y-70.07 S255
+M03 F1000 X7.23 Y-71.52 S255
+M05 F2000 X7.94 Y-70.30
+M03 F1000 X9.38 Y-69.91 S255
+M03 F1000 X9.25 Y-69.43 S255
+M03 F1000 X8.64 Y-69.07 S255
+M03 F1000 X8.16 Y-69.20 S255
+M03 F1000 X7.81 Y-69.81 S255
+M03 F1000 X8.06 Y-70.78 S255
+M03 F1000 X8.68 Y-71.13 S255
+M03 F1000 X9.64 Y-70.87 S255
+M05 F2000 X12.25 Y-67.07
+M03 F1000 X12.89 Y-69.49 S255
+M03 F1000 X13.51 Y-69.84 S255
+M05 F2000 X15.40 Y-67.26
+M03 F1000 X15.92 Y-69.19 S255
+M05 F2000 X15.53 Y-67.74
+M03 F1000 X14.92 Y-67.39 S255
+M03 F1000 X14.44 Y-67.52 S255
+M03 F1000 X14.08 Y-68.13 S255
+M03 F1000 X14.34 Y-69.10 S255
+M03 F1000 X14.96 Y-69.45 S255
+M03 F1000 X15.44 Y-69.32 S255
+M03 F1000 X15.79 Y-68.71 S255
+M05 F2000 X16.37 Y-67.00
+M03 F1000 X17.82 Y-66.61 S255
+M03 F1000 X16.89 Y-68.93 S255
+M03 F1000 X18.34 Y-68.55 S255
+M05 F2000 X18.78 Y-66.35
+M03 F1000 X19.17 Y-67.80 S255
+M03 F1000 X19.79 Y-68.16 S255
+M03 F1000 X20.27 Y-68.03 S255
+M03 F1000 X20.62 Y-67.42 S255
+M05 F2000 X20.23 Y-65.97
+M03 F1000 X20.88 Y-68.38 S255
+M03 F1000 X20.53 Y-68.99 S255
+M03 F1000 X20.04 Y-69.12 S255
+M03 F1000 X19.43 Y-68.77 S255
+M05 F2000 X22.39 Y-64.35
+M03 F1000 X23.17 Y-67.25 S255
+M05 F2000 X22.78 Y-65.80
+M03 F1000 X22.17 Y-65.45 S255
+M03 F1000 X21.68 Y-65.58 S255
+M03 F1000 X21.33 Y-66.19 S255
+M03 F1000 X21.59 Y-67.16 S255
+M03 F1000 X22.20 Y-67.51 S255
+M03 F1000 X22.68 Y-67.38 S255
+M03 F1000 X23.04 Y-66.77 S255
+M05 F2000 X24.62 Y-66.86
+M03 F1000 X24.00 Y-66.51 S255
+M03 F1000 X23.74 Y-65.54 S255
+M03 F1000 X24.10 Y-64.93 S255
+M03 F1000 X24.58 Y-64.80 S255
+M03 F1000 X25.19 Y-65.16 S255
+M03 F1000 X25.45 Y-66.12 S255
+M03 F1000 X25.10 Y-66.73 S255
+M03 F1000 X24.62 Y-66.86 S255
+M05 F2000 X27.48 Y-64.03
+M03 F1000 X28.13 Y-66.44 S255
+M03 F1000 X27.77 Y-67.05 S255
+M03 F1000 X27.29 Y-67.18 S255
+M03 F1000 X26.68 Y-66.83 S255
+M05 F2000 X27.61 Y-64.51
+M03 F1000 X27.00 Y-64.15 S255
+M03 F1000 X26.51 Y-64.28 S255
+M03 F1000 X26.16 Y-64.90 S255
+M03 F1000 X26.42 Y-65.86 S255
+M03 F1000 X27.03 Y-66.22 S255
+M03 F1000 X27.51 Y-66.09 S255
+M03 F1000 X27.87 Y-65.47 S255
+M05 F2000 X0 Y0
\ No newline at end of file
@@ -0,0 +1,180 @@
+G01
+M05 F2000 X2.39 Y0.25
+M03 F1000 X3.27 Y0.25 S255
+M05 F2000 X6.80 Y0.25
+M03 F1000 X11.01 Y0.25 S255
+M05 F2000 X19.94 Y0.25
+M03 F1000 X20.82 Y0.25 S255
+M05 F2000 X2.39 Y0.75
+M03 F1000 X3.27 Y0.75 S255
+M05 F2000 X6.80 Y0.75
+M03 F1000 X11.01 Y0.75 S255
+M05 F2000 X19.94 Y0.75
+M03 F1000 X20.82 Y0.75 S255
+M05 F2000 X2.39 Y1.25
+M03 F1000 X3.27 Y1.25 S255
+M05 F2000 X6.80 Y1.25
+M03 F1000 X7.67 Y1.25 S255
+M05 F2000 X19.94 Y1.25
+M03 F1000 X20.82 Y1.25 S255
+M05 F2000 X2.39 Y1.75
+M03 F1000 X3.27 Y1.75 S255
+M05 F2000 X6.80 Y1.75
+M03 F1000 X7.67 Y1.75 S255
+M05 F2000 X19.94 Y1.75
+M03 F1000 X20.82 Y1.75 S255
+M05 F2000 X2.39 Y2.25
+M03 F1000 X3.27 Y2.25 S255
+M05 F2000 X6.80 Y2.25
+M03 F1000 X7.67 Y2.25 S255
+M05 F2000 X19.94 Y2.25
+M03 F1000 X20.82 Y2.25 S255
+M05 F2000 X2.39 Y2.75
+M03 F1000 X3.27 Y2.75 S255
+M05 F2000 X6.80 Y2.75
+M03 F1000 X7.67 Y2.75 S255
+M05 F2000 X19.94 Y2.75
+M03 F1000 X20.82 Y2.75 S255
+M05 F2000 X2.39 Y3.25
+M03 F1000 X3.27 Y3.25 S255
+M05 F2000 X6.80 Y3.25
+M03 F1000 X7.67 Y3.25 S255
+M05 F2000 X19.94 Y3.25
+M03 F1000 X20.82 Y3.25 S255
+M05 F2000 X2.39 Y3.75
+M03 F1000 X3.27 Y3.75 S255
+M05 F2000 X6.80 Y3.75
+M03 F1000 X10.81 Y3.75 S255
+M05 F2000 X19.94 Y3.75
+M03 F1000 X20.82 Y3.75 S255
+M05 F2000 X2.39 Y4.25
+M03 F1000 X3.27 Y4.25 S255
+M05 F2000 X6.80 Y4.25
+M03 F1000 X10.81 Y4.25 S255
+M05 F2000 X19.94 Y4.25
+M03 F1000 X20.82 Y4.25 S255
+M05 F2000 X2.39 Y4.75
+M03 F1000 X3.27 Y4.75 S255
+M05 F2000 X6.80 Y4.75
+M03 F1000 X7.67 Y4.75 S255
+M05 F2000 X19.94 Y4.75
+M03 F1000 X20.82 Y4.75 S255
+M05 F2000 X2.39 Y5.25
+M03 F1000 X3.27 Y5.25 S255
+M05 F2000 X6.80 Y5.25
+M03 F1000 X7.67 Y5.25 S255
+M05 F2000 X19.94 Y5.25
+M03 F1000 X20.82 Y5.25 S255
+M05 F2000 X2.39 Y5.75
+M03 F1000 X3.27 Y5.75 S255
+M05 F2000 X6.80 Y5.75
+M03 F1000 X7.67 Y5.75 S255
+M05 F2000 X19.94 Y5.75
+M03 F1000 X20.82 Y5.75 S255
+M05 F2000 X2.39 Y6.25
+M03 F1000 X3.27 Y6.25 S255
+M05 F2000 X6.80 Y6.25
+M03 F1000 X7.67 Y6.25 S255
+M05 F2000 X19.94 Y6.25
+M03 F1000 X20.82 Y6.25 S255
+M05 F2000 X2.39 Y6.75
+M03 F1000 X3.27 Y6.75 S255
+M05 F2000 X6.80 Y6.75
+M03 F1000 X7.67 Y6.75 S255
+M05 F2000 X19.94 Y6.75
+M03 F1000 X20.82 Y6.75 S255
+M05 F2000 X0.00 Y7.25
+M03 F1000 X5.66 Y7.25 S255
+M05 F2000 X6.80 Y7.25
+M03 F1000 X11.01 Y7.25 S255
+M05 F2000 X17.55 Y7.25
+M03 F1000 X23.21 Y7.25 S255
+M05 F2000 X22.75 Y6.88
+M03 F1000 X22.75 Y7.66 S255
+M05 F2000 X22.25 Y6.88
+M03 F1000 X22.25 Y7.66 S255
+M05 F2000 X21.75 Y6.88
+M03 F1000 X21.75 Y7.66 S255
+M05 F2000 X21.25 Y6.88
+M03 F1000 X21.25 Y7.66 S255
+M05 F2000 X20.75 Y0.10
+M03 F1000 X20.75 Y7.66 S255
+M05 F2000 X20.25 Y0.10
+M03 F1000 X20.25 Y7.66 S255
+M05 F2000 X19.75 Y6.88
+M03 F1000 X19.75 Y7.66 S255
+M05 F2000 X19.25 Y6.88
+M03 F1000 X19.25 Y7.66 S255
+M05 F2000 X18.75 Y6.88
+M03 F1000 X18.75 Y7.66 S255
+M05 F2000 X18.25 Y6.88
+M03 F1000 X18.25 Y7.66 S255
+M05 F2000 X17.75 Y6.88
+M03 F1000 X17.75 Y7.66 S255
+M05 F2000 X10.75 Y0.10
+M03 F1000 X10.75 Y0.89 S255
+M05 F2000 X10.75 Y3.67
+M03 F1000 X10.75 Y4.44 S255
+M05 F2000 X10.75 Y6.88
+M03 F1000 X10.75 Y7.66 S255
+M05 F2000 X10.25 Y0.10
+M03 F1000 X10.25 Y0.89 S255
+M05 F2000 X10.25 Y3.67
+M03 F1000 X10.25 Y4.44 S255
+M05 F2000 X10.25 Y6.88
+M03 F1000 X10.25 Y7.66 S255
+M05 F2000 X9.75 Y0.10
+M03 F1000 X9.75 Y0.89 S255
+M05 F2000 X9.75 Y3.67
+M03 F1000 X9.75 Y4.44 S255
+M05 F2000 X9.75 Y6.88
+M03 F1000 X9.75 Y7.66 S255
+M05 F2000 X9.25 Y0.10
+M03 F1000 X9.25 Y0.89 S255
+M05 F2000 X9.25 Y3.67
+M03 F1000 X9.25 Y4.44 S255
+M05 F2000 X9.25 Y6.88
+M03 F1000 X9.25 Y7.66 S255
+M05 F2000 X8.75 Y0.10
+M03 F1000 X8.75 Y0.89 S255
+M05 F2000 X8.75 Y3.67
+M03 F1000 X8.75 Y4.44 S255
+M05 F2000 X8.75 Y6.88
+M03 F1000 X8.75 Y7.66 S255
+M05 F2000 X8.25 Y0.10
+M03 F1000 X8.25 Y0.89 S255
+M05 F2000 X8.25 Y3.67
+M03 F1000 X8.25 Y4.44 S255
+M05 F2000 X8.25 Y6.88
+M03 F1000 X8.25 Y7.66 S255
+M05 F2000 X7.75 Y0.10
+M03 F1000 X7.75 Y0.89 S255
+M05 F2000 X7.75 Y3.67
+M03 F1000 X7.75 Y4.44 S255
+M05 F2000 X7.75 Y6.88
+M03 F1000 X7.75 Y7.66 S255
+M05 F2000 X7.25 Y0.10
+M03 F1000 X7.25 Y7.66 S255
+M05 F2000 X5.25 Y6.88
+M03 F1000 X5.25 Y7.66 S255
+M05 F2000 X4.75 Y6.88
+M03 F1000 X4.75 Y7.66 S255
+M05 F2000 X4.25 Y6.88
+M03 F1000 X4.25 Y7.66 S255
+M05 F2000 X3.75 Y6.88
+M03 F1000 X3.75 Y7.66 S255
+M05 F2000 X3.25 Y0.10
+M03 F1000 X3.25 Y7.66 S255
+M05 F2000 X2.75 Y0.10
+M03 F1000 X2.75 Y7.66 S255
+M05 F2000 X2.25 Y6.88
+M03 F1000 X2.25 Y7.66 S255
+M05 F2000 X1.75 Y6.88
+M03 F1000 X1.75 Y7.66 S255
+M05 F2000 X1.25 Y6.88
+M03 F1000 X1.25 Y7.66 S255
+M05 F2000 X0.75 Y6.88
+M03 F1000 X0.75 Y7.66 S255
+M05 F2000 X0.25 Y6.88
+M03 F1000 X0.25 Y7.66 S255
+M05 F2000 X0 Y0
\ No newline at end of file
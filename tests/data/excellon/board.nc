@@ -0,0 +1,21 @@
+G01
+T1 M06
+G00 Z2.00
+G81 X20.00 Y2.00 Z-1.80 R2.00 F100
+G81 X24.00 Y2.00 Z-1.80 R2.00 F100
+G81 X28.00 Y2.00 Z-1.80 R2.00 F100
+G80
+G01
+T2 M06
+G00 Z2.00
+G81 X5.00 Y5.00 Z-1.80 R2.00 F100
+G81 X5.00 Y10.00 Z-1.80 R2.00 F100
+G80
+G01
+T3 M06
+G00 Z2.00
+G81 X3.50 Y3.50 Z-1.80 R2.00 F100
+G81 X46.50 Y3.50 Z-1.80 R2.00 F100
+G80
+G01
+M05 F2000 X0 Y0
\ No newline at end of file
@@ -0,0 +1,225 @@
+G01
+M05 F2000 X20.40 Y2.00
+M03 F1000 X20.37 Y2.15 S255
+M03 F1000 X20.28 Y2.28 S255
+M03 F1000 X20.15 Y2.37 S255
+M03 F1000 X20.00 Y2.40 S255
+M03 F1000 X19.85 Y2.37 S255
+M03 F1000 X19.72 Y2.28 S255
+M03 F1000 X19.63 Y2.15 S255
+M03 F1000 X19.60 Y2.00 S255
+M03 F1000 X19.63 Y1.85 S255
+M03 F1000 X19.72 Y1.72 S255
+M03 F1000 X19.85 Y1.63 S255
+M03 F1000 X20.00 Y1.60 S255
+M03 F1000 X20.15 Y1.63 S255
+M03 F1000 X20.28 Y1.72 S255
+M03 F1000 X20.37 Y1.85 S255
+M03 F1000 X20.40 Y2.00 S255
+M05 F2000 X24.40 Y2.00
+M03 F1000 X24.37 Y2.15 S255
+M03 F1000 X24.28 Y2.28 S255
+M03 F1000 X24.15 Y2.37 S255
+M03 F1000 X24.00 Y2.40 S255
+M03 F1000 X23.85 Y2.37 S255
+M03 F1000 X23.72 Y2.28 S255
+M03 F1000 X23.63 Y2.15 S255
+M03 F1000 X23.60 Y2.00 S255
+M03 F1000 X23.63 Y1.85 S255
+M03 F1000 X23.72 Y1.72 S255
+M03 F1000 X23.85 Y1.63 S255
+M03 F1000 X24.00 Y1.60 S255
+M03 F1000 X24.15 Y1.63 S255
+M03 F1000 X24.28 Y1.72 S255
+M03 F1000 X24.37 Y1.85 S255
+M03 F1000 X24.40 Y2.00 S255
+M05 F2000 X28.40 Y2.00
+M03 F1000 X28.37 Y2.15 S255
+M03 F1000 X28.28 Y2.28 S255
+M03 F1000 X28.15 Y2.37 S255
+M03 F1000 X28.00 Y2.40 S255
+M03 F1000 X27.85 Y2.37 S255
+M03 F1000 X27.72 Y2.28 S255
+M03 F1000 X27.63 Y2.15 S255
+M03 F1000 X27.60 Y2.00 S255
+M03 F1000 X27.63 Y1.85 S255
+M03 F1000 X27.72 Y1.72 S255
+M03 F1000 X27.85 Y1.63 S255
+M03 F1000 X28.00 Y1.60 S255
+M03 F1000 X28.15 Y1.63 S255
+M03 F1000 X28.28 Y1.72 S255
+M03 F1000 X28.37 Y1.85 S255
+M03 F1000 X28.40 Y2.00 S255
+M05 F2000 X5.50 Y5.00
+M03 F1000 X5.48 Y5.15 S255
+M03 F1000 X5.40 Y5.29 S255
+M03 F1000 X5.29 Y5.40 S255
+M03 F1000 X5.15 Y5.48 S255
+M03 F1000 X5.00 Y5.50 S255
+M03 F1000 X4.85 Y5.48 S255
+M03 F1000 X4.71 Y5.40 S255
+M03 F1000 X4.60 Y5.29 S255
+M03 F1000 X4.52 Y5.15 S255
+M03 F1000 X4.50 Y5.00 S255
+M03 F1000 X4.52 Y4.85 S255
+M03 F1000 X4.60 Y4.71 S255
+M03 F1000 X4.71 Y4.60 S255
+M03 F1000 X4.85 Y4.52 S255
+M03 F1000 X5.00 Y4.50 S255
+M03 F1000 X5.15 Y4.52 S255
+M03 F1000 X5.29 Y4.60 S255
+M03 F1000 X5.40 Y4.71 S255
+M03 F1000 X5.48 Y4.85 S255
+M03 F1000 X5.50 Y5.00 S255
+M05 F2000 X5.50 Y10.00
+M03 F1000 X5.48 Y10.15 S255
+M03 F1000 X5.40 Y10.29 S255
+M03 F1000 X5.29 Y10.40 S255
+M03 F1000 X5.15 Y10.48 S255
+M03 F1000 X5.00 Y10.50 S255
+M03 F1000 X4.85 Y10.48 S255
+M03 F1000 X4.71 Y10.40 S255
+M03 F1000 X4.60 Y10.29 S255
+M03 F1000 X4.52 Y10.15 S255
+M03 F1000 X4.50 Y10.00 S255
+M03 F1000 X4.52 Y9.85 S255
+M03 F1000 X4.60 Y9.71 S255
+M03 F1000 X4.71 Y9.60 S255
+M03 F1000 X4.85 Y9.52 S255
+M03 F1000 X5.00 Y9.50 S255
+M03 F1000 X5.15 Y9.52 S255
+M03 F1000 X5.29 Y9.60 S255
+M03 F1000 X5.40 Y9.71 S255
+M03 F1000 X5.48 Y9.85 S255
+M03 F1000 X5.50 Y10.00 S255
+M05 F2000 X5.10 Y3.50
+M03 F1000 X5.09 Y3.66 S255
+M03 F1000 X5.07 Y3.81 S255
+M03 F1000 X5.03 Y3.96 S255
+M03 F1000 X4.98 Y4.11 S255
+M03 F1000 X4.91 Y4.25 S255
+M03 F1000 X4.83 Y4.39 S255
+M03 F1000 X4.74 Y4.52 S255
+M03 F1000 X4.63 Y4.63 S255
+M03 F1000 X4.52 Y4.74 S255
+M03 F1000 X4.39 Y4.83 S255
+M03 F1000 X4.25 Y4.91 S255
+M03 F1000 X4.11 Y4.98 S255
+M03 F1000 X3.96 Y5.03 S255
+M03 F1000 X3.81 Y5.07 S255
+M03 F1000 X3.66 Y5.09 S255
+M03 F1000 X3.50 Y5.10 S255
+M03 F1000 X3.34 Y5.09 S255
+M03 F1000 X3.19 Y5.07 S255
+M03 F1000 X3.04 Y5.03 S255
+M03 F1000 X2.89 Y4.98 S255
+M03 F1000 X2.75 Y4.91 S255
+M03 F1000 X2.61 Y4.83 S255
+M03 F1000 X2.48 Y4.74 S255
+M03 F1000 X2.37 Y4.63 S255
+M03 F1000 X2.26 Y4.52 S255
+M03 F1000 X2.17 Y4.39 S255
+M03 F1000 X2.09 Y4.25 S255
+M03 F1000 X2.02 Y4.11 S255
+M03 F1000 X1.97 Y3.96 S255
+M03 F1000 X1.93 Y3.81 S255
+M03 F1000 X1.91 Y3.66 S255
+M03 F1000 X1.90 Y3.50 S255
+M03 F1000 X1.91 Y3.34 S255
+M03 F1000 X1.93 Y3.19 S255
+M03 F1000 X1.97 Y3.04 S255
+M03 F1000 X2.02 Y2.89 S255
+M03 F1000 X2.09 Y2.75 S255
+M03 F1000 X2.17 Y2.61 S255
+M03 F1000 X2.26 Y2.48 S255
+M03 F1000 X2.37 Y2.37 S255
+M03 F1000 X2.48 Y2.26 S255
+M03 F1000 X2.61 Y2.17 S255
+M03 F1000 X2.75 Y2.09 S255
+M03 F1000 X2.89 Y2.02 S255
+M03 F1000 X3.04 Y1.97 S255
+M03 F1000 X3.19 Y1.93 S255
+M03 F1000 X3.34 Y1.91 S255
+M03 F1000 X3.50 Y1.90 S255
+M03 F1000 X3.66 Y1.91 S255
+M03 F1000 X3.81 Y1.93 S255
+M03 F1000 X3.96 Y1.97 S255
+M03 F1000 X4.11 Y2.02 S255
+M03 F1000 X4.25 Y2.09 S255
+M03 F1000 X4.39 Y2.17 S255
+M03 F1000 X4.52 Y2.26 S255
+M03 F1000 X4.63 Y2.37 S255
+M03 F1000 X4.74 Y2.48 S255
+M03 F1000 X4.83 Y2.61 S255
+M03 F1000 X4.91 Y2.75 S255
+M03 F1000 X4.98 Y2.89 S255
+M03 F1000 X5.03 Y3.04 S255
+M03 F1000 X5.07 Y3.19 S255
+M03 F1000 X5.09 Y3.34 S255
+M03 F1000 X5.10 Y3.50 S255
+M05 F2000 X48.10 Y3.50
+M03 F1000 X48.09 Y3.66 S255
+M03 F1000 X48.07 Y3.81 S255
+M03 F1000 X48.03 Y3.96 S255
+M03 F1000 X47.98 Y4.11 S255
+M03 F1000 X47.91 Y4.25 S255
+M03 F1000 X47.83 Y4.39 S255
+M03 F1000 X47.74 Y4.52 S255
+M03 F1000 X47.63 Y4.63 S255
+M03 F1000 X47.52 Y4.74 S255
+M03 F1000 X47.39 Y4.83 S255
+M03 F1000 X47.25 Y4.91 S255
+M03 F1000 X47.11 Y4.98 S255
+M03 F1000 X46.96 Y5.03 S255
+M03 F1000 X46.81 Y5.07 S255
+M03 F1000 X46.66 Y5.09 S255
+M03 F1000 X46.50 Y5.10 S255
+M03 F1000 X46.34 Y5.09 S255
+M03 F1000 X46.19 Y5.07 S255
+M03 F1000 X46.04 Y5.03 S255
+M03 F1000 X45.89 Y4.98 S255
+M03 F1000 X45.75 Y4.91 S255
+M03 F1000 X45.61 Y4.83 S255
+M03 F1000 X45.48 Y4.74 S255
+M03 F1000 X45.37 Y4.63 S255
+M03 F1000 X45.26 Y4.52 S255
+M03 F1000 X45.17 Y4.39 S255
+M03 F1000 X45.09 Y4.25 S255
+M03 F1000 X45.02 Y4.11 S255
+M03 F1000 X44.97 Y3.96 S255
+M03 F1000 X44.93 Y3.81 S255
+M03 F1000 X44.91 Y3.66 S255
+M03 F1000 X44.90 Y3.50 S255
+M03 F1000 X44.91 Y3.34 S255
+M03 F1000 X44.93 Y3.19 S255
+M03 F1000 X44.97 Y3.04 S255
+M03 F1000 X45.02 Y2.89 S255
+M03 F1000 X45.09 Y2.75 S255
+M03 F1000 X45.17 Y2.61 S255
+M03 F1000 X45.26 Y2.48 S255
+M03 F1000 X45.37 Y2.37 S255
+M03 F1000 X45.48 Y2.26 S255
+M03 F1000 X45.61 Y2.17 S255
+M03 F1000 X45.75 Y2.09 S255
+M03 F1000 X45.89 Y2.02 S255
+M03 F1000 X46.04 Y1.97 S255
+M03 F1000 X46.19 Y1.93 S255
+M03 F1000 X46.34 Y1.91 S255
+M03 F1000 X46.50 Y1.90 S255
+M03 F1000 X46.66 Y1.91 S255
+M03 F1000 X46.81 Y1.93 S255
+M03 F1000 X46.96 Y1.97 S255
+M03 F1000 X47.11 Y2.02 S255
+M03 F1000 X47.25 Y2.09 S255
+M03 F1000 X47.39 Y2.17 S255
+M03 F1000 X47.52 Y2.26 S255
+M03 F1000 X47.63 Y2.37 S255
+M03 F1000 X47.74 Y2.48 S255
+M03 F1000 X47.83 Y2.61 S255
+M03 F1000 X47.91 Y2.75 S255
+M03 F1000 X47.98 Y2.89 S255
+M03 F1000 X48.03 Y3.04 S255
+M03 F1000 X48.07 Y3.19 S255
+M03 F1000 X48.09 Y3.34 S255
+M03 F1000 X48.10 Y3.50 S255
+M05 F2000 X0 Y0
\ No newline at end of file
@@ -0,0 +1,71 @@
+G01
+G00 Z2.00
+G00 X20.00 Y2.00
+G01 Z-0.50 F100
+G00 Z2.00
+G01 Z-1.00 F100
+G00 Z2.00
+G01 Z-1.50 F100
+G00 Z2.00
+G01 Z-1.60 F100
+G00 Z2.00
+G00 X24.00 Y2.00
+G01 Z-0.50 F100
+G00 Z2.00
+G01 Z-1.00 F100
+G00 Z2.00
+G01 Z-1.50 F100
+G00 Z2.00
+G01 Z-1.60 F100
+G00 Z2.00
+G00 X28.00 Y2.00
+G01 Z-0.50 F100
+G00 Z2.00
+G01 Z-1.00 F100
+G00 Z2.00
+G01 Z-1.50 F100
+G00 Z2.00
+G01 Z-1.60 F100
+G00 Z2.00
+G01
+G00 Z2.00
+G00 X5.00 Y5.00
+G01 Z-0.50 F100
+G00 Z2.00
+G01 Z-1.00 F100
+G00 Z2.00
+G01 Z-1.50 F100
+G00 Z2.00
+G01 Z-1.60 F100
+G00 Z2.00
+G00 X5.00 Y10.00
+G01 Z-0.50 F100
+G00 Z2.00
+G01 Z-1.00 F100
+G00 Z2.00
+G01 Z-1.50 F100
+G00 Z2.00
+G01 Z-1.60 F100
+G00 Z2.00
+G01
+G00 Z2.00
+G00 X3.50 Y3.50
+G01 Z-0.50 F100
+G00 Z2.00
+G01 Z-1.00 F100
+G00 Z2.00
+G01 Z-1.50 F100
+G00 Z2.00
+G01 Z-1.60 F100
+G00 Z2.00
+G00 X46.50 Y3.50
+G01 Z-0.50 F100
+G00 Z2.00
+G01 Z-1.00 F100
+G00 Z2.00
+G01 Z-1.50 F100
+G00 Z2.00
+G01 Z-1.60 F100
+G00 Z2.00
+G01
+M05 F2000 X0 Y0
\ No newline at end of file
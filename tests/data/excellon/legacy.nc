@@ -0,0 +1,18 @@
+G01
+T1 M06
+G00 Z2.00
+G81 X20.00 Y20.00 Z-1.80 R2.00 F100
+G81 X24.00 Y20.00 Z-1.80 R2.00 F100
+G80
+G01
+T2 M06
+G00 Z2.00
+G81 X25.40 Y12.70 Z-1.80 R2.00 F100
+G80
+G01
+T3 M06
+G00 Z2.00
+G81 X-1.27 Y25.40 Z-1.80 R2.00 F100
+G80
+G01
+M05 F2000 X0 Y0
\ No newline at end of file
@@ -0,0 +1,21 @@
+%
+; Exported from another CAM tool
+(Job: bracket)
+N10 G21 G90
+N20 g0 x5 y5 (move to start)
+N30 M3 S800
+N40 G1 X45.000 Y5.000 F1200
+N50 G1 Y25.000
+N60 G2 X35.000 Y35.000 I-10.000 J0.000
+N70 G1 X5.000
+N80 G3 X5 Y5 R15
+N90 M5
+N100 G91
+N110 G0 X10 Y10
+N120 M3 S400
+N130 G1 X10 F600
+N140 G1 Y10
+N150 G90 M5
+N160 G0 X0 Y0 *71
+N170 M2
+%
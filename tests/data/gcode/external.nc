@@ -0,0 +1,19 @@
+; Exported from another CAM tool
+; Job: bracket
+N10 G21 G90
+N20 G00 X5 Y5 ; move to start
+N30 M03 S800
+N40 G01 X45.000 Y5.000 F1200
+N50 G01 Y25.000
+N60 G02 X35.000 Y35.000 I-10.000 J0.000
+N70 G01 X5.000
+N80 G03 X5 Y5 R15
+N90 M05
+N100 G91
+N110 G00 X10 Y10
+N120 M03 S400
+N130 G01 X10 F600
+N140 G01 Y10
+N150 G90 M05
+N160 G00 X0 Y0
+N170 M02
@@ -0,0 +1,19 @@
+; Exported from another CAM tool
+; Job: bracket
+N10 G21 G90
+N20 G00 X25.00 Y2.50 ; move to start
+N30 M03 S800
+N40 G01 X65.000 Y2.500 F1200
+N50 G01 Y22.500
+N60 G02 X55.000 Y32.500 I-10.000 J0.000
+N70 G01 X25.000
+N80 G03 X25.00 Y2.50 R15
+N90 M05
+N100 G91
+N110 G00 X10 Y10
+N120 M03 S400
+N130 G01 X10 F600
+N140 G01 Y10
+N150 G90 M05
+N160 G00 X20.00 Y-2.50
+N170 M02
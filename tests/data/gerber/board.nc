@@ -0,0 +1,407 @@
+G01
+M05 F2000 X0.00 Y-0.25
+M03 F1000 X10.00 Y-0.25 S255
+M03 F1000 X10.10 Y-0.23 S255
+M03 F1000 X10.18 Y-0.17 S255
+M03 F1000 X10.23 Y-0.10 S255
+M03 F1000 X10.25 Y-0.01 S255
+M03 F1000 X10.25 Y5.00 S255
+M03 F1000 X10.27 Y5.45 S255
+M03 F1000 X10.30 Y5.70 S255
+M03 F1000 X10.35 Y5.95 S255
+M03 F1000 X10.45 Y6.38 S255
+M03 F1000 X10.61 Y6.83 S255
+M03 F1000 X10.80 Y7.23 S255
+M03 F1000 X10.93 Y7.44 S255
+M03 F1000 X11.06 Y7.65 S255
+M03 F1000 X11.32 Y8.01 S255
+M03 F1000 X11.63 Y8.35 S255
+M03 F1000 X11.82 Y8.53 S255
+M03 F1000 X12.15 Y8.80 S255
+M03 F1000 X12.36 Y8.95 S255
+M03 F1000 X12.75 Y9.18 S255
+M03 F1000 X12.97 Y9.30 S255
+M03 F1000 X13.40 Y9.47 S255
+M03 F1000 X13.62 Y9.55 S255
+M03 F1000 X14.05 Y9.65 S255
+M03 F1000 X14.30 Y9.70 S255
+M03 F1000 X14.78 Y9.74 S255
+M03 F1000 X18.00 Y9.75 S255
+M03 F1000 X18.10 Y9.77 S255
+M03 F1000 X18.18 Y9.82 S255
+M03 F1000 X18.23 Y9.90 S255
+M03 F1000 X18.25 Y10.01 S255
+M03 F1000 X18.23 Y10.10 S255
+M03 F1000 X18.18 Y10.18 S255
+M03 F1000 X18.10 Y10.23 S255
+M03 F1000 X18.00 Y10.25 S255
+M03 F1000 X15.00 Y10.25 S255
+M03 F1000 X14.75 Y10.24 S255
+M03 F1000 X14.47 Y10.22 S255
+M03 F1000 X14.20 Y10.19 S255
+M03 F1000 X13.75 Y10.10 S255
+M03 F1000 X13.45 Y10.02 S255
+M03 F1000 X13.00 Y9.85 S255
+M03 F1000 X12.72 Y9.73 S255
+M03 F1000 X12.30 Y9.50 S255
+M03 F1000 X11.88 Y9.22 S255
+M03 F1000 X11.66 Y9.05 S255
+M03 F1000 X11.30 Y8.72 S255
+M03 F1000 X11.10 Y8.51 S255
+M03 F1000 X10.95 Y8.34 S255
+M03 F1000 X10.78 Y8.12 S255
+M03 F1000 X10.62 Y7.90 S255
+M03 F1000 X10.38 Y7.48 S255
+M03 F1000 X10.25 Y7.24 S255
+M03 F1000 X10.06 Y6.78 S255
+M03 F1000 X9.97 Y6.52 S255
+M03 F1000 X9.90 Y6.25 S255
+M03 F1000 X9.81 Y5.78 S255
+M03 F1000 X9.77 Y5.50 S255
+M03 F1000 X9.75 Y5.01 S255
+M03 F1000 X9.75 Y0.25 S255
+M03 F1000 X0.00 Y0.25 S255
+M03 F1000 X-0.10 Y0.23 S255
+M03 F1000 X-0.18 Y0.18 S255
+M03 F1000 X-0.23 Y0.10 S255
+M03 F1000 X-0.25 Y-0.01 S255
+M03 F1000 X-0.23 Y-0.10 S255
+M03 F1000 X-0.17 Y-0.18 S255
+M03 F1000 X-0.10 Y-0.23 S255
+M03 F1000 X0.00 Y-0.25 S255
+M05 F2000 X23.85 Y1.02
+M03 F1000 X24.00 Y1.00 S255
+M03 F1000 X24.15 Y1.02 S255
+M03 F1000 X24.30 Y1.10 S255
+M03 F1000 X24.41 Y1.23 S255
+M03 F1000 X24.48 Y1.34 S255
+M03 F1000 X24.50 Y1.49 S255
+M03 F1000 X24.50 Y2.51 S255
+M03 F1000 X24.48 Y2.66 S255
+M03 F1000 X24.40 Y2.80 S255
+M03 F1000 X24.30 Y2.90 S255
+M03 F1000 X24.16 Y2.98 S255
+M03 F1000 X24.00 Y3.00 S255
+M03 F1000 X23.84 Y2.98 S255
+M03 F1000 X23.70 Y2.90 S255
+M03 F1000 X23.59 Y2.78 S255
+M03 F1000 X23.53 Y2.66 S255
+M03 F1000 X23.50 Y2.51 S255
+M03 F1000 X23.50 Y1.50 S255
+M03 F1000 X23.53 Y1.34 S255
+M03 F1000 X23.60 Y1.20 S255
+M03 F1000 X23.70 Y1.10 S255
+M03 F1000 X23.85 Y1.02 S255
+M05 F2000 X27.62 Y1.35
+M03 F1000 X28.38 Y1.35 S255
+M03 F1000 X28.75 Y1.99 S255
+M03 F1000 X28.75 Y2.01 S255
+M03 F1000 X28.38 Y2.65 S255
+M03 F1000 X27.62 Y2.65 S255
+M03 F1000 X27.25 Y2.01 S255
+M03 F1000 X27.25 Y1.99 S255
+M03 F1000 X27.62 Y1.35 S255
+M05 F2000 X19.20 Y1.40
+M03 F1000 X20.78 Y1.40 S255
+M03 F1000 X20.80 Y1.39 S255
+M03 F1000 X20.80 Y2.59 S255
+M03 F1000 X20.78 Y2.60 S255
+M03 F1000 X19.20 Y2.60 S255
+M03 F1000 X19.20 Y1.40 S255
+M05 F2000 X29.38 Y7.08
+M03 F1000 X29.40 Y7.08 S255
+M03 F1000 X30.98 Y7.99 S255
+M03 F1000 X31.00 Y7.99 S255
+M03 F1000 X31.00 Y8.00 S255
+M03 F1000 X31.10 Y8.06 S255
+M03 F1000 X31.11 Y8.07 S255
+M03 F1000 X30.62 Y8.92 S255
+M03 F1000 X30.60 Y8.92 S255
+M03 F1000 X28.90 Y7.94 S255
+M03 F1000 X28.89 Y7.92 S255
+M03 F1000 X29.38 Y7.08 S255
+M05 F2000 X24.82 Y7.22
+M03 F1000 X25.00 Y7.20 S255
+M03 F1000 X25.12 Y7.21 S255
+M03 F1000 X25.23 Y7.23 S255
+M03 F1000 X25.35 Y7.28 S255
+M03 F1000 X25.43 Y7.33 S255
+M03 F1000 X25.44 Y7.35 S255
+M03 F1000 X25.24 Y7.55 S255
+M03 F1000 X25.23 Y7.55 S255
+M03 F1000 X25.10 Y7.51 S255
+M03 F1000 X25.00 Y7.50 S255
+M03 F1000 X24.88 Y7.52 S255
+M03 F1000 X24.78 Y7.55 S255
+M03 F1000 X24.56 Y7.35 S255
+M03 F1000 X24.57 Y7.33 S255
+M03 F1000 X24.65 Y7.28 S255
+M03 F1000 X24.82 Y7.22 S255
+M05 F2000 X19.85 Y7.42
+M03 F1000 X20.00 Y7.40 S255
+M03 F1000 X20.15 Y7.42 S255
+M03 F1000 X20.30 Y7.48 S255
+M03 F1000 X20.42 Y7.58 S255
+M03 F1000 X20.52 Y7.70 S255
+M03 F1000 X20.58 Y7.85 S255
+M03 F1000 X20.60 Y8.01 S255
+M03 F1000 X20.58 Y8.15 S255
+M03 F1000 X20.52 Y8.30 S255
+M03 F1000 X20.42 Y8.42 S255
+M03 F1000 X20.30 Y8.52 S255
+M03 F1000 X20.15 Y8.58 S255
+M03 F1000 X20.00 Y8.60 S255
+M03 F1000 X19.85 Y8.58 S255
+M03 F1000 X19.70 Y8.52 S255
+M03 F1000 X19.58 Y8.42 S255
+M03 F1000 X19.48 Y8.30 S255
+M03 F1000 X19.42 Y8.15 S255
+M03 F1000 X19.40 Y7.99 S255
+M03 F1000 X19.42 Y7.85 S255
+M03 F1000 X19.48 Y7.70 S255
+M03 F1000 X19.58 Y7.58 S255
+M03 F1000 X19.70 Y7.48 S255
+M03 F1000 X19.85 Y7.42 S255
+M05 F2000 X24.32 Y7.57
+M03 F1000 X24.35 Y7.56 S255
+M03 F1000 X24.55 Y7.78 S255
+M03 F1000 X24.52 Y7.88 S255
+M03 F1000 X24.50 Y8.00 S255
+M03 F1000 X24.51 Y8.10 S255
+M03 F1000 X24.55 Y8.22 S255
+M03 F1000 X24.55 Y8.24 S255
+M03 F1000 X24.35 Y8.44 S255
+M03 F1000 X24.32 Y8.43 S255
+M03 F1000 X24.28 Y8.35 S255
+M03 F1000 X24.24 Y8.25 S255
+M03 F1000 X24.21 Y8.12 S255
+M03 F1000 X24.20 Y8.01 S255
+M03 F1000 X24.21 Y7.88 S255
+M03 F1000 X24.23 Y7.78 S255
+M03 F1000 X24.28 Y7.65 S255
+M03 F1000 X24.32 Y7.57 S255
+M05 F2000 X25.65 Y7.56
+M03 F1000 X25.68 Y7.57 S255
+M03 F1000 X25.72 Y7.65 S255
+M03 F1000 X25.76 Y7.75 S255
+M03 F1000 X25.79 Y7.88 S255
+M03 F1000 X25.80 Y8.01 S255
+M03 F1000 X25.79 Y8.12 S255
+M03 F1000 X25.76 Y8.25 S255
+M03 F1000 X25.72 Y8.35 S255
+M03 F1000 X25.68 Y8.43 S255
+M03 F1000 X25.65 Y8.44 S255
+M03 F1000 X25.45 Y8.24 S255
+M03 F1000 X25.45 Y8.22 S255
+M03 F1000 X25.49 Y8.10 S255
+M03 F1000 X25.50 Y8.00 S255
+M03 F1000 X25.48 Y7.88 S255
+M03 F1000 X25.45 Y7.78 S255
+M03 F1000 X25.65 Y7.56 S255
+M05 F2000 X29.90 Y7.72
+M03 F1000 X29.80 Y7.78 S255
+M03 F1000 X29.73 Y7.88 S255
+M03 F1000 X29.70 Y8.00 S255
+M03 F1000 X29.73 Y8.12 S255
+M03 F1000 X29.80 Y8.22 S255
+M03 F1000 X29.88 Y8.28 S255
+M03 F1000 X30.00 Y8.30 S255
+M03 F1000 X30.12 Y8.28 S255
+M03 F1000 X30.20 Y8.22 S255
+M03 F1000 X30.28 Y8.12 S255
+M03 F1000 X30.30 Y8.00 S255
+M03 F1000 X30.28 Y7.88 S255
+M03 F1000 X30.20 Y7.78 S255
+M03 F1000 X30.12 Y7.73 S255
+M03 F1000 X30.00 Y7.70 S255
+M03 F1000 X29.90 Y7.72 S255
+M05 F2000 X20.00 Y7.75
+M03 F1000 X19.90 Y7.77 S255
+M03 F1000 X19.82 Y7.83 S255
+M03 F1000 X19.77 Y7.90 S255
+M03 F1000 X19.75 Y8.00 S255
+M03 F1000 X19.77 Y8.10 S255
+M03 F1000 X19.82 Y8.18 S255
+M03 F1000 X19.90 Y8.23 S255
+M03 F1000 X20.00 Y8.25 S255
+M03 F1000 X20.10 Y8.23 S255
+M03 F1000 X20.18 Y8.18 S255
+M03 F1000 X20.23 Y8.10 S255
+M03 F1000 X20.25 Y8.00 S255
+M03 F1000 X20.23 Y7.90 S255
+M03 F1000 X20.18 Y7.82 S255
+M03 F1000 X20.10 Y7.77 S255
+M03 F1000 X20.00 Y7.75 S255
+M05 F2000 X24.78 Y8.45
+M03 F1000 X24.88 Y8.48 S255
+M03 F1000 X25.00 Y8.50 S255
+M03 F1000 X25.10 Y8.49 S255
+M03 F1000 X25.23 Y8.45 S255
+M03 F1000 X25.24 Y8.45 S255
+M03 F1000 X25.44 Y8.65 S255
+M03 F1000 X25.43 Y8.67 S255
+M03 F1000 X25.35 Y8.72 S255
+M03 F1000 X25.23 Y8.77 S255
+M03 F1000 X25.12 Y8.79 S255
+M03 F1000 X25.00 Y8.80 S255
+M03 F1000 X24.88 Y8.79 S255
+M03 F1000 X24.78 Y8.77 S255
+M03 F1000 X24.65 Y8.72 S255
+M03 F1000 X24.57 Y8.67 S255
+M03 F1000 X24.56 Y8.65 S255
+M03 F1000 X24.78 Y8.45 S255
+M05 F2000 X0.00 Y12.00
+M03 F1000 X9.97 Y12.00 S255
+M03 F1000 X10.00 Y11.99 S255
+M03 F1000 X10.00 Y17.99 S255
+M03 F1000 X9.97 Y18.00 S255
+M03 F1000 X0.00 Y18.00 S255
+M03 F1000 X0.00 Y12.00 S255
+M05 F2000 X14.85 Y14.42
+M03 F1000 X15.00 Y14.40 S255
+M03 F1000 X15.15 Y14.42 S255
+M03 F1000 X15.30 Y14.48 S255
+M03 F1000 X15.43 Y14.58 S255
+M03 F1000 X15.52 Y14.70 S255
+M03 F1000 X15.58 Y14.85 S255
+M03 F1000 X15.60 Y15.01 S255
+M03 F1000 X15.58 Y15.15 S255
+M03 F1000 X15.52 Y15.30 S255
+M03 F1000 X15.42 Y15.43 S255
+M03 F1000 X15.30 Y15.52 S255
+M03 F1000 X15.15 Y15.58 S255
+M03 F1000 X15.00 Y15.60 S255
+M03 F1000 X14.85 Y15.58 S255
+M03 F1000 X14.70 Y15.52 S255
+M03 F1000 X14.57 Y15.42 S255
+M03 F1000 X14.48 Y15.30 S255
+M03 F1000 X14.42 Y15.15 S255
+M03 F1000 X14.40 Y14.99 S255
+M03 F1000 X14.42 Y14.85 S255
+M03 F1000 X14.48 Y14.70 S255
+M03 F1000 X14.58 Y14.57 S255
+M03 F1000 X14.70 Y14.48 S255
+M03 F1000 X14.85 Y14.42 S255
+M05 F2000 X18.85 Y14.42
+M03 F1000 X19.00 Y14.40 S255
+M03 F1000 X19.15 Y14.42 S255
+M03 F1000 X19.30 Y14.48 S255
+M03 F1000 X19.42 Y14.57 S255
+M03 F1000 X19.52 Y14.70 S255
+M03 F1000 X19.58 Y14.85 S255
+M03 F1000 X19.60 Y15.01 S255
+M03 F1000 X19.58 Y15.15 S255
+M03 F1000 X19.52 Y15.30 S255
+M03 F1000 X19.42 Y15.43 S255
+M03 F1000 X19.30 Y15.52 S255
+M03 F1000 X19.15 Y15.58 S255
+M03 F1000 X19.00 Y15.60 S255
+M03 F1000 X18.85 Y15.58 S255
+M03 F1000 X18.70 Y15.52 S255
+M03 F1000 X18.57 Y15.42 S255
+M03 F1000 X18.48 Y15.30 S255
+M03 F1000 X18.42 Y15.15 S255
+M03 F1000 X18.40 Y14.99 S255
+M03 F1000 X18.42 Y14.85 S255
+M03 F1000 X18.48 Y14.70 S255
+M03 F1000 X18.58 Y14.57 S255
+M03 F1000 X18.70 Y14.48 S255
+M03 F1000 X18.85 Y14.42 S255
+M05 F2000 X22.85 Y14.42
+M03 F1000 X23.00 Y14.40 S255
+M03 F1000 X23.15 Y14.42 S255
+M03 F1000 X23.30 Y14.48 S255
+M03 F1000 X23.42 Y14.57 S255
+M03 F1000 X23.52 Y14.70 S255
+M03 F1000 X23.58 Y14.85 S255
+M03 F1000 X23.60 Y15.01 S255
+M03 F1000 X23.58 Y15.15 S255
+M03 F1000 X23.52 Y15.30 S255
+M03 F1000 X23.42 Y15.43 S255
+M03 F1000 X23.30 Y15.52 S255
+M03 F1000 X23.15 Y15.58 S255
+M03 F1000 X23.00 Y15.60 S255
+M03 F1000 X22.85 Y15.58 S255
+M03 F1000 X22.70 Y15.52 S255
+M03 F1000 X22.57 Y15.42 S255
+M03 F1000 X22.48 Y15.30 S255
+M03 F1000 X22.42 Y15.15 S255
+M03 F1000 X22.40 Y14.99 S255
+M03 F1000 X22.42 Y14.85 S255
+M03 F1000 X22.48 Y14.70 S255
+M03 F1000 X22.58 Y14.57 S255
+M03 F1000 X22.70 Y14.48 S255
+M03 F1000 X22.85 Y14.42 S255
+M05 F2000 X2.00 Y14.75
+M03 F1000 X1.90 Y14.77 S255
+M03 F1000 X1.82 Y14.82 S255
+M03 F1000 X1.77 Y14.90 S255
+M03 F1000 X1.75 Y14.97 S255
+M03 F1000 X1.74 Y15.00 S255
+M03 F1000 X1.75 Y15.03 S255
+M03 F1000 X1.77 Y15.10 S255
+M03 F1000 X1.82 Y15.18 S255
+M03 F1000 X1.90 Y15.23 S255
+M03 F1000 X2.00 Y15.25 S255
+M03 F1000 X8.00 Y15.25 S255
+M03 F1000 X8.10 Y15.23 S255
+M03 F1000 X8.18 Y15.18 S255
+M03 F1000 X8.23 Y15.10 S255
+M03 F1000 X8.25 Y15.00 S255
+M03 F1000 X8.23 Y14.90 S255
+M03 F1000 X8.17 Y14.82 S255
+M03 F1000 X8.10 Y14.77 S255
+M03 F1000 X8.00 Y14.75 S255
+M03 F1000 X2.00 Y14.75 S255
+M05 F2000 X14.90 Y14.77
+M03 F1000 X14.82 Y14.82 S255
+M03 F1000 X14.77 Y14.90 S255
+M03 F1000 X14.75 Y15.00 S255
+M03 F1000 X14.77 Y15.10 S255
+M03 F1000 X14.82 Y15.18 S255
+M03 F1000 X14.90 Y15.23 S255
+M03 F1000 X15.00 Y15.25 S255
+M03 F1000 X15.10 Y15.23 S255
+M03 F1000 X15.18 Y15.18 S255
+M03 F1000 X15.23 Y15.10 S255
+M03 F1000 X15.25 Y15.00 S255
+M03 F1000 X15.23 Y14.90 S255
+M03 F1000 X15.18 Y14.82 S255
+M03 F1000 X15.10 Y14.77 S255
+M03 F1000 X15.00 Y14.75 S255
+M03 F1000 X14.90 Y14.77 S255
+M05 F2000 X18.90 Y14.77
+M03 F1000 X18.82 Y14.82 S255
+M03 F1000 X18.77 Y14.90 S255
+M03 F1000 X18.75 Y15.00 S255
+M03 F1000 X18.77 Y15.10 S255
+M03 F1000 X18.82 Y15.18 S255
+M03 F1000 X18.90 Y15.23 S255
+M03 F1000 X19.00 Y15.25 S255
+M03 F1000 X19.10 Y15.23 S255
+M03 F1000 X19.18 Y15.18 S255
+M03 F1000 X19.23 Y15.10 S255
+M03 F1000 X19.25 Y15.00 S255
+M03 F1000 X19.23 Y14.90 S255
+M03 F1000 X19.18 Y14.82 S255
+M03 F1000 X19.10 Y14.77 S255
+M03 F1000 X19.00 Y14.75 S255
+M03 F1000 X18.90 Y14.77 S255
+M05 F2000 X22.90 Y14.77
+M03 F1000 X22.82 Y14.82 S255
+M03 F1000 X22.77 Y14.90 S255
+M03 F1000 X22.75 Y15.00 S255
+M03 F1000 X22.77 Y15.10 S255
+M03 F1000 X22.82 Y15.18 S255
+M03 F1000 X22.90 Y15.23 S255
+M03 F1000 X23.00 Y15.25 S255
+M03 F1000 X23.10 Y15.23 S255
+M03 F1000 X23.18 Y15.18 S255
+M03 F1000 X23.23 Y15.10 S255
+M03 F1000 X23.25 Y15.00 S255
+M03 F1000 X23.23 Y14.90 S255
+M03 F1000 X23.18 Y14.82 S255
+M03 F1000 X23.10 Y14.77 S255
+M03 F1000 X23.00 Y14.75 S255
+M03 F1000 X22.90 Y14.77 S255
+M05 F2000 X0 Y0
\ No newline at end of file
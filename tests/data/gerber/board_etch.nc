@@ -0,0 +1,326 @@
+G01
+M05 F2000 X-0.23 Y-0.10
+M03 F1000 X10.23 Y-0.10 S255
+M05 F2000 X10.25 Y0.10
+M03 F1000 X-0.23 Y0.10 S255
+M05 F2000 X9.75 Y0.30
+M03 F1000 X10.25 Y0.30 S255
+M05 F2000 X10.25 Y0.50
+M03 F1000 X9.75 Y0.50 S255
+M05 F2000 X9.75 Y0.70
+M03 F1000 X10.25 Y0.70 S255
+M05 F2000 X10.25 Y0.90
+M03 F1000 X9.75 Y0.90 S255
+M05 F2000 X9.75 Y1.10
+M03 F1000 X10.25 Y1.10 S255
+M05 F2000 X23.70 Y1.10
+M03 F1000 X24.30 Y1.10 S255
+M05 F2000 X24.45 Y1.30
+M03 F1000 X23.55 Y1.30 S255
+M05 F2000 X10.25 Y1.30
+M03 F1000 X9.75 Y1.30 S255
+M05 F2000 X9.75 Y1.50
+M03 F1000 X10.25 Y1.50 S255
+M05 F2000 X19.20 Y1.50
+M03 F1000 X20.80 Y1.50 S255
+M05 F2000 X23.50 Y1.50
+M03 F1000 X24.50 Y1.50 S255
+M05 F2000 X27.54 Y1.50
+M03 F1000 X28.46 Y1.50 S255
+M05 F2000 X28.58 Y1.70
+M03 F1000 X27.42 Y1.70 S255
+M05 F2000 X24.50 Y1.70
+M03 F1000 X23.50 Y1.70 S255
+M05 F2000 X20.80 Y1.70
+M03 F1000 X19.20 Y1.70 S255
+M05 F2000 X10.25 Y1.70
+M03 F1000 X9.75 Y1.70 S255
+M05 F2000 X9.75 Y1.90
+M03 F1000 X10.25 Y1.90 S255
+M05 F2000 X19.20 Y1.90
+M03 F1000 X20.80 Y1.90 S255
+M05 F2000 X23.50 Y1.90
+M03 F1000 X24.50 Y1.90 S255
+M05 F2000 X27.31 Y1.90
+M03 F1000 X28.69 Y1.90 S255
+M05 F2000 X28.69 Y2.10
+M03 F1000 X27.31 Y2.10 S255
+M05 F2000 X24.50 Y2.10
+M03 F1000 X23.50 Y2.10 S255
+M05 F2000 X20.80 Y2.10
+M03 F1000 X19.20 Y2.10 S255
+M05 F2000 X10.25 Y2.10
+M03 F1000 X9.75 Y2.10 S255
+M05 F2000 X9.75 Y2.30
+M03 F1000 X10.25 Y2.30 S255
+M05 F2000 X19.20 Y2.30
+M03 F1000 X20.80 Y2.30 S255
+M05 F2000 X23.50 Y2.30
+M03 F1000 X24.50 Y2.30 S255
+M05 F2000 X27.42 Y2.30
+M03 F1000 X28.58 Y2.30 S255
+M05 F2000 X28.46 Y2.50
+M03 F1000 X27.54 Y2.50 S255
+M05 F2000 X24.50 Y2.50
+M03 F1000 X23.50 Y2.50 S255
+M05 F2000 X20.80 Y2.50
+M03 F1000 X19.20 Y2.50 S255
+M05 F2000 X10.25 Y2.50
+M03 F1000 X9.75 Y2.50 S255
+M05 F2000 X9.75 Y2.70
+M03 F1000 X10.25 Y2.70 S255
+M05 F2000 X23.55 Y2.70
+M03 F1000 X24.45 Y2.70 S255
+M05 F2000 X24.30 Y2.90
+M03 F1000 X23.70 Y2.90 S255
+M05 F2000 X10.25 Y2.90
+M03 F1000 X9.75 Y2.90 S255
+M05 F2000 X9.75 Y3.10
+M03 F1000 X10.25 Y3.10 S255
+M05 F2000 X10.25 Y3.30
+M03 F1000 X9.75 Y3.30 S255
+M05 F2000 X9.75 Y3.50
+M03 F1000 X10.25 Y3.50 S255
+M05 F2000 X10.25 Y3.70
+M03 F1000 X9.75 Y3.70 S255
+M05 F2000 X9.75 Y3.90
+M03 F1000 X10.25 Y3.90 S255
+M05 F2000 X10.25 Y4.10
+M03 F1000 X9.75 Y4.10 S255
+M05 F2000 X9.75 Y4.30
+M03 F1000 X10.25 Y4.30 S255
+M05 F2000 X10.25 Y4.50
+M03 F1000 X9.75 Y4.50 S255
+M05 F2000 X9.75 Y4.70
+M03 F1000 X10.25 Y4.70 S255
+M05 F2000 X10.25 Y4.90
+M03 F1000 X9.75 Y4.90 S255
+M05 F2000 X9.75 Y5.10
+M03 F1000 X10.25 Y5.10 S255
+M05 F2000 X10.26 Y5.30
+M03 F1000 X9.76 Y5.30 S255
+M05 F2000 X9.77 Y5.50
+M03 F1000 X10.28 Y5.50 S255
+M05 F2000 X10.30 Y5.70
+M03 F1000 X9.80 Y5.70 S255
+M05 F2000 X9.83 Y5.90
+M03 F1000 X10.34 Y5.90 S255
+M05 F2000 X10.38 Y6.10
+M03 F1000 X9.87 Y6.10 S255
+M05 F2000 X9.91 Y6.30
+M03 F1000 X10.43 Y6.30 S255
+M05 F2000 X10.49 Y6.50
+M03 F1000 X9.97 Y6.50 S255
+M05 F2000 X10.03 Y6.70
+M03 F1000 X10.57 Y6.70 S255
+M05 F2000 X10.65 Y6.90
+M03 F1000 X10.11 Y6.90 S255
+M05 F2000 X10.19 Y7.10
+M03 F1000 X10.74 Y7.10 S255
+M05 F2000 X29.36 Y7.10
+M03 F1000 X29.44 Y7.10 S255
+M05 F2000 X29.79 Y7.30
+M03 F1000 X29.25 Y7.30 S255
+M05 F2000 X25.38 Y7.30
+M03 F1000 X24.62 Y7.30 S255
+M05 F2000 X10.85 Y7.30
+M03 F1000 X10.28 Y7.30 S255
+M05 F2000 X10.38 Y7.50
+M03 F1000 X10.96 Y7.50 S255
+M05 F2000 X19.67 Y7.50
+M03 F1000 X20.33 Y7.50 S255
+M05 F2000 X24.71 Y7.50
+M03 F1000 X25.29 Y7.50 S255
+M05 F2000 X29.13 Y7.50
+M03 F1000 X30.13 Y7.50 S255
+M05 F2000 X30.48 Y7.70
+M03 F1000 X29.02 Y7.70 S255
+M05 F2000 X25.74 Y7.70
+M03 F1000 X25.51 Y7.70 S255
+M05 F2000 X24.49 Y7.70
+M03 F1000 X24.26 Y7.70 S255
+M05 F2000 X20.52 Y7.70
+M03 F1000 X19.48 Y7.70 S255
+M05 F2000 X11.09 Y7.70
+M03 F1000 X10.50 Y7.70 S255
+M05 F2000 X10.62 Y7.90
+M03 F1000 X11.24 Y7.90 S255
+M05 F2000 X19.41 Y7.90
+M03 F1000 X19.77 Y7.90 S255
+M05 F2000 X20.23 Y7.90
+M03 F1000 X20.59 Y7.90 S255
+M05 F2000 X24.21 Y7.90
+M03 F1000 X24.51 Y7.90 S255
+M05 F2000 X25.49 Y7.90
+M03 F1000 X25.79 Y7.90 S255
+M05 F2000 X28.90 Y7.90
+M03 F1000 X29.72 Y7.90 S255
+M05 F2000 X30.28 Y7.90
+M03 F1000 X30.83 Y7.90 S255
+M05 F2000 X31.10 Y8.10
+M03 F1000 X30.28 Y8.10 S255
+M05 F2000 X29.72 Y8.10
+M03 F1000 X29.17 Y8.10 S255
+M05 F2000 X25.79 Y8.10
+M03 F1000 X25.49 Y8.10 S255
+M05 F2000 X24.51 Y8.10
+M03 F1000 X24.21 Y8.10 S255
+M05 F2000 X20.59 Y8.10
+M03 F1000 X20.23 Y8.10 S255
+M05 F2000 X19.77 Y8.10
+M03 F1000 X19.41 Y8.10 S255
+M05 F2000 X11.40 Y8.10
+M03 F1000 X10.76 Y8.10 S255
+M05 F2000 X10.92 Y8.30
+M03 F1000 X11.59 Y8.30 S255
+M05 F2000 X19.48 Y8.30
+M03 F1000 X20.52 Y8.30 S255
+M05 F2000 X24.26 Y8.30
+M03 F1000 X24.49 Y8.30 S255
+M05 F2000 X25.51 Y8.30
+M03 F1000 X25.74 Y8.30 S255
+M05 F2000 X29.52 Y8.30
+M03 F1000 X30.98 Y8.30 S255
+M05 F2000 X30.87 Y8.50
+M03 F1000 X29.87 Y8.50 S255
+M05 F2000 X25.29 Y8.50
+M03 F1000 X24.71 Y8.50 S255
+M05 F2000 X20.33 Y8.50
+M03 F1000 X19.67 Y8.50 S255
+M05 F2000 X11.79 Y8.50
+M03 F1000 X11.09 Y8.50 S255
+M05 F2000 X11.28 Y8.70
+M03 F1000 X12.02 Y8.70 S255
+M05 F2000 X24.62 Y8.70
+M03 F1000 X25.38 Y8.70 S255
+M05 F2000 X30.21 Y8.70
+M03 F1000 X30.75 Y8.70 S255
+M05 F2000 X30.64 Y8.90
+M03 F1000 X30.56 Y8.90 S255
+M05 F2000 X12.29 Y8.90
+M03 F1000 X11.49 Y8.90 S255
+M05 F2000 X11.72 Y9.10
+M03 F1000 X12.60 Y9.10 S255
+M05 F2000 X12.98 Y9.30
+M03 F1000 X11.99 Y9.30 S255
+M05 F2000 X12.30 Y9.50
+M03 F1000 X13.48 Y9.50 S255
+M05 F2000 X14.32 Y9.70
+M03 F1000 X12.66 Y9.70 S255
+M05 F2000 X13.12 Y9.90
+M03 F1000 X18.23 Y9.90 S255
+M05 F2000 X18.23 Y10.10
+M03 F1000 X13.76 Y10.10 S255
+M05 F2000 X0.00 Y12.10
+M03 F1000 X10.00 Y12.10 S255
+M05 F2000 X10.00 Y12.30
+M03 F1000 X0.00 Y12.30 S255
+M05 F2000 X0.00 Y12.50
+M03 F1000 X10.00 Y12.50 S255
+M05 F2000 X10.00 Y12.70
+M03 F1000 X0.00 Y12.70 S255
+M05 F2000 X0.00 Y12.90
+M03 F1000 X10.00 Y12.90 S255
+M05 F2000 X10.00 Y13.10
+M03 F1000 X0.00 Y13.10 S255
+M05 F2000 X0.00 Y13.30
+M03 F1000 X10.00 Y13.30 S255
+M05 F2000 X10.00 Y13.50
+M03 F1000 X0.00 Y13.50 S255
+M05 F2000 X0.00 Y13.70
+M03 F1000 X10.00 Y13.70 S255
+M05 F2000 X10.00 Y13.90
+M03 F1000 X0.00 Y13.90 S255
+M05 F2000 X0.00 Y14.10
+M03 F1000 X10.00 Y14.10 S255
+M05 F2000 X10.00 Y14.30
+M03 F1000 X0.00 Y14.30 S255
+M05 F2000 X0.00 Y14.50
+M03 F1000 X10.00 Y14.50 S255
+M05 F2000 X14.67 Y14.50
+M03 F1000 X15.33 Y14.50 S255
+M05 F2000 X18.67 Y14.50
+M03 F1000 X19.33 Y14.50 S255
+M05 F2000 X22.67 Y14.50
+M03 F1000 X23.33 Y14.50 S255
+M05 F2000 X23.52 Y14.70
+M03 F1000 X22.48 Y14.70 S255
+M05 F2000 X19.52 Y14.70
+M03 F1000 X18.48 Y14.70 S255
+M05 F2000 X15.52 Y14.70
+M03 F1000 X14.48 Y14.70 S255
+M05 F2000 X10.00 Y14.70
+M03 F1000 X0.00 Y14.70 S255
+M05 F2000 X0.00 Y14.90
+M03 F1000 X1.77 Y14.90 S255
+M05 F2000 X8.23 Y14.90
+M03 F1000 X10.00 Y14.90 S255
+M05 F2000 X14.41 Y14.90
+M03 F1000 X14.77 Y14.90 S255
+M05 F2000 X15.23 Y14.90
+M03 F1000 X15.59 Y14.90 S255
+M05 F2000 X18.41 Y14.90
+M03 F1000 X18.77 Y14.90 S255
+M05 F2000 X19.23 Y14.90
+M03 F1000 X19.59 Y14.90 S255
+M05 F2000 X22.41 Y14.90
+M03 F1000 X22.77 Y14.90 S255
+M05 F2000 X23.23 Y14.90
+M03 F1000 X23.59 Y14.90 S255
+M05 F2000 X23.59 Y15.10
+M03 F1000 X23.23 Y15.10 S255
+M05 F2000 X22.77 Y15.10
+M03 F1000 X22.41 Y15.10 S255
+M05 F2000 X19.59 Y15.10
+M03 F1000 X19.23 Y15.10 S255
+M05 F2000 X18.77 Y15.10
+M03 F1000 X18.41 Y15.10 S255
+M05 F2000 X15.59 Y15.10
+M03 F1000 X15.23 Y15.10 S255
+M05 F2000 X14.77 Y15.10
+M03 F1000 X14.41 Y15.10 S255
+M05 F2000 X10.00 Y15.10
+M03 F1000 X8.23 Y15.10 S255
+M05 F2000 X1.77 Y15.10
+M03 F1000 X0.00 Y15.10 S255
+M05 F2000 X0.00 Y15.30
+M03 F1000 X10.00 Y15.30 S255
+M05 F2000 X14.48 Y15.30
+M03 F1000 X15.52 Y15.30 S255
+M05 F2000 X18.48 Y15.30
+M03 F1000 X19.52 Y15.30 S255
+M05 F2000 X22.48 Y15.30
+M03 F1000 X23.52 Y15.30 S255
+M05 F2000 X23.33 Y15.50
+M03 F1000 X22.67 Y15.50 S255
+M05 F2000 X19.33 Y15.50
+M03 F1000 X18.67 Y15.50 S255
+M05 F2000 X15.33 Y15.50
+M03 F1000 X14.67 Y15.50 S255
+M05 F2000 X10.00 Y15.50
+M03 F1000 X0.00 Y15.50 S255
+M05 F2000 X0.00 Y15.70
+M03 F1000 X10.00 Y15.70 S255
+M05 F2000 X10.00 Y15.90
+M03 F1000 X0.00 Y15.90 S255
+M05 F2000 X0.00 Y16.10
+M03 F1000 X10.00 Y16.10 S255
+M05 F2000 X10.00 Y16.30
+M03 F1000 X0.00 Y16.30 S255
+M05 F2000 X0.00 Y16.50
+M03 F1000 X10.00 Y16.50 S255
+M05 F2000 X10.00 Y16.70
+M03 F1000 X0.00 Y16.70 S255
+M05 F2000 X0.00 Y16.90
+M03 F1000 X10.00 Y16.90 S255
+M05 F2000 X10.00 Y17.10
+M03 F1000 X0.00 Y17.10 S255
+M05 F2000 X0.00 Y17.30
+M03 F1000 X10.00 Y17.30 S255
+M05 F2000 X10.00 Y17.50
+M03 F1000 X0.00 Y17.50 S255
+M05 F2000 X0.00 Y17.70
+M03 F1000 X10.00 Y17.70 S255
+M05 F2000 X10.00 Y17.90
+M03 F1000 X0.00 Y17.90 S255
+M05 F2000 X0 Y0
\ No newline at end of file
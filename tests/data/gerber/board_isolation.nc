@@ -0,0 +1,825 @@
+G01
+M05 F2000 X0.00 Y-0.35
+M03 F1000 X10.00 Y-0.35 S255
+M03 F1000 X10.15 Y-0.31 S255
+M03 F1000 X10.24 Y-0.25 S255
+M03 F1000 X10.31 Y-0.15 S255
+M03 F1000 X10.35 Y-0.01 S255
+M03 F1000 X10.35 Y5.00 S255
+M03 F1000 X10.36 Y5.25 S255
+M03 F1000 X10.40 Y5.68 S255
+M03 F1000 X10.45 Y5.97 S255
+M03 F1000 X10.53 Y6.27 S255
+M03 F1000 X10.62 Y6.59 S255
+M03 F1000 X10.65 Y6.64 S255
+M03 F1000 X10.68 Y6.72 S255
+M03 F1000 X10.90 Y7.20 S255
+M03 F1000 X11.00 Y7.37 S255
+M03 F1000 X11.12 Y7.57 S255
+M03 F1000 X11.29 Y7.80 S255
+M03 F1000 X11.53 Y8.10 S255
+M03 F1000 X11.72 Y8.30 S255
+M03 F1000 X12.05 Y8.60 S255
+M03 F1000 X12.22 Y8.73 S255
+M03 F1000 X12.63 Y9.00 S255
+M03 F1000 X12.83 Y9.11 S255
+M03 F1000 X12.85 Y9.12 S255
+M03 F1000 X12.85 Y9.14 S255
+M03 F1000 X12.88 Y9.13 S255
+M03 F1000 X12.90 Y9.15 S255
+M03 F1000 X12.90 Y9.16 S255
+M03 F1000 X12.93 Y9.16 S255
+M03 F1000 X12.95 Y9.18 S255
+M03 F1000 X12.95 Y9.19 S255
+M03 F1000 X12.97 Y9.19 S255
+M03 F1000 X13.00 Y9.20 S255
+M03 F1000 X13.00 Y9.21 S255
+M03 F1000 X13.03 Y9.21 S255
+M03 F1000 X13.05 Y9.22 S255
+M03 F1000 X13.05 Y9.24 S255
+M03 F1000 X13.08 Y9.23 S255
+M03 F1000 X13.20 Y9.29 S255
+M03 F1000 X13.22 Y9.31 S255
+M03 F1000 X13.25 Y9.31 S255
+M03 F1000 X13.33 Y9.34 S255
+M03 F1000 X13.35 Y9.36 S255
+M03 F1000 X13.38 Y9.36 S255
+M03 F1000 X13.41 Y9.38 S255
+M03 F1000 X13.62 Y9.44 S255
+M03 F1000 X13.65 Y9.46 S255
+M03 F1000 X13.68 Y9.46 S255
+M03 F1000 X13.80 Y9.49 S255
+M03 F1000 X13.83 Y9.51 S255
+M03 F1000 X13.85 Y9.50 S255
+M03 F1000 X13.90 Y9.52 S255
+M03 F1000 X13.92 Y9.53 S255
+M03 F1000 X13.93 Y9.54 S255
+M03 F1000 X13.95 Y9.53 S255
+M03 F1000 X14.32 Y9.60 S255
+M03 F1000 X14.75 Y9.64 S255
+M03 F1000 X18.03 Y9.65 S255
+M03 F1000 X18.15 Y9.69 S255
+M03 F1000 X18.23 Y9.74 S255
+M03 F1000 X18.25 Y9.76 S255
+M03 F1000 X18.31 Y9.85 S255
+M03 F1000 X18.35 Y10.01 S255
+M03 F1000 X18.31 Y10.15 S255
+M03 F1000 X18.25 Y10.24 S255
+M03 F1000 X18.15 Y10.31 S255
+M03 F1000 X18.00 Y10.35 S255
+M03 F1000 X14.75 Y10.34 S255
+M03 F1000 X14.25 Y10.30 S255
+M03 F1000 X14.00 Y10.25 S255
+M03 F1000 X13.98 Y10.25 S255
+M03 F1000 X13.97 Y10.24 S255
+M03 F1000 X13.93 Y10.24 S255
+M03 F1000 X13.78 Y10.21 S255
+M03 F1000 X13.75 Y10.19 S255
+M03 F1000 X13.72 Y10.20 S255
+M03 F1000 X13.68 Y10.18 S255
+M03 F1000 X13.65 Y10.16 S255
+M03 F1000 X13.62 Y10.17 S255
+M03 F1000 X13.50 Y10.13 S255
+M03 F1000 X13.47 Y10.11 S255
+M03 F1000 X13.45 Y10.12 S255
+M03 F1000 X13.43 Y10.11 S255
+M03 F1000 X13.40 Y10.10 S255
+M03 F1000 X13.40 Y10.09 S255
+M03 F1000 X13.38 Y10.10 S255
+M03 F1000 X13.33 Y10.08 S255
+M03 F1000 X13.33 Y10.06 S255
+M03 F1000 X13.30 Y10.07 S255
+M03 F1000 X13.25 Y10.05 S255
+M03 F1000 X13.25 Y10.04 S255
+M03 F1000 X13.22 Y10.05 S255
+M03 F1000 X13.20 Y10.04 S255
+M03 F1000 X13.18 Y10.01 S255
+M03 F1000 X13.15 Y10.02 S255
+M03 F1000 X13.08 Y9.99 S255
+M03 F1000 X13.05 Y9.96 S255
+M03 F1000 X13.03 Y9.97 S255
+M03 F1000 X13.00 Y9.96 S255
+M03 F1000 X12.97 Y9.94 S255
+M03 F1000 X12.95 Y9.94 S255
+M03 F1000 X12.93 Y9.93 S255
+M03 F1000 X12.93 Y9.91 S255
+M03 F1000 X12.90 Y9.92 S255
+M03 F1000 X12.78 Y9.86 S255
+M03 F1000 X12.75 Y9.84 S255
+M03 F1000 X12.72 Y9.84 S255
+M03 F1000 X12.70 Y9.83 S255
+M03 F1000 X12.28 Y9.60 S255
+M03 F1000 X12.15 Y9.53 S255
+M03 F1000 X12.15 Y9.51 S255
+M03 F1000 X12.12 Y9.51 S255
+M03 F1000 X12.03 Y9.45 S255
+M03 F1000 X11.65 Y9.17 S255
+M03 F1000 X11.62 Y9.16 S255
+M03 F1000 X11.62 Y9.15 S255
+M03 F1000 X11.47 Y9.02 S255
+M03 F1000 X11.45 Y9.01 S255
+M03 F1000 X11.45 Y9.00 S255
+M03 F1000 X11.23 Y8.80 S255
+M03 F1000 X11.03 Y8.58 S255
+M03 F1000 X11.00 Y8.55 S255
+M03 F1000 X11.00 Y8.54 S255
+M03 F1000 X10.97 Y8.52 S255
+M03 F1000 X10.88 Y8.41 S255
+M03 F1000 X10.85 Y8.36 S255
+M03 F1000 X10.83 Y8.34 S255
+M03 F1000 X10.72 Y8.22 S255
+M03 F1000 X10.47 Y7.86 S255
+M03 F1000 X10.30 Y7.55 S255
+M03 F1000 X10.15 Y7.26 S255
+M03 F1000 X10.10 Y7.13 S255
+M03 F1000 X10.05 Y7.04 S255
+M03 F1000 X9.88 Y6.54 S255
+M03 F1000 X9.75 Y6.04 S255
+M03 F1000 X9.68 Y5.53 S255
+M03 F1000 X9.66 Y5.25 S255
+M03 F1000 X9.65 Y5.00 S255
+M03 F1000 X9.65 Y0.35 S255
+M03 F1000 X0.00 Y0.35 S255
+M03 F1000 X-0.15 Y0.31 S255
+M03 F1000 X-0.25 Y0.24 S255
+M03 F1000 X-0.31 Y0.15 S255
+M03 F1000 X-0.35 Y0.00 S255
+M03 F1000 X-0.31 Y-0.15 S255
+M03 F1000 X-0.24 Y-0.25 S255
+M03 F1000 X-0.15 Y-0.31 S255
+M03 F1000 X0.00 Y-0.35 S255
+M05 F2000 X23.85 Y0.92
+M03 F1000 X24.00 Y0.90 S255
+M03 F1000 X24.18 Y0.93 S255
+M03 F1000 X24.22 Y0.95 S255
+M03 F1000 X24.23 Y0.96 S255
+M03 F1000 X24.25 Y0.96 S255
+M03 F1000 X24.28 Y0.99 S255
+M03 F1000 X24.30 Y0.99 S255
+M03 F1000 X24.33 Y1.01 S255
+M03 F1000 X24.36 Y1.02 S255
+M03 F1000 X24.50 Y1.16 S255
+M03 F1000 X24.57 Y1.33 S255
+M03 F1000 X24.60 Y1.49 S255
+M03 F1000 X24.60 Y2.51 S255
+M03 F1000 X24.57 Y2.68 S255
+M03 F1000 X24.55 Y2.74 S255
+M03 F1000 X24.50 Y2.84 S255
+M03 F1000 X24.35 Y2.99 S255
+M03 F1000 X24.33 Y2.99 S255
+M03 F1000 X24.30 Y3.01 S255
+M03 F1000 X24.28 Y3.01 S255
+M03 F1000 X24.25 Y3.04 S255
+M03 F1000 X24.23 Y3.04 S255
+M03 F1000 X24.22 Y3.05 S255
+M03 F1000 X24.18 Y3.07 S255
+M03 F1000 X24.00 Y3.10 S255
+M03 F1000 X23.83 Y3.07 S255
+M03 F1000 X23.78 Y3.05 S255
+M03 F1000 X23.78 Y3.04 S255
+M03 F1000 X23.75 Y3.04 S255
+M03 F1000 X23.73 Y3.01 S255
+M03 F1000 X23.70 Y3.01 S255
+M03 F1000 X23.68 Y2.99 S255
+M03 F1000 X23.65 Y2.99 S255
+M03 F1000 X23.60 Y2.94 S255
+M03 F1000 X23.50 Y2.84 S255
+M03 F1000 X23.45 Y2.74 S255
+M03 F1000 X23.43 Y2.68 S255
+M03 F1000 X23.40 Y2.50 S255
+M03 F1000 X23.40 Y1.50 S255
+M03 F1000 X23.43 Y1.33 S255
+M03 F1000 X23.50 Y1.16 S255
+M03 F1000 X23.64 Y1.02 S255
+M03 F1000 X23.68 Y1.01 S255
+M03 F1000 X23.70 Y0.99 S255
+M03 F1000 X23.73 Y0.99 S255
+M03 F1000 X23.75 Y0.96 S255
+M03 F1000 X23.78 Y0.96 S255
+M03 F1000 X23.78 Y0.95 S255
+M03 F1000 X23.80 Y0.94 S255
+M03 F1000 X23.85 Y0.92 S255
+M05 F2000 X27.63 Y1.25
+M03 F1000 X28.38 Y1.25 S255
+M03 F1000 X28.40 Y1.29 S255
+M03 F1000 X28.45 Y1.28 S255
+M03 F1000 X28.46 Y1.30 S255
+M03 F1000 X28.48 Y1.31 S255
+M03 F1000 X28.50 Y1.37 S255
+M03 F1000 X28.58 Y1.49 S255
+M03 F1000 X28.84 Y1.95 S255
+M03 F1000 X28.85 Y2.01 S255
+M03 F1000 X28.84 Y2.05 S255
+M03 F1000 X28.58 Y2.51 S255
+M03 F1000 X28.50 Y2.63 S255
+M03 F1000 X28.48 Y2.69 S255
+M03 F1000 X28.46 Y2.70 S255
+M03 F1000 X28.45 Y2.72 S255
+M03 F1000 X28.40 Y2.71 S255
+M03 F1000 X28.38 Y2.75 S255
+M03 F1000 X27.63 Y2.75 S255
+M03 F1000 X27.60 Y2.71 S255
+M03 F1000 X27.55 Y2.72 S255
+M03 F1000 X27.54 Y2.70 S255
+M03 F1000 X27.53 Y2.69 S255
+M03 F1000 X27.50 Y2.63 S255
+M03 F1000 X27.43 Y2.51 S255
+M03 F1000 X27.28 Y2.24 S255
+M03 F1000 X27.25 Y2.21 S255
+M03 F1000 X27.15 Y2.03 S255
+M03 F1000 X27.15 Y1.98 S255
+M03 F1000 X27.25 Y1.79 S255
+M03 F1000 X27.28 Y1.76 S255
+M03 F1000 X27.43 Y1.49 S255
+M03 F1000 X27.50 Y1.37 S255
+M03 F1000 X27.53 Y1.31 S255
+M03 F1000 X27.54 Y1.30 S255
+M03 F1000 X27.55 Y1.28 S255
+M03 F1000 X27.60 Y1.29 S255
+M03 F1000 X27.63 Y1.25 S255
+M05 F2000 X19.20 Y1.30
+M03 F1000 X20.80 Y1.30 S255
+M03 F1000 X20.87 Y1.33 S255
+M03 F1000 X20.90 Y1.39 S255
+M03 F1000 X20.90 Y2.59 S255
+M03 F1000 X20.89 Y2.63 S255
+M03 F1000 X20.85 Y2.67 S255
+M03 F1000 X20.80 Y2.66 S255
+M03 F1000 X20.80 Y2.68 S255
+M03 F1000 X20.78 Y2.70 S255
+M03 F1000 X19.20 Y2.70 S255
+M03 F1000 X19.20 Y2.68 S255
+M03 F1000 X19.13 Y2.65 S255
+M03 F1000 X19.10 Y2.60 S255
+M03 F1000 X19.10 Y1.40 S255
+M03 F1000 X19.11 Y1.35 S255
+M03 F1000 X19.13 Y1.33 S255
+M03 F1000 X19.20 Y1.30 S255
+M05 F2000 X29.33 Y7.00
+M03 F1000 X29.40 Y6.98 S255
+M03 F1000 X29.45 Y6.99 S255
+M03 F1000 X29.70 Y7.13 S255
+M03 F1000 X29.72 Y7.15 S255
+M03 F1000 X29.73 Y7.16 S255
+M03 F1000 X29.75 Y7.16 S255
+M03 F1000 X29.88 Y7.24 S255
+M03 F1000 X29.90 Y7.26 S255
+M03 F1000 X29.93 Y7.26 S255
+M03 F1000 X30.35 Y7.51 S255
+M03 F1000 X30.37 Y7.53 S255
+M03 F1000 X30.38 Y7.54 S255
+M03 F1000 X30.40 Y7.54 S255
+M03 F1000 X30.53 Y7.61 S255
+M03 F1000 X30.55 Y7.64 S255
+M03 F1000 X30.58 Y7.64 S255
+M03 F1000 X30.83 Y7.78 S255
+M03 F1000 X30.85 Y7.80 S255
+M03 F1000 X30.85 Y7.81 S255
+M03 F1000 X30.88 Y7.81 S255
+M03 F1000 X31.00 Y7.88 S255
+M03 F1000 X31.02 Y7.90 S255
+M03 F1000 X31.03 Y7.91 S255
+M03 F1000 X31.05 Y7.91 S255
+M03 F1000 X31.15 Y7.97 S255
+M03 F1000 X31.20 Y8.03 S255
+M03 F1000 X31.21 Y8.07 S255
+M03 F1000 X31.20 Y8.12 S255
+M03 F1000 X30.88 Y8.68 S255
+M03 F1000 X30.85 Y8.74 S255
+M03 F1000 X30.78 Y8.85 S255
+M03 F1000 X30.75 Y8.91 S255
+M03 F1000 X30.69 Y9.00 S255
+M03 F1000 X30.60 Y9.02 S255
+M03 F1000 X30.55 Y9.01 S255
+M03 F1000 X30.30 Y8.87 S255
+M03 F1000 X30.28 Y8.85 S255
+M03 F1000 X30.28 Y8.84 S255
+M03 F1000 X30.25 Y8.84 S255
+M03 F1000 X30.13 Y8.76 S255
+M03 F1000 X30.10 Y8.74 S255
+M03 F1000 X30.08 Y8.74 S255
+M03 F1000 X29.65 Y8.49 S255
+M03 F1000 X29.63 Y8.47 S255
+M03 F1000 X29.63 Y8.46 S255
+M03 F1000 X29.60 Y8.46 S255
+M03 F1000 X29.48 Y8.39 S255
+M03 F1000 X29.45 Y8.36 S255
+M03 F1000 X29.43 Y8.36 S255
+M03 F1000 X29.18 Y8.22 S255
+M03 F1000 X29.15 Y8.20 S255
+M03 F1000 X29.15 Y8.19 S255
+M03 F1000 X29.13 Y8.19 S255
+M03 F1000 X29.00 Y8.12 S255
+M03 F1000 X28.98 Y8.10 S255
+M03 F1000 X28.98 Y8.09 S255
+M03 F1000 X28.95 Y8.09 S255
+M03 F1000 X28.85 Y8.03 S255
+M03 F1000 X28.80 Y7.97 S255
+M03 F1000 X28.79 Y7.95 S255
+M03 F1000 X28.79 Y7.90 S255
+M03 F1000 X28.88 Y7.74 S255
+M03 F1000 X28.90 Y7.71 S255
+M03 F1000 X29.15 Y7.26 S255
+M03 F1000 X29.23 Y7.15 S255
+M03 F1000 X29.25 Y7.09 S255
+M03 F1000 X29.31 Y7.00 S255
+M03 F1000 X29.33 Y7.00 S255
+M05 F2000 X24.83 Y7.12
+M03 F1000 X25.00 Y7.10 S255
+M03 F1000 X25.15 Y7.11 S255
+M03 F1000 X25.19 Y7.12 S255
+M03 F1000 X25.20 Y7.14 S255
+M03 F1000 X25.23 Y7.13 S255
+M03 F1000 X25.25 Y7.14 S255
+M03 F1000 X25.27 Y7.15 S255
+M03 F1000 X25.28 Y7.16 S255
+M03 F1000 X25.30 Y7.15 S255
+M03 F1000 X25.35 Y7.19 S255
+M03 F1000 X25.38 Y7.18 S255
+M03 F1000 X25.40 Y7.19 S255
+M03 F1000 X25.45 Y7.22 S255
+M03 F1000 X25.45 Y7.24 S255
+M03 F1000 X25.48 Y7.24 S255
+M03 F1000 X25.49 Y7.25 S255
+M03 F1000 X25.52 Y7.30 S255
+M03 F1000 X25.53 Y7.32 S255
+M03 F1000 X25.53 Y7.33 S255
+M03 F1000 X25.53 Y7.38 S255
+M03 F1000 X25.50 Y7.42 S255
+M03 F1000 X25.30 Y7.64 S255
+M03 F1000 X25.25 Y7.64 S255
+M03 F1000 X25.23 Y7.65 S255
+M03 F1000 X25.20 Y7.64 S255
+M03 F1000 X25.18 Y7.64 S255
+M03 F1000 X25.15 Y7.62 S255
+M03 F1000 X25.15 Y7.61 S255
+M03 F1000 X25.13 Y7.62 S255
+M03 F1000 X25.00 Y7.60 S255
+M03 F1000 X24.88 Y7.62 S255
+M03 F1000 X24.85 Y7.61 S255
+M03 F1000 X24.85 Y7.62 S255
+M03 F1000 X24.83 Y7.64 S255
+M03 F1000 X24.80 Y7.64 S255
+M03 F1000 X24.78 Y7.65 S255
+M03 F1000 X24.75 Y7.64 S255
+M03 F1000 X24.70 Y7.64 S255
+M03 F1000 X24.48 Y7.40 S255
+M03 F1000 X24.47 Y7.38 S255
+M03 F1000 X24.47 Y7.33 S255
+M03 F1000 X24.48 Y7.32 S255
+M03 F1000 X24.48 Y7.30 S255
+M03 F1000 X24.51 Y7.25 S255
+M03 F1000 X24.53 Y7.24 S255
+M03 F1000 X24.55 Y7.24 S255
+M03 F1000 X24.55 Y7.22 S255
+M03 F1000 X24.60 Y7.19 S255
+M03 F1000 X24.63 Y7.18 S255
+M03 F1000 X24.65 Y7.19 S255
+M03 F1000 X24.70 Y7.15 S255
+M03 F1000 X24.73 Y7.16 S255
+M03 F1000 X24.73 Y7.15 S255
+M03 F1000 X24.75 Y7.14 S255
+M03 F1000 X24.78 Y7.13 S255
+M03 F1000 X24.80 Y7.14 S255
+M03 F1000 X24.83 Y7.12 S255
+M05 F2000 X19.85 Y7.32
+M03 F1000 X20.00 Y7.30 S255
+M03 F1000 X20.15 Y7.32 S255
+M03 F1000 X20.18 Y7.34 S255
+M03 F1000 X20.20 Y7.33 S255
+M03 F1000 X20.28 Y7.36 S255
+M03 F1000 X20.30 Y7.39 S255
+M03 F1000 X20.33 Y7.38 S255
+M03 F1000 X20.35 Y7.39 S255
+M03 F1000 X20.50 Y7.51 S255
+M03 F1000 X20.61 Y7.65 S255
+M03 F1000 X20.68 Y7.81 S255
+M03 F1000 X20.70 Y8.01 S255
+M03 F1000 X20.68 Y8.19 S255
+M03 F1000 X20.61 Y8.35 S255
+M03 F1000 X20.50 Y8.49 S255
+M03 F1000 X20.35 Y8.61 S255
+M03 F1000 X20.33 Y8.62 S255
+M03 F1000 X20.30 Y8.61 S255
+M03 F1000 X20.28 Y8.64 S255
+M03 F1000 X20.20 Y8.67 S255
+M03 F1000 X20.18 Y8.66 S255
+M03 F1000 X20.15 Y8.68 S255
+M03 F1000 X20.00 Y8.70 S255
+M03 F1000 X19.85 Y8.68 S255
+M03 F1000 X19.83 Y8.66 S255
+M03 F1000 X19.80 Y8.67 S255
+M03 F1000 X19.73 Y8.64 S255
+M03 F1000 X19.70 Y8.61 S255
+M03 F1000 X19.68 Y8.62 S255
+M03 F1000 X19.65 Y8.61 S255
+M03 F1000 X19.50 Y8.49 S255
+M03 F1000 X19.39 Y8.35 S255
+M03 F1000 X19.33 Y8.19 S255
+M03 F1000 X19.30 Y7.99 S255
+M03 F1000 X19.33 Y7.81 S255
+M03 F1000 X19.39 Y7.65 S255
+M03 F1000 X19.51 Y7.50 S255
+M03 F1000 X19.65 Y7.39 S255
+M03 F1000 X19.68 Y7.38 S255
+M03 F1000 X19.70 Y7.39 S255
+M03 F1000 X19.73 Y7.36 S255
+M03 F1000 X19.80 Y7.33 S255
+M03 F1000 X19.83 Y7.34 S255
+M03 F1000 X19.85 Y7.32 S255
+M05 F2000 X24.33 Y7.47
+M03 F1000 X24.35 Y7.46 S255
+M03 F1000 X24.38 Y7.49 S255
+M03 F1000 X24.40 Y7.48 S255
+M03 F1000 X24.43 Y7.50 S255
+M03 F1000 X24.60 Y7.68 S255
+M03 F1000 X24.65 Y7.74 S255
+M03 F1000 X24.65 Y7.78 S255
+M03 F1000 X24.65 Y7.81 S255
+M03 F1000 X24.61 Y7.90 S255
+M03 F1000 X24.60 Y8.00 S255
+M03 F1000 X24.61 Y8.10 S255
+M03 F1000 X24.65 Y8.19 S255
+M03 F1000 X24.65 Y8.26 S255
+M03 F1000 X24.60 Y8.32 S255
+M03 F1000 X24.43 Y8.50 S255
+M03 F1000 X24.40 Y8.52 S255
+M03 F1000 X24.38 Y8.51 S255
+M03 F1000 X24.35 Y8.54 S255
+M03 F1000 X24.30 Y8.52 S255
+M03 F1000 X24.25 Y8.49 S255
+M03 F1000 X24.23 Y8.46 S255
+M03 F1000 X24.18 Y8.36 S255
+M03 F1000 X24.13 Y8.21 S255
+M03 F1000 X24.10 Y8.01 S255
+M03 F1000 X24.13 Y7.79 S255
+M03 F1000 X24.18 Y7.64 S255
+M03 F1000 X24.23 Y7.54 S255
+M03 F1000 X24.25 Y7.51 S255
+M03 F1000 X24.30 Y7.48 S255
+M03 F1000 X24.32 Y7.47 S255
+M03 F1000 X24.33 Y7.47 S255
+M05 F2000 X25.65 Y7.46
+M03 F1000 X25.70 Y7.48 S255
+M03 F1000 X25.75 Y7.51 S255
+M03 F1000 X25.78 Y7.54 S255
+M03 F1000 X25.83 Y7.64 S255
+M03 F1000 X25.88 Y7.79 S255
+M03 F1000 X25.90 Y8.01 S255
+M03 F1000 X25.88 Y8.21 S255
+M03 F1000 X25.83 Y8.36 S255
+M03 F1000 X25.78 Y8.46 S255
+M03 F1000 X25.75 Y8.49 S255
+M03 F1000 X25.70 Y8.52 S255
+M03 F1000 X25.65 Y8.54 S255
+M03 F1000 X25.63 Y8.51 S255
+M03 F1000 X25.60 Y8.52 S255
+M03 F1000 X25.58 Y8.50 S255
+M03 F1000 X25.40 Y8.32 S255
+M03 F1000 X25.35 Y8.26 S255
+M03 F1000 X25.35 Y8.19 S255
+M03 F1000 X25.39 Y8.10 S255
+M03 F1000 X25.40 Y8.00 S255
+M03 F1000 X25.39 Y7.90 S255
+M03 F1000 X25.35 Y7.81 S255
+M03 F1000 X25.35 Y7.78 S255
+M03 F1000 X25.35 Y7.74 S255
+M03 F1000 X25.40 Y7.68 S255
+M03 F1000 X25.58 Y7.50 S255
+M03 F1000 X25.60 Y7.48 S255
+M03 F1000 X25.63 Y7.49 S255
+M03 F1000 X25.65 Y7.46 S255
+M05 F2000 X29.93 Y7.82
+M03 F1000 X29.87 Y7.85 S255
+M03 F1000 X29.82 Y7.93 S255
+M03 F1000 X29.80 Y8.00 S255
+M03 F1000 X29.82 Y8.07 S255
+M03 F1000 X29.87 Y8.15 S255
+M03 F1000 X29.93 Y8.18 S255
+M03 F1000 X30.00 Y8.20 S255
+M03 F1000 X30.08 Y8.18 S255
+M03 F1000 X30.13 Y8.15 S255
+M03 F1000 X30.18 Y8.07 S255
+M03 F1000 X30.20 Y8.00 S255
+M03 F1000 X30.18 Y7.93 S255
+M03 F1000 X30.15 Y7.87 S255
+M03 F1000 X30.08 Y7.82 S255
+M03 F1000 X30.00 Y7.80 S255
+M03 F1000 X29.93 Y7.82 S255
+M05 F2000 X19.93 Y7.86
+M03 F1000 X19.92 Y7.88 S255
+M03 F1000 X19.89 Y7.90 S255
+M03 F1000 X19.86 Y7.95 S255
+M03 F1000 X19.85 Y8.00 S255
+M03 F1000 X19.88 Y8.07 S255
+M03 F1000 X19.92 Y8.12 S255
+M03 F1000 X19.93 Y8.14 S255
+M03 F1000 X20.00 Y8.15 S255
+M03 F1000 X20.08 Y8.14 S255
+M03 F1000 X20.08 Y8.12 S255
+M03 F1000 X20.11 Y8.10 S255
+M03 F1000 X20.14 Y8.05 S255
+M03 F1000 X20.15 Y8.00 S255
+M03 F1000 X20.12 Y7.93 S255
+M03 F1000 X20.08 Y7.88 S255
+M03 F1000 X20.08 Y7.86 S255
+M03 F1000 X20.00 Y7.85 S255
+M03 F1000 X19.93 Y7.86 S255
+M05 F2000 X24.78 Y8.35
+M03 F1000 X24.80 Y8.36 S255
+M03 F1000 X24.83 Y8.36 S255
+M03 F1000 X24.85 Y8.38 S255
+M03 F1000 X24.85 Y8.39 S255
+M03 F1000 X24.88 Y8.38 S255
+M03 F1000 X25.00 Y8.40 S255
+M03 F1000 X25.13 Y8.38 S255
+M03 F1000 X25.15 Y8.39 S255
+M03 F1000 X25.15 Y8.38 S255
+M03 F1000 X25.18 Y8.36 S255
+M03 F1000 X25.20 Y8.36 S255
+M03 F1000 X25.23 Y8.35 S255
+M03 F1000 X25.25 Y8.36 S255
+M03 F1000 X25.30 Y8.36 S255
+M03 F1000 X25.52 Y8.60 S255
+M03 F1000 X25.54 Y8.65 S255
+M03 F1000 X25.51 Y8.72 S255
+M03 F1000 X25.48 Y8.76 S255
+M03 F1000 X25.45 Y8.76 S255
+M03 F1000 X25.45 Y8.78 S255
+M03 F1000 X25.38 Y8.82 S255
+M03 F1000 X25.35 Y8.81 S255
+M03 F1000 X25.30 Y8.85 S255
+M03 F1000 X25.28 Y8.84 S255
+M03 F1000 X25.27 Y8.85 S255
+M03 F1000 X25.25 Y8.86 S255
+M03 F1000 X25.23 Y8.87 S255
+M03 F1000 X25.20 Y8.86 S255
+M03 F1000 X25.18 Y8.88 S255
+M03 F1000 X25.10 Y8.89 S255
+M03 F1000 X25.00 Y8.90 S255
+M03 F1000 X24.85 Y8.89 S255
+M03 F1000 X24.81 Y8.88 S255
+M03 F1000 X24.80 Y8.86 S255
+M03 F1000 X24.78 Y8.87 S255
+M03 F1000 X24.75 Y8.86 S255
+M03 F1000 X24.73 Y8.85 S255
+M03 F1000 X24.73 Y8.84 S255
+M03 F1000 X24.70 Y8.85 S255
+M03 F1000 X24.65 Y8.81 S255
+M03 F1000 X24.63 Y8.82 S255
+M03 F1000 X24.60 Y8.81 S255
+M03 F1000 X24.55 Y8.78 S255
+M03 F1000 X24.55 Y8.76 S255
+M03 F1000 X24.53 Y8.76 S255
+M03 F1000 X24.51 Y8.75 S255
+M03 F1000 X24.49 Y8.72 S255
+M03 F1000 X24.48 Y8.68 S255
+M03 F1000 X24.47 Y8.68 S255
+M03 F1000 X24.47 Y8.62 S255
+M03 F1000 X24.50 Y8.57 S255
+M03 F1000 X24.70 Y8.36 S255
+M03 F1000 X24.75 Y8.36 S255
+M03 F1000 X24.78 Y8.35 S255
+M05 F2000 X0.00 Y11.90
+M03 F1000 X9.97 Y11.90 S255
+M03 F1000 X10.00 Y11.89 S255
+M03 F1000 X10.00 Y11.90 S255
+M03 F1000 X10.03 Y11.91 S255
+M03 F1000 X10.05 Y11.91 S255
+M03 F1000 X10.09 Y11.95 S255
+M03 F1000 X10.10 Y11.99 S255
+M03 F1000 X10.10 Y17.99 S255
+M03 F1000 X10.09 Y18.03 S255
+M03 F1000 X10.05 Y18.07 S255
+M03 F1000 X10.03 Y18.06 S255
+M03 F1000 X10.00 Y18.08 S255
+M03 F1000 X10.00 Y18.10 S255
+M03 F1000 X9.97 Y18.10 S255
+M03 F1000 X0.00 Y18.10 S255
+M03 F1000 X-0.03 Y18.06 S255
+M03 F1000 X-0.05 Y18.06 S255
+M03 F1000 X-0.07 Y18.05 S255
+M03 F1000 X-0.10 Y18.00 S255
+M03 F1000 X-0.09 Y17.98 S255
+M03 F1000 X-0.09 Y12.00 S255
+M03 F1000 X-0.10 Y11.97 S255
+M03 F1000 X-0.07 Y11.93 S255
+M03 F1000 X0.00 Y11.90 S255
+M05 F2000 X14.85 Y14.32
+M03 F1000 X15.00 Y14.30 S255
+M03 F1000 X15.15 Y14.32 S255
+M03 F1000 X15.18 Y14.34 S255
+M03 F1000 X15.20 Y14.33 S255
+M03 F1000 X15.28 Y14.36 S255
+M03 F1000 X15.30 Y14.39 S255
+M03 F1000 X15.33 Y14.38 S255
+M03 F1000 X15.35 Y14.39 S255
+M03 F1000 X15.50 Y14.51 S255
+M03 F1000 X15.61 Y14.65 S255
+M03 F1000 X15.67 Y14.81 S255
+M03 F1000 X15.70 Y15.03 S255
+M03 F1000 X15.67 Y15.19 S255
+M03 F1000 X15.61 Y15.35 S255
+M03 F1000 X15.49 Y15.50 S255
+M03 F1000 X15.35 Y15.61 S255
+M03 F1000 X15.33 Y15.62 S255
+M03 F1000 X15.30 Y15.61 S255
+M03 F1000 X15.28 Y15.64 S255
+M03 F1000 X15.20 Y15.67 S255
+M03 F1000 X15.18 Y15.66 S255
+M03 F1000 X15.15 Y15.68 S255
+M03 F1000 X15.00 Y15.70 S255
+M03 F1000 X14.85 Y15.68 S255
+M03 F1000 X14.83 Y15.66 S255
+M03 F1000 X14.80 Y15.67 S255
+M03 F1000 X14.72 Y15.64 S255
+M03 F1000 X14.70 Y15.61 S255
+M03 F1000 X14.68 Y15.62 S255
+M03 F1000 X14.65 Y15.61 S255
+M03 F1000 X14.50 Y15.49 S255
+M03 F1000 X14.39 Y15.35 S255
+M03 F1000 X14.33 Y15.19 S255
+M03 F1000 X14.30 Y14.99 S255
+M03 F1000 X14.33 Y14.81 S255
+M03 F1000 X14.39 Y14.65 S255
+M03 F1000 X14.50 Y14.51 S255
+M03 F1000 X14.65 Y14.39 S255
+M03 F1000 X14.68 Y14.38 S255
+M03 F1000 X14.70 Y14.39 S255
+M03 F1000 X14.72 Y14.36 S255
+M03 F1000 X14.80 Y14.33 S255
+M03 F1000 X14.83 Y14.34 S255
+M03 F1000 X14.85 Y14.32 S255
+M05 F2000 X18.85 Y14.32
+M03 F1000 X19.00 Y14.30 S255
+M03 F1000 X19.15 Y14.32 S255
+M03 F1000 X19.18 Y14.34 S255
+M03 F1000 X19.20 Y14.33 S255
+M03 F1000 X19.28 Y14.36 S255
+M03 F1000 X19.30 Y14.39 S255
+M03 F1000 X19.33 Y14.38 S255
+M03 F1000 X19.35 Y14.39 S255
+M03 F1000 X19.50 Y14.51 S255
+M03 F1000 X19.61 Y14.65 S255
+M03 F1000 X19.68 Y14.81 S255
+M03 F1000 X19.70 Y15.01 S255
+M03 F1000 X19.68 Y15.19 S255
+M03 F1000 X19.61 Y15.35 S255
+M03 F1000 X19.50 Y15.49 S255
+M03 F1000 X19.35 Y15.61 S255
+M03 F1000 X19.33 Y15.62 S255
+M03 F1000 X19.30 Y15.61 S255
+M03 F1000 X19.28 Y15.64 S255
+M03 F1000 X19.20 Y15.67 S255
+M03 F1000 X19.18 Y15.66 S255
+M03 F1000 X19.15 Y15.68 S255
+M03 F1000 X19.00 Y15.70 S255
+M03 F1000 X18.85 Y15.68 S255
+M03 F1000 X18.83 Y15.66 S255
+M03 F1000 X18.80 Y15.67 S255
+M03 F1000 X18.73 Y15.64 S255
+M03 F1000 X18.70 Y15.61 S255
+M03 F1000 X18.68 Y15.62 S255
+M03 F1000 X18.65 Y15.61 S255
+M03 F1000 X18.50 Y15.49 S255
+M03 F1000 X18.39 Y15.35 S255
+M03 F1000 X18.33 Y15.19 S255
+M03 F1000 X18.30 Y14.99 S255
+M03 F1000 X18.33 Y14.81 S255
+M03 F1000 X18.39 Y14.65 S255
+M03 F1000 X18.50 Y14.51 S255
+M03 F1000 X18.65 Y14.39 S255
+M03 F1000 X18.68 Y14.38 S255
+M03 F1000 X18.70 Y14.39 S255
+M03 F1000 X18.73 Y14.36 S255
+M03 F1000 X18.80 Y14.33 S255
+M03 F1000 X18.83 Y14.34 S255
+M03 F1000 X18.85 Y14.32 S255
+M05 F2000 X22.85 Y14.32
+M03 F1000 X23.00 Y14.30 S255
+M03 F1000 X23.15 Y14.32 S255
+M03 F1000 X23.18 Y14.34 S255
+M03 F1000 X23.20 Y14.33 S255
+M03 F1000 X23.28 Y14.36 S255
+M03 F1000 X23.30 Y14.39 S255
+M03 F1000 X23.33 Y14.38 S255
+M03 F1000 X23.35 Y14.39 S255
+M03 F1000 X23.50 Y14.51 S255
+M03 F1000 X23.61 Y14.65 S255
+M03 F1000 X23.68 Y14.81 S255
+M03 F1000 X23.70 Y15.01 S255
+M03 F1000 X23.68 Y15.19 S255
+M03 F1000 X23.61 Y15.35 S255
+M03 F1000 X23.50 Y15.49 S255
+M03 F1000 X23.35 Y15.61 S255
+M03 F1000 X23.33 Y15.62 S255
+M03 F1000 X23.30 Y15.61 S255
+M03 F1000 X23.28 Y15.64 S255
+M03 F1000 X23.20 Y15.67 S255
+M03 F1000 X23.18 Y15.66 S255
+M03 F1000 X23.15 Y15.68 S255
+M03 F1000 X23.00 Y15.70 S255
+M03 F1000 X22.85 Y15.68 S255
+M03 F1000 X22.83 Y15.66 S255
+M03 F1000 X22.80 Y15.67 S255
+M03 F1000 X22.73 Y15.64 S255
+M03 F1000 X22.70 Y15.61 S255
+M03 F1000 X22.68 Y15.62 S255
+M03 F1000 X22.65 Y15.61 S255
+M03 F1000 X22.50 Y15.49 S255
+M03 F1000 X22.39 Y15.35 S255
+M03 F1000 X22.33 Y15.19 S255
+M03 F1000 X22.30 Y14.99 S255
+M03 F1000 X22.33 Y14.81 S255
+M03 F1000 X22.39 Y14.65 S255
+M03 F1000 X22.50 Y14.51 S255
+M03 F1000 X22.65 Y14.39 S255
+M03 F1000 X22.68 Y14.38 S255
+M03 F1000 X22.70 Y14.39 S255
+M03 F1000 X22.73 Y14.36 S255
+M03 F1000 X22.80 Y14.33 S255
+M03 F1000 X22.83 Y14.34 S255
+M03 F1000 X22.85 Y14.32 S255
+M05 F2000 X2.00 Y14.84
+M03 F1000 X2.00 Y14.85 S255
+M03 F1000 X1.93 Y14.86 S255
+M03 F1000 X1.92 Y14.88 S255
+M03 F1000 X1.89 Y14.90 S255
+M03 F1000 X1.86 Y14.95 S255
+M03 F1000 X1.85 Y15.00 S255
+M03 F1000 X1.86 Y15.05 S255
+M03 F1000 X1.89 Y15.10 S255
+M03 F1000 X1.92 Y15.12 S255
+M03 F1000 X1.93 Y15.14 S255
+M03 F1000 X2.03 Y15.15 S255
+M03 F1000 X7.97 Y15.15 S255
+M03 F1000 X8.07 Y15.14 S255
+M03 F1000 X8.08 Y15.12 S255
+M03 F1000 X8.11 Y15.10 S255
+M03 F1000 X8.14 Y15.05 S255
+M03 F1000 X8.15 Y15.00 S255
+M03 F1000 X8.14 Y14.95 S255
+M03 F1000 X8.11 Y14.90 S255
+M03 F1000 X8.08 Y14.88 S255
+M03 F1000 X8.07 Y14.86 S255
+M03 F1000 X8.00 Y14.85 S255
+M03 F1000 X2.03 Y14.85 S255
+M03 F1000 X2.00 Y14.84 S255
+M05 F2000 X14.93 Y14.86
+M03 F1000 X14.92 Y14.88 S255
+M03 F1000 X14.89 Y14.90 S255
+M03 F1000 X14.86 Y14.95 S255
+M03 F1000 X14.85 Y15.00 S255
+M03 F1000 X14.88 Y15.08 S255
+M03 F1000 X14.92 Y15.12 S255
+M03 F1000 X14.93 Y15.14 S255
+M03 F1000 X15.00 Y15.15 S255
+M03 F1000 X15.08 Y15.14 S255
+M03 F1000 X15.08 Y15.12 S255
+M03 F1000 X15.11 Y15.10 S255
+M03 F1000 X15.14 Y15.05 S255
+M03 F1000 X15.15 Y15.00 S255
+M03 F1000 X15.12 Y14.93 S255
+M03 F1000 X15.08 Y14.88 S255
+M03 F1000 X15.08 Y14.86 S255
+M03 F1000 X15.00 Y14.85 S255
+M03 F1000 X14.93 Y14.86 S255
+M05 F2000 X18.93 Y14.86
+M03 F1000 X18.92 Y14.88 S255
+M03 F1000 X18.89 Y14.90 S255
+M03 F1000 X18.86 Y14.95 S255
+M03 F1000 X18.85 Y15.00 S255
+M03 F1000 X18.88 Y15.08 S255
+M03 F1000 X18.92 Y15.12 S255
+M03 F1000 X18.93 Y15.14 S255
+M03 F1000 X19.00 Y15.15 S255
+M03 F1000 X19.08 Y15.14 S255
+M03 F1000 X19.08 Y15.12 S255
+M03 F1000 X19.11 Y15.10 S255
+M03 F1000 X19.14 Y15.05 S255
+M03 F1000 X19.15 Y15.00 S255
+M03 F1000 X19.12 Y14.93 S255
+M03 F1000 X19.08 Y14.88 S255
+M03 F1000 X19.08 Y14.86 S255
+M03 F1000 X19.00 Y14.85 S255
+M03 F1000 X18.93 Y14.86 S255
+M05 F2000 X22.93 Y14.86
+M03 F1000 X22.92 Y14.88 S255
+M03 F1000 X22.89 Y14.90 S255
+M03 F1000 X22.86 Y14.95 S255
+M03 F1000 X22.85 Y15.00 S255
+M03 F1000 X22.88 Y15.08 S255
+M03 F1000 X22.92 Y15.12 S255
+M03 F1000 X22.93 Y15.14 S255
+M03 F1000 X23.00 Y15.15 S255
+M03 F1000 X23.08 Y15.14 S255
+M03 F1000 X23.08 Y15.12 S255
+M03 F1000 X23.11 Y15.10 S255
+M03 F1000 X23.14 Y15.05 S255
+M03 F1000 X23.15 Y15.00 S255
+M03 F1000 X23.12 Y14.93 S255
+M03 F1000 X23.08 Y14.88 S255
+M03 F1000 X23.08 Y14.86 S255
+M03 F1000 X23.00 Y14.85 S255
+M03 F1000 X22.93 Y14.86 S255
+M05 F2000 X0 Y0
\ No newline at end of file
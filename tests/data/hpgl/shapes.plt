@@ -0,0 +1,8 @@
+IN;SP1;PU400,400;PD2400,400,2400,1600,400,1600,400,400;PU;
+SP2;PU3200,1000;PD;AA3200,1600,360;PU;
+PA5000,1000;CI600;
+PU4000,2400PD4800,2400PR0,400,-800,0PA4000,2400;
+LBHello; worldPU;
+DT@;LBlabel;@SP1;PU400,2400;PD;AR400,0,-180,10;PU;
+SP3;PU6000,400;EA7200,1200;ER400,-400;PU;
+SP0;IN;
@@ -0,0 +1,177 @@
+G01
+M05 F2000 X80.00 Y25.00
+M03 F1000 X81.31 Y25.06 S120
+M03 F1000 X82.60 Y25.23 S120
+M03 F1000 X83.88 Y25.51 S120
+M03 F1000 X85.13 Y25.90 S120
+M03 F1000 X86.34 Y26.41 S120
+M03 F1000 X87.50 Y27.01 S120
+M03 F1000 X88.60 Y27.71 S120
+M03 F1000 X89.64 Y28.51 S120
+M03 F1000 X90.61 Y29.39 S120
+M03 F1000 X91.49 Y30.36 S120
+M03 F1000 X92.29 Y31.40 S120
+M03 F1000 X92.99 Y32.50 S120
+M03 F1000 X93.59 Y33.66 S120
+M03 F1000 X94.10 Y34.87 S120
+M03 F1000 X94.49 Y36.12 S120
+M03 F1000 X94.77 Y37.40 S120
+M03 F1000 X94.94 Y38.69 S120
+M03 F1000 X95.00 Y40.00 S120
+M03 F1000 X94.94 Y41.31 S120
+M03 F1000 X94.77 Y42.60 S120
+M03 F1000 X94.49 Y43.88 S120
+M03 F1000 X94.10 Y45.13 S120
+M03 F1000 X93.59 Y46.34 S120
+M03 F1000 X92.99 Y47.50 S120
+M03 F1000 X92.29 Y48.60 S120
+M03 F1000 X91.49 Y49.64 S120
+M03 F1000 X90.61 Y50.61 S120
+M03 F1000 X89.64 Y51.49 S120
+M03 F1000 X88.60 Y52.29 S120
+M03 F1000 X87.50 Y52.99 S120
+M03 F1000 X86.34 Y53.59 S120
+M03 F1000 X85.13 Y54.10 S120
+M03 F1000 X83.88 Y54.49 S120
+M03 F1000 X82.60 Y54.77 S120
+M03 F1000 X81.31 Y54.94 S120
+M03 F1000 X80.00 Y55.00 S120
+M03 F1000 X78.69 Y54.94 S120
+M03 F1000 X77.40 Y54.77 S120
+M03 F1000 X76.12 Y54.49 S120
+M03 F1000 X74.87 Y54.10 S120
+M03 F1000 X73.66 Y53.59 S120
+M03 F1000 X72.50 Y52.99 S120
+M03 F1000 X71.40 Y52.29 S120
+M03 F1000 X70.36 Y51.49 S120
+M03 F1000 X69.39 Y50.61 S120
+M03 F1000 X68.51 Y49.64 S120
+M03 F1000 X67.71 Y48.60 S120
+M03 F1000 X67.01 Y47.50 S120
+M03 F1000 X66.41 Y46.34 S120
+M03 F1000 X65.90 Y45.13 S120
+M03 F1000 X65.51 Y43.88 S120
+M03 F1000 X65.23 Y42.60 S120
+M03 F1000 X65.06 Y41.31 S120
+M03 F1000 X65.00 Y40.00 S120
+M03 F1000 X65.06 Y38.69 S120
+M03 F1000 X65.23 Y37.40 S120
+M03 F1000 X65.51 Y36.12 S120
+M03 F1000 X65.90 Y34.87 S120
+M03 F1000 X66.41 Y33.66 S120
+M03 F1000 X67.01 Y32.50 S120
+M03 F1000 X67.71 Y31.40 S120
+M03 F1000 X68.51 Y30.36 S120
+M03 F1000 X69.39 Y29.39 S120
+M03 F1000 X70.36 Y28.51 S120
+M03 F1000 X71.40 Y27.71 S120
+M03 F1000 X72.50 Y27.01 S120
+M03 F1000 X73.66 Y26.41 S120
+M03 F1000 X74.87 Y25.90 S120
+M03 F1000 X76.12 Y25.51 S120
+M03 F1000 X77.40 Y25.23 S120
+M03 F1000 X78.69 Y25.06 S120
+M03 F1000 X80.00 Y25.00 S120
+M05 F2000 X140.00 Y25.00
+M03 F1000 X139.94 Y26.31 S120
+M03 F1000 X139.77 Y27.60 S120
+M03 F1000 X139.49 Y28.88 S120
+M03 F1000 X139.10 Y30.13 S120
+M03 F1000 X138.59 Y31.34 S120
+M03 F1000 X137.99 Y32.50 S120
+M03 F1000 X137.29 Y33.60 S120
+M03 F1000 X136.49 Y34.64 S120
+M03 F1000 X135.61 Y35.61 S120
+M03 F1000 X134.64 Y36.49 S120
+M03 F1000 X133.60 Y37.29 S120
+M03 F1000 X132.50 Y37.99 S120
+M03 F1000 X131.34 Y38.59 S120
+M03 F1000 X130.13 Y39.10 S120
+M03 F1000 X128.88 Y39.49 S120
+M03 F1000 X127.60 Y39.77 S120
+M03 F1000 X126.31 Y39.94 S120
+M03 F1000 X125.00 Y40.00 S120
+M03 F1000 X123.69 Y39.94 S120
+M03 F1000 X122.40 Y39.77 S120
+M03 F1000 X121.12 Y39.49 S120
+M03 F1000 X119.87 Y39.10 S120
+M03 F1000 X118.66 Y38.59 S120
+M03 F1000 X117.50 Y37.99 S120
+M03 F1000 X116.40 Y37.29 S120
+M03 F1000 X115.36 Y36.49 S120
+M03 F1000 X114.39 Y35.61 S120
+M03 F1000 X113.51 Y34.64 S120
+M03 F1000 X112.71 Y33.60 S120
+M03 F1000 X112.01 Y32.50 S120
+M03 F1000 X111.41 Y31.34 S120
+M03 F1000 X110.90 Y30.13 S120
+M03 F1000 X110.51 Y28.88 S120
+M03 F1000 X110.23 Y27.60 S120
+M03 F1000 X110.06 Y26.31 S120
+M03 F1000 X110.00 Y25.00 S120
+M03 F1000 X110.06 Y23.69 S120
+M03 F1000 X110.23 Y22.40 S120
+M03 F1000 X110.51 Y21.12 S120
+M03 F1000 X110.90 Y19.87 S120
+M03 F1000 X111.41 Y18.66 S120
+M03 F1000 X112.01 Y17.50 S120
+M03 F1000 X112.71 Y16.40 S120
+M03 F1000 X113.51 Y15.36 S120
+M03 F1000 X114.39 Y14.39 S120
+M03 F1000 X115.36 Y13.51 S120
+M03 F1000 X116.40 Y12.71 S120
+M03 F1000 X117.50 Y12.01 S120
+M03 F1000 X118.66 Y11.41 S120
+M03 F1000 X119.87 Y10.90 S120
+M03 F1000 X121.12 Y10.51 S120
+M03 F1000 X122.40 Y10.23 S120
+M03 F1000 X123.69 Y10.06 S120
+M03 F1000 X125.00 Y10.00 S120
+M03 F1000 X126.31 Y10.06 S120
+M03 F1000 X127.60 Y10.23 S120
+M03 F1000 X128.88 Y10.51 S120
+M03 F1000 X130.13 Y10.90 S120
+M03 F1000 X131.34 Y11.41 S120
+M03 F1000 X132.50 Y12.01 S120
+M03 F1000 X133.60 Y12.71 S120
+M03 F1000 X134.64 Y13.51 S120
+M03 F1000 X135.61 Y14.39 S120
+M03 F1000 X136.49 Y15.36 S120
+M03 F1000 X137.29 Y16.40 S120
+M03 F1000 X137.99 Y17.50 S120
+M03 F1000 X138.59 Y18.66 S120
+M03 F1000 X139.10 Y19.87 S120
+M03 F1000 X139.49 Y21.12 S120
+M03 F1000 X139.77 Y22.40 S120
+M03 F1000 X139.94 Y23.69 S120
+M03 F1000 X140.00 Y25.00 S120
+M05 F2000 X100.00 Y60.00
+M03 F1000 X120.00 Y60.00 S120
+M03 F1000 X120.00 Y70.00 S120
+M03 F1000 X100.00 Y70.00 S120
+M03 F1000 X100.00 Y60.00 S120
+M05 F2000 X10.00 Y10.00
+M03 F1000 X60.00 Y10.00 S255
+M03 F1000 X60.00 Y40.00 S255
+M03 F1000 X10.00 Y40.00 S255
+M03 F1000 X10.00 Y10.00 S255
+M05 F2000 X10.00 Y60.00
+M03 F1000 X10.15 Y61.74 S255
+M03 F1000 X10.60 Y63.42 S255
+M03 F1000 X11.34 Y65.00 S255
+M03 F1000 X12.34 Y66.43 S255
+M03 F1000 X13.57 Y67.66 S255
+M03 F1000 X15.00 Y68.66 S255
+M03 F1000 X16.58 Y69.40 S255
+M03 F1000 X18.26 Y69.85 S255
+M03 F1000 X20.00 Y70.00 S255
+M03 F1000 X21.74 Y69.85 S255
+M03 F1000 X23.42 Y69.40 S255
+M03 F1000 X25.00 Y68.66 S255
+M03 F1000 X26.43 Y67.66 S255
+M03 F1000 X27.66 Y66.43 S255
+M03 F1000 X28.66 Y65.00 S255
+M03 F1000 X29.40 Y63.42 S255
+M03 F1000 X29.85 Y61.74 S255
+M03 F1000 X30.00 Y60.00 S255
+M05 F2000 X0 Y0
\ No newline at end of file
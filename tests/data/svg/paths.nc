@@ -0,0 +1,387 @@
+G01
+M05 F2000 X2.54 Y48.26
+M03 F1000 X22.86 Y48.26 S255
+M03 F1000 X22.86 Y27.94 S255
+M03 F1000 X2.54 Y27.94 S255
+M03 F1000 X2.54 Y48.26 S255
+M05 F2000 X27.94 Y48.26
+M03 F1000 X38.10 Y27.94 S255
+M03 F1000 X48.26 Y48.26 S255
+M03 F1000 X27.94 Y48.26 S255
+M05 F2000 X53.34 Y27.94
+M03 F1000 X53.35 Y28.61 S255
+M03 F1000 X53.37 Y29.26 S255
+M03 F1000 X53.41 Y29.90 S255
+M03 F1000 X53.46 Y30.53 S255
+M03 F1000 X53.52 Y31.14 S255
+M03 F1000 X53.60 Y31.73 S255
+M03 F1000 X53.69 Y32.31 S255
+M03 F1000 X53.79 Y32.88 S255
+M03 F1000 X53.91 Y33.43 S255
+M03 F1000 X54.04 Y33.96 S255
+M03 F1000 X54.18 Y34.48 S255
+M03 F1000 X54.33 Y34.98 S255
+M03 F1000 X54.49 Y35.47 S255
+M03 F1000 X54.66 Y35.95 S255
+M03 F1000 X54.85 Y36.41 S255
+M03 F1000 X55.04 Y36.85 S255
+M03 F1000 X55.24 Y37.28 S255
+M03 F1000 X55.45 Y37.69 S255
+M03 F1000 X55.67 Y38.09 S255
+M03 F1000 X55.90 Y38.48 S255
+M03 F1000 X56.14 Y38.85 S255
+M03 F1000 X56.39 Y39.20 S255
+M03 F1000 X56.64 Y39.54 S255
+M03 F1000 X56.90 Y39.86 S255
+M03 F1000 X57.17 Y40.17 S255
+M03 F1000 X57.45 Y40.46 S255
+M03 F1000 X57.73 Y40.74 S255
+M03 F1000 X58.02 Y41.01 S255
+M03 F1000 X58.31 Y41.25 S255
+M03 F1000 X58.61 Y41.49 S255
+M03 F1000 X58.91 Y41.70 S255
+M03 F1000 X59.22 Y41.91 S255
+M03 F1000 X59.53 Y42.10 S255
+M03 F1000 X59.85 Y42.27 S255
+M03 F1000 X60.17 Y42.43 S255
+M03 F1000 X60.49 Y42.57 S255
+M03 F1000 X60.82 Y42.70 S255
+M03 F1000 X61.15 Y42.81 S255
+M03 F1000 X61.48 Y42.91 S255
+M03 F1000 X61.81 Y42.99 S255
+M03 F1000 X62.15 Y43.06 S255
+M03 F1000 X62.49 Y43.11 S255
+M03 F1000 X62.82 Y43.15 S255
+M03 F1000 X63.16 Y43.17 S255
+M03 F1000 X63.50 Y43.18 S255
+M03 F1000 X63.84 Y43.17 S255
+M03 F1000 X64.18 Y43.15 S255
+M03 F1000 X64.51 Y43.11 S255
+M03 F1000 X64.85 Y43.06 S255
+M03 F1000 X65.19 Y42.99 S255
+M03 F1000 X65.52 Y42.91 S255
+M03 F1000 X65.85 Y42.81 S255
+M03 F1000 X66.18 Y42.70 S255
+M03 F1000 X66.51 Y42.57 S255
+M03 F1000 X66.83 Y42.43 S255
+M03 F1000 X67.15 Y42.27 S255
+M03 F1000 X67.47 Y42.10 S255
+M03 F1000 X67.78 Y41.91 S255
+M03 F1000 X68.09 Y41.70 S255
+M03 F1000 X68.39 Y41.49 S255
+M03 F1000 X68.69 Y41.25 S255
+M03 F1000 X68.98 Y41.01 S255
+M03 F1000 X69.27 Y40.74 S255
+M03 F1000 X69.55 Y40.46 S255
+M03 F1000 X69.83 Y40.17 S255
+M03 F1000 X70.10 Y39.86 S255
+M03 F1000 X70.36 Y39.54 S255
+M03 F1000 X70.61 Y39.20 S255
+M03 F1000 X70.86 Y38.85 S255
+M03 F1000 X71.10 Y38.48 S255
+M03 F1000 X71.33 Y38.09 S255
+M03 F1000 X71.55 Y37.69 S255
+M03 F1000 X71.76 Y37.28 S255
+M03 F1000 X71.96 Y36.85 S255
+M03 F1000 X72.15 Y36.41 S255
+M03 F1000 X72.34 Y35.95 S255
+M03 F1000 X72.51 Y35.47 S255
+M03 F1000 X72.67 Y34.98 S255
+M03 F1000 X72.82 Y34.48 S255
+M03 F1000 X72.96 Y33.96 S255
+M03 F1000 X73.09 Y33.43 S255
+M03 F1000 X73.21 Y32.88 S255
+M03 F1000 X73.31 Y32.31 S255
+M03 F1000 X73.40 Y31.73 S255
+M03 F1000 X73.48 Y31.14 S255
+M03 F1000 X73.54 Y30.53 S255
+M03 F1000 X73.59 Y29.90 S255
+M03 F1000 X73.63 Y29.26 S255
+M03 F1000 X73.65 Y28.61 S255
+M03 F1000 X73.66 Y27.94 S255
+M03 F1000 X73.67 Y27.27 S255
+M03 F1000 X73.69 Y26.62 S255
+M03 F1000 X73.73 Y25.98 S255
+M03 F1000 X73.78 Y25.35 S255
+M03 F1000 X73.84 Y24.74 S255
+M03 F1000 X73.92 Y24.15 S255
+M03 F1000 X74.01 Y23.57 S255
+M03 F1000 X74.11 Y23.00 S255
+M03 F1000 X74.23 Y22.45 S255
+M03 F1000 X74.36 Y21.92 S255
+M03 F1000 X74.50 Y21.40 S255
+M03 F1000 X74.65 Y20.90 S255
+M03 F1000 X74.81 Y20.41 S255
+M03 F1000 X74.98 Y19.93 S255
+M03 F1000 X75.17 Y19.47 S255
+M03 F1000 X75.36 Y19.03 S255
+M03 F1000 X75.56 Y18.60 S255
+M03 F1000 X75.77 Y18.19 S255
+M03 F1000 X75.99 Y17.79 S255
+M03 F1000 X76.22 Y17.40 S255
+M03 F1000 X76.46 Y17.03 S255
+M03 F1000 X76.71 Y16.68 S255
+M03 F1000 X76.96 Y16.34 S255
+M03 F1000 X77.22 Y16.02 S255
+M03 F1000 X77.49 Y15.71 S255
+M03 F1000 X77.77 Y15.42 S255
+M03 F1000 X78.05 Y15.14 S255
+M03 F1000 X78.34 Y14.87 S255
+M03 F1000 X78.63 Y14.63 S255
+M03 F1000 X78.93 Y14.39 S255
+M03 F1000 X79.23 Y14.18 S255
+M03 F1000 X79.54 Y13.97 S255
+M03 F1000 X79.85 Y13.78 S255
+M03 F1000 X80.17 Y13.61 S255
+M03 F1000 X80.49 Y13.45 S255
+M03 F1000 X80.81 Y13.31 S255
+M03 F1000 X81.14 Y13.18 S255
+M03 F1000 X81.47 Y13.07 S255
+M03 F1000 X81.80 Y12.97 S255
+M03 F1000 X82.13 Y12.89 S255
+M03 F1000 X82.47 Y12.82 S255
+M03 F1000 X82.81 Y12.77 S255
+M03 F1000 X83.14 Y12.73 S255
+M03 F1000 X83.48 Y12.71 S255
+M03 F1000 X83.82 Y12.70 S255
+M03 F1000 X84.16 Y12.71 S255
+M03 F1000 X84.50 Y12.73 S255
+M03 F1000 X84.83 Y12.77 S255
+M03 F1000 X85.17 Y12.82 S255
+M03 F1000 X85.51 Y12.89 S255
+M03 F1000 X85.84 Y12.97 S255
+M03 F1000 X86.17 Y13.07 S255
+M03 F1000 X86.50 Y13.18 S255
+M03 F1000 X86.83 Y13.31 S255
+M03 F1000 X87.15 Y13.45 S255
+M03 F1000 X87.47 Y13.61 S255
+M03 F1000 X87.79 Y13.78 S255
+M03 F1000 X88.10 Y13.97 S255
+M03 F1000 X88.41 Y14.18 S255
+M03 F1000 X88.71 Y14.39 S255
+M03 F1000 X89.01 Y14.63 S255
+M03 F1000 X89.30 Y14.87 S255
+M03 F1000 X89.59 Y15.14 S255
+M03 F1000 X89.87 Y15.42 S255
+M03 F1000 X90.15 Y15.71 S255
+M03 F1000 X90.42 Y16.02 S255
+M03 F1000 X90.68 Y16.34 S255
+M03 F1000 X90.93 Y16.68 S255
+M03 F1000 X91.18 Y17.03 S255
+M03 F1000 X91.42 Y17.40 S255
+M03 F1000 X91.65 Y17.79 S255
+M03 F1000 X91.87 Y18.19 S255
+M03 F1000 X92.08 Y18.60 S255
+M03 F1000 X92.28 Y19.03 S255
+M03 F1000 X92.47 Y19.47 S255
+M03 F1000 X92.66 Y19.93 S255
+M03 F1000 X92.83 Y20.41 S255
+M03 F1000 X92.99 Y20.90 S255
+M03 F1000 X93.14 Y21.40 S255
+M03 F1000 X93.28 Y21.92 S255
+M03 F1000 X93.41 Y22.45 S255
+M03 F1000 X93.53 Y23.00 S255
+M03 F1000 X93.63 Y23.57 S255
+M03 F1000 X93.72 Y24.15 S255
+M03 F1000 X93.80 Y24.74 S255
+M03 F1000 X93.86 Y25.35 S255
+M03 F1000 X93.91 Y25.98 S255
+M03 F1000 X93.95 Y26.62 S255
+M03 F1000 X93.97 Y27.27 S255
+M03 F1000 X93.98 Y27.94 S255
+M05 F2000 X2.54 Y12.70
+M03 F1000 X3.01 Y13.16 S255
+M03 F1000 X3.49 Y13.60 S255
+M03 F1000 X3.96 Y14.02 S255
+M03 F1000 X4.43 Y14.41 S255
+M03 F1000 X4.90 Y14.79 S255
+M03 F1000 X5.38 Y15.14 S255
+M03 F1000 X5.85 Y15.47 S255
+M03 F1000 X6.32 Y15.78 S255
+M03 F1000 X6.79 Y16.06 S255
+M03 F1000 X7.27 Y16.33 S255
+M03 F1000 X7.74 Y16.57 S255
+M03 F1000 X8.21 Y16.79 S255
+M03 F1000 X8.68 Y16.99 S255
+M03 F1000 X9.16 Y17.16 S255
+M03 F1000 X9.63 Y17.32 S255
+M03 F1000 X10.10 Y17.45 S255
+M03 F1000 X10.57 Y17.56 S255
+M03 F1000 X11.05 Y17.65 S255
+M03 F1000 X11.52 Y17.71 S255
+M03 F1000 X11.99 Y17.76 S255
+M03 F1000 X12.46 Y17.78 S255
+M03 F1000 X12.94 Y17.78 S255
+M03 F1000 X13.41 Y17.76 S255
+M03 F1000 X13.88 Y17.71 S255
+M03 F1000 X14.35 Y17.65 S255
+M03 F1000 X14.83 Y17.56 S255
+M03 F1000 X15.30 Y17.45 S255
+M03 F1000 X15.77 Y17.32 S255
+M03 F1000 X16.24 Y17.16 S255
+M03 F1000 X16.72 Y16.99 S255
+M03 F1000 X17.19 Y16.79 S255
+M03 F1000 X17.66 Y16.57 S255
+M03 F1000 X18.13 Y16.33 S255
+M03 F1000 X18.61 Y16.06 S255
+M03 F1000 X19.08 Y15.78 S255
+M03 F1000 X19.55 Y15.47 S255
+M03 F1000 X20.02 Y15.14 S255
+M03 F1000 X20.50 Y14.79 S255
+M03 F1000 X20.97 Y14.41 S255
+M03 F1000 X21.44 Y14.02 S255
+M03 F1000 X21.91 Y13.60 S255
+M03 F1000 X22.39 Y13.16 S255
+M03 F1000 X22.86 Y12.70 S255
+M03 F1000 X23.33 Y12.24 S255
+M03 F1000 X23.81 Y11.80 S255
+M03 F1000 X24.28 Y11.38 S255
+M03 F1000 X24.75 Y10.99 S255
+M03 F1000 X25.22 Y10.61 S255
+M03 F1000 X25.70 Y10.26 S255
+M03 F1000 X26.17 Y9.93 S255
+M03 F1000 X26.64 Y9.62 S255
+M03 F1000 X27.11 Y9.34 S255
+M03 F1000 X27.59 Y9.07 S255
+M03 F1000 X28.06 Y8.83 S255
+M03 F1000 X28.53 Y8.61 S255
+M03 F1000 X29.00 Y8.41 S255
+M03 F1000 X29.48 Y8.24 S255
+M03 F1000 X29.95 Y8.08 S255
+M03 F1000 X30.42 Y7.95 S255
+M03 F1000 X30.89 Y7.84 S255
+M03 F1000 X31.37 Y7.75 S255
+M03 F1000 X31.84 Y7.69 S255
+M03 F1000 X32.31 Y7.64 S255
+M03 F1000 X32.78 Y7.62 S255
+M03 F1000 X33.26 Y7.62 S255
+M03 F1000 X33.73 Y7.64 S255
+M03 F1000 X34.20 Y7.69 S255
+M03 F1000 X34.67 Y7.75 S255
+M03 F1000 X35.15 Y7.84 S255
+M03 F1000 X35.62 Y7.95 S255
+M03 F1000 X36.09 Y8.08 S255
+M03 F1000 X36.56 Y8.24 S255
+M03 F1000 X37.04 Y8.41 S255
+M03 F1000 X37.51 Y8.61 S255
+M03 F1000 X37.98 Y8.83 S255
+M03 F1000 X38.45 Y9.07 S255
+M03 F1000 X38.93 Y9.34 S255
+M03 F1000 X39.40 Y9.62 S255
+M03 F1000 X39.87 Y9.93 S255
+M03 F1000 X40.34 Y10.26 S255
+M03 F1000 X40.82 Y10.61 S255
+M03 F1000 X41.29 Y10.99 S255
+M03 F1000 X41.76 Y11.38 S255
+M03 F1000 X42.23 Y11.80 S255
+M03 F1000 X42.71 Y12.24 S255
+M03 F1000 X43.18 Y12.70 S255
+M05 F2000 X50.80 Y12.70
+M03 F1000 X50.83 Y12.18 S255
+M03 F1000 X50.94 Y11.65 S255
+M03 F1000 X51.11 Y11.14 S255
+M03 F1000 X51.35 Y10.64 S255
+M03 F1000 X51.66 Y10.15 S255
+M03 F1000 X52.02 Y9.68 S255
+M03 F1000 X52.45 Y9.23 S255
+M03 F1000 X52.94 Y8.80 S255
+M03 F1000 X53.49 Y8.40 S255
+M03 F1000 X54.08 Y8.03 S255
+M03 F1000 X54.72 Y7.69 S255
+M03 F1000 X55.40 Y7.38 S255
+M03 F1000 X56.12 Y7.12 S255
+M03 F1000 X56.88 Y6.88 S255
+M03 F1000 X57.66 Y6.69 S255
+M03 F1000 X58.47 Y6.54 S255
+M03 F1000 X59.29 Y6.44 S255
+M03 F1000 X60.12 Y6.37 S255
+M03 F1000 X60.96 Y6.35 S255
+M03 F1000 X61.80 Y6.37 S255
+M03 F1000 X62.63 Y6.44 S255
+M03 F1000 X63.45 Y6.54 S255
+M03 F1000 X64.26 Y6.69 S255
+M03 F1000 X65.04 Y6.88 S255
+M03 F1000 X65.80 Y7.12 S255
+M03 F1000 X66.52 Y7.38 S255
+M03 F1000 X67.20 Y7.69 S255
+M03 F1000 X67.84 Y8.03 S255
+M03 F1000 X68.43 Y8.40 S255
+M03 F1000 X68.98 Y8.80 S255
+M03 F1000 X69.47 Y9.23 S255
+M03 F1000 X69.90 Y9.68 S255
+M03 F1000 X70.26 Y10.15 S255
+M03 F1000 X70.57 Y10.64 S255
+M03 F1000 X70.81 Y11.14 S255
+M03 F1000 X70.98 Y11.65 S255
+M03 F1000 X71.09 Y12.18 S255
+M03 F1000 X71.12 Y12.70 S255
+M03 F1000 X71.09 Y12.18 S255
+M03 F1000 X70.98 Y11.65 S255
+M03 F1000 X70.81 Y11.14 S255
+M03 F1000 X70.57 Y10.64 S255
+M03 F1000 X70.26 Y10.15 S255
+M03 F1000 X69.90 Y9.68 S255
+M03 F1000 X69.47 Y9.23 S255
+M03 F1000 X68.98 Y8.80 S255
+M03 F1000 X68.43 Y8.40 S255
+M03 F1000 X67.84 Y8.03 S255
+M03 F1000 X67.20 Y7.69 S255
+M03 F1000 X66.52 Y7.38 S255
+M03 F1000 X65.80 Y7.12 S255
+M03 F1000 X65.04 Y6.88 S255
+M03 F1000 X64.26 Y6.69 S255
+M03 F1000 X63.45 Y6.54 S255
+M03 F1000 X62.63 Y6.44 S255
+M03 F1000 X61.80 Y6.37 S255
+M03 F1000 X60.96 Y6.35 S255
+M03 F1000 X60.12 Y6.37 S255
+M03 F1000 X59.29 Y6.44 S255
+M03 F1000 X58.47 Y6.54 S255
+M03 F1000 X57.66 Y6.69 S255
+M03 F1000 X56.88 Y6.88 S255
+M03 F1000 X56.12 Y7.12 S255
+M03 F1000 X55.40 Y7.38 S255
+M03 F1000 X54.72 Y7.69 S255
+M03 F1000 X54.08 Y8.03 S255
+M03 F1000 X53.49 Y8.40 S255
+M03 F1000 X52.94 Y8.80 S255
+M03 F1000 X52.45 Y9.23 S255
+M03 F1000 X52.02 Y9.68 S255
+M03 F1000 X51.66 Y10.15 S255
+M03 F1000 X51.35 Y10.64 S255
+M03 F1000 X51.11 Y11.14 S255
+M03 F1000 X50.94 Y11.65 S255
+M03 F1000 X50.83 Y12.18 S255
+M03 F1000 X50.80 Y12.70 S255
+M05 F2000 X76.33 Y50.67
+M03 F1000 X0.13 Y48.26 S255
+M03 F1000 X81.28 Y5.08 S255
+M03 F1000 X81.41 Y4.95 S255
+M03 F1000 X86.49 Y12.57 S255
+M03 F1000 X89.03 Y12.57 S255
+M03 F1000 X89.03 Y17.65 S255
+M05 F2000 X86.36 Y2.54
+M03 F1000 X86.41 Y3.23 S255
+M03 F1000 X86.55 Y3.91 S255
+M03 F1000 X86.78 Y4.56 S255
+M03 F1000 X87.10 Y5.18 S255
+M03 F1000 X87.50 Y5.75 S255
+M03 F1000 X87.97 Y6.25 S255
+M03 F1000 X88.51 Y6.69 S255
+M03 F1000 X89.10 Y7.05 S255
+M03 F1000 X89.74 Y7.33 S255
+M03 F1000 X90.41 Y7.51 S255
+M03 F1000 X91.09 Y7.61 S255
+M03 F1000 X91.79 Y7.61 S255
+M03 F1000 X92.47 Y7.51 S255
+M03 F1000 X93.14 Y7.33 S255
+M03 F1000 X93.78 Y7.05 S255
+M03 F1000 X94.37 Y6.69 S255
+M03 F1000 X94.91 Y6.25 S255
+M03 F1000 X95.38 Y5.75 S255
+M03 F1000 X95.78 Y5.18 S255
+M03 F1000 X96.10 Y4.56 S255
+M03 F1000 X96.33 Y3.91 S255
+M03 F1000 X96.47 Y3.23 S255
+M03 F1000 X96.52 Y2.54 S255
+M05 F2000 X0 Y0
\ No newline at end of file
@@ -0,0 +1,247 @@
+G01
+M05 F2000 X5.00 Y75.00
+M03 F1000 X35.00 Y75.00 S200
+M03 F1000 X35.00 Y55.00 S200
+M03 F1000 X5.00 Y55.00 S200
+M03 F1000 X5.00 Y75.00 S200
+M05 F2000 X49.00 Y75.00
+M03 F1000 X71.00 Y75.00 S200
+M03 F1000 X71.52 Y74.97 S200
+M03 F1000 X72.04 Y74.86 S200
+M03 F1000 X72.53 Y74.70 S200
+M03 F1000 X73.00 Y74.46 S200
+M03 F1000 X73.44 Y74.17 S200
+M03 F1000 X73.83 Y73.83 S200
+M03 F1000 X74.17 Y73.44 S200
+M03 F1000 X74.46 Y73.00 S200
+M03 F1000 X74.70 Y72.53 S200
+M03 F1000 X74.86 Y72.04 S200
+M03 F1000 X74.97 Y71.52 S200
+M03 F1000 X75.00 Y71.00 S200
+M03 F1000 X75.00 Y59.00 S200
+M03 F1000 X74.97 Y58.48 S200
+M03 F1000 X74.86 Y57.96 S200
+M03 F1000 X74.70 Y57.47 S200
+M03 F1000 X74.46 Y57.00 S200
+M03 F1000 X74.17 Y56.56 S200
+M03 F1000 X73.83 Y56.17 S200
+M03 F1000 X73.44 Y55.83 S200
+M03 F1000 X73.00 Y55.54 S200
+M03 F1000 X72.53 Y55.30 S200
+M03 F1000 X72.04 Y55.14 S200
+M03 F1000 X71.52 Y55.03 S200
+M03 F1000 X71.00 Y55.00 S200
+M03 F1000 X49.00 Y55.00 S200
+M03 F1000 X48.48 Y55.03 S200
+M03 F1000 X47.96 Y55.14 S200
+M03 F1000 X47.47 Y55.30 S200
+M03 F1000 X47.00 Y55.54 S200
+M03 F1000 X46.56 Y55.83 S200
+M03 F1000 X46.17 Y56.17 S200
+M03 F1000 X45.83 Y56.56 S200
+M03 F1000 X45.54 Y57.00 S200
+M03 F1000 X45.30 Y57.47 S200
+M03 F1000 X45.14 Y57.96 S200
+M03 F1000 X45.03 Y58.48 S200
+M03 F1000 X45.00 Y59.00 S200
+M03 F1000 X45.00 Y71.00 S200
+M03 F1000 X45.03 Y71.52 S200
+M03 F1000 X45.14 Y72.04 S200
+M03 F1000 X45.30 Y72.53 S200
+M03 F1000 X45.54 Y73.00 S200
+M03 F1000 X45.83 Y73.44 S200
+M03 F1000 X46.17 Y73.83 S200
+M03 F1000 X46.56 Y74.17 S200
+M03 F1000 X47.00 Y74.46 S200
+M03 F1000 X47.47 Y74.70 S200
+M03 F1000 X47.96 Y74.86 S200
+M03 F1000 X48.48 Y74.97 S200
+M03 F1000 X49.00 Y75.00 S200
+M05 F2000 X30.00 Y30.00
+M03 F1000 X29.98 Y29.30 S100
+M03 F1000 X29.90 Y28.61 S100
+M03 F1000 X29.78 Y27.92 S100
+M03 F1000 X29.61 Y27.24 S100
+M03 F1000 X29.40 Y26.58 S100
+M03 F1000 X29.14 Y25.93 S100
+M03 F1000 X28.83 Y25.31 S100
+M03 F1000 X28.48 Y24.70 S100
+M03 F1000 X28.09 Y24.12 S100
+M03 F1000 X27.66 Y23.57 S100
+M03 F1000 X27.19 Y23.05 S100
+M03 F1000 X26.69 Y22.57 S100
+M03 F1000 X26.16 Y22.12 S100
+M03 F1000 X25.59 Y21.71 S100
+M03 F1000 X25.00 Y21.34 S100
+M03 F1000 X24.38 Y21.01 S100
+M03 F1000 X23.75 Y20.73 S100
+M03 F1000 X23.09 Y20.49 S100
+M03 F1000 X22.42 Y20.30 S100
+M03 F1000 X21.74 Y20.15 S100
+M03 F1000 X21.05 Y20.05 S100
+M03 F1000 X20.35 Y20.01 S100
+M03 F1000 X19.65 Y20.01 S100
+M03 F1000 X18.95 Y20.05 S100
+M03 F1000 X18.26 Y20.15 S100
+M03 F1000 X17.58 Y20.30 S100
+M03 F1000 X16.91 Y20.49 S100
+M03 F1000 X16.25 Y20.73 S100
+M03 F1000 X15.62 Y21.01 S100
+M03 F1000 X15.00 Y21.34 S100
+M03 F1000 X14.41 Y21.71 S100
+M03 F1000 X13.84 Y22.12 S100
+M03 F1000 X13.31 Y22.57 S100
+M03 F1000 X12.81 Y23.05 S100
+M03 F1000 X12.34 Y23.57 S100
+M03 F1000 X11.91 Y24.12 S100
+M03 F1000 X11.52 Y24.70 S100
+M03 F1000 X11.17 Y25.31 S100
+M03 F1000 X10.86 Y25.93 S100
+M03 F1000 X10.60 Y26.58 S100
+M03 F1000 X10.39 Y27.24 S100
+M03 F1000 X10.22 Y27.92 S100
+M03 F1000 X10.10 Y28.61 S100
+M03 F1000 X10.02 Y29.30 S100
+M03 F1000 X10.00 Y30.00 S100
+M03 F1000 X10.02 Y30.70 S100
+M03 F1000 X10.10 Y31.39 S100
+M03 F1000 X10.22 Y32.08 S100
+M03 F1000 X10.39 Y32.76 S100
+M03 F1000 X10.60 Y33.42 S100
+M03 F1000 X10.86 Y34.07 S100
+M03 F1000 X11.17 Y34.69 S100
+M03 F1000 X11.52 Y35.30 S100
+M03 F1000 X11.91 Y35.88 S100
+M03 F1000 X12.34 Y36.43 S100
+M03 F1000 X12.81 Y36.95 S100
+M03 F1000 X13.31 Y37.43 S100
+M03 F1000 X13.84 Y37.88 S100
+M03 F1000 X14.41 Y38.29 S100
+M03 F1000 X15.00 Y38.66 S100
+M03 F1000 X15.62 Y38.99 S100
+M03 F1000 X16.25 Y39.27 S100
+M03 F1000 X16.91 Y39.51 S100
+M03 F1000 X17.58 Y39.70 S100
+M03 F1000 X18.26 Y39.85 S100
+M03 F1000 X18.95 Y39.95 S100
+M03 F1000 X19.65 Y39.99 S100
+M03 F1000 X20.35 Y39.99 S100
+M03 F1000 X21.05 Y39.95 S100
+M03 F1000 X21.74 Y39.85 S100
+M03 F1000 X22.42 Y39.70 S100
+M03 F1000 X23.09 Y39.51 S100
+M03 F1000 X23.75 Y39.27 S100
+M03 F1000 X24.38 Y38.99 S100
+M03 F1000 X25.00 Y38.66 S100
+M03 F1000 X25.59 Y38.29 S100
+M03 F1000 X26.16 Y37.88 S100
+M03 F1000 X26.69 Y37.43 S100
+M03 F1000 X27.19 Y36.95 S100
+M03 F1000 X27.66 Y36.43 S100
+M03 F1000 X28.09 Y35.88 S100
+M03 F1000 X28.48 Y35.30 S100
+M03 F1000 X28.83 Y34.69 S100
+M03 F1000 X29.14 Y34.07 S100
+M03 F1000 X29.40 Y33.42 S100
+M03 F1000 X29.61 Y32.76 S100
+M03 F1000 X29.78 Y32.08 S100
+M03 F1000 X29.90 Y31.39 S100
+M03 F1000 X29.98 Y30.70 S100
+M03 F1000 X30.00 Y30.00 S100
+M05 F2000 X75.00 Y30.00
+M03 F1000 X74.96 Y29.48 S100
+M03 F1000 X74.85 Y28.96 S100
+M03 F1000 X74.67 Y28.44 S100
+M03 F1000 X74.42 Y27.93 S100
+M03 F1000 X74.10 Y27.43 S100
+M03 F1000 X73.70 Y26.95 S100
+M03 F1000 X73.24 Y26.48 S100
+M03 F1000 X72.72 Y26.03 S100
+M03 F1000 X72.14 Y25.59 S100
+M03 F1000 X71.49 Y25.18 S100
+M03 F1000 X70.79 Y24.79 S100
+M03 F1000 X70.04 Y24.43 S100
+M03 F1000 X69.23 Y24.09 S100
+M03 F1000 X68.39 Y23.78 S100
+M03 F1000 X67.50 Y23.50 S100
+M03 F1000 X66.58 Y23.26 S100
+M03 F1000 X65.62 Y23.05 S100
+M03 F1000 X64.64 Y22.87 S100
+M03 F1000 X63.63 Y22.72 S100
+M03 F1000 X62.60 Y22.61 S100
+M03 F1000 X61.57 Y22.54 S100
+M03 F1000 X60.52 Y22.50 S100
+M03 F1000 X59.48 Y22.50 S100
+M03 F1000 X58.43 Y22.54 S100
+M03 F1000 X57.40 Y22.61 S100
+M03 F1000 X56.37 Y22.72 S100
+M03 F1000 X55.36 Y22.87 S100
+M03 F1000 X54.38 Y23.05 S100
+M03 F1000 X53.42 Y23.26 S100
+M03 F1000 X52.50 Y23.50 S100
+M03 F1000 X51.61 Y23.78 S100
+M03 F1000 X50.77 Y24.09 S100
+M03 F1000 X49.96 Y24.43 S100
+M03 F1000 X49.21 Y24.79 S100
+M03 F1000 X48.51 Y25.18 S100
+M03 F1000 X47.86 Y25.59 S100
+M03 F1000 X47.28 Y26.03 S100
+M03 F1000 X46.76 Y26.48 S100
+M03 F1000 X46.30 Y26.95 S100
+M03 F1000 X45.90 Y27.43 S100
+M03 F1000 X45.58 Y27.93 S100
+M03 F1000 X45.33 Y28.44 S100
+M03 F1000 X45.15 Y28.96 S100
+M03 F1000 X45.04 Y29.48 S100
+M03 F1000 X45.00 Y30.00 S100
+M03 F1000 X45.04 Y30.52 S100
+M03 F1000 X45.15 Y31.04 S100
+M03 F1000 X45.33 Y31.56 S100
+M03 F1000 X45.58 Y32.07 S100
+M03 F1000 X45.90 Y32.57 S100
+M03 F1000 X46.30 Y33.05 S100
+M03 F1000 X46.76 Y33.52 S100
+M03 F1000 X47.28 Y33.97 S100
+M03 F1000 X47.86 Y34.41 S100
+M03 F1000 X48.51 Y34.82 S100
+M03 F1000 X49.21 Y35.21 S100
+M03 F1000 X49.96 Y35.57 S100
+M03 F1000 X50.77 Y35.91 S100
+M03 F1000 X51.61 Y36.22 S100
+M03 F1000 X52.50 Y36.50 S100
+M03 F1000 X53.42 Y36.74 S100
+M03 F1000 X54.38 Y36.95 S100
+M03 F1000 X55.36 Y37.13 S100
+M03 F1000 X56.37 Y37.28 S100
+M03 F1000 X57.40 Y37.39 S100
+M03 F1000 X58.43 Y37.46 S100
+M03 F1000 X59.48 Y37.50 S100
+M03 F1000 X60.52 Y37.50 S100
+M03 F1000 X61.57 Y37.46 S100
+M03 F1000 X62.60 Y37.39 S100
+M03 F1000 X63.63 Y37.28 S100
+M03 F1000 X64.64 Y37.13 S100
+M03 F1000 X65.62 Y36.95 S100
+M03 F1000 X66.58 Y36.74 S100
+M03 F1000 X67.50 Y36.50 S100
+M03 F1000 X68.39 Y36.22 S100
+M03 F1000 X69.23 Y35.91 S100
+M03 F1000 X70.04 Y35.57 S100
+M03 F1000 X70.79 Y35.21 S100
+M03 F1000 X71.49 Y34.82 S100
+M03 F1000 X72.14 Y34.41 S100
+M03 F1000 X72.72 Y33.97 S100
+M03 F1000 X73.24 Y33.52 S100
+M03 F1000 X73.70 Y33.05 S100
+M03 F1000 X74.10 Y32.57 S100
+M03 F1000 X74.42 Y32.07 S100
+M03 F1000 X74.67 Y31.56 S100
+M03 F1000 X74.85 Y31.04 S100
+M03 F1000 X74.96 Y30.52 S100
+M03 F1000 X75.00 Y30.00 S100
+M05 F2000 X50.00 Y27.07
+M03 F1000 X57.07 Y20.00 S100
+M03 F1000 X50.00 Y12.93 S100
+M03 F1000 X42.93 Y20.00 S100
+M03 F1000 X50.00 Y27.07 S100
+M05 F2000 X0 Y0
\ No newline at end of file
@@ -1,5 +1,6 @@
-use std::fs::read_to_string;
-use rust_cnc::{process};
+use std::fs::{read, read_to_string, File};
+use std::io::BufReader;
+use rust_cnc::{export_toolpaths, export_toolpaths_bytes, process, process_bytes_with_options, process_reader, process_reader_with_options, process_with_options, Drill, DxfOptions, FillOptions, FillRule, HatchMode, OutputUnits, PointAction, Pulse, Unit};
 
 const DATA_PATH: &str = "tests/data/dxf/";
 
@@ -23,9 +24,313 @@ fn dxf_bulge_anticlockwise_test() {
     test_dxf("bulge2.dxf", "bulge2.nc");
 }
 
+#[test]
+fn dxf_text_entities_test() {
+    test_dxf("text_entities.dxf", "text_entities.nc");
+}
+
+#[test]
+fn dxf_group_code_order_test() {
+    test_dxf("reordered.dxf", "reordered.nc");
+}
+
+#[test]
+fn dxf_r12_sections_test() {
+    test_dxf("r12_sections.dxf", "r12_sections.nc");
+}
+
+#[test]
+fn dxf_inserts_test() {
+    test_dxf("inserts.dxf", "inserts.nc");
+}
+
+#[test]
+fn dxf_fill_test() {
+    let mut fill = FillOptions::new(0.5);
+    fill.angle = 45.0;
+
+    let options = DxfOptions { fill: Some(fill), ..DxfOptions::default() };
+
+    test_dxf_with_options("shapes.dxf", "shapes_fill.nc", &options);
+}
+
+#[test]
+fn dxf_cross_hatch_without_outline_test() {
+    let mut fill = FillOptions::new(0.5);
+    fill.cross_hatch = true;
+    fill.rule = FillRule::NonZero;
+    fill.bidirectional = false;
+
+    let options = DxfOptions { fill: Some(fill), outline: false, ..DxfOptions::default() };
+
+    test_dxf_with_options("text.dxf", "text_fill.nc", &options);
+}
+
+#[test]
+fn dxf_hatch_fill_test() {
+    let options = DxfOptions {
+        fill: Some(FillOptions::new(1.0)),
+        hatches: HatchMode::Fill(FillOptions::new(1.0)),
+        ..DxfOptions::default()
+    };
+
+    test_dxf_with_options("hatch.dxf", "hatch_fill.nc", &options);
+}
+
+#[test]
+fn dxf_hatch_dash_pattern_test() {
+    let options = DxfOptions { hatches: HatchMode::Fill(FillOptions::new(1.0)), outline: false, ..DxfOptions::default() };
+
+    test_dxf_with_options("hatch_dashed.dxf", "hatch_dashed.nc", &options);
+}
+
+#[test]
+fn dxf_hatches_ignored_by_default_test() {
+    let dxf = read_to_string(DATA_PATH.to_owned() + "hatch_dashed.dxf").unwrap();
+
+    assert_eq!("G01\nM05 F2000 X0 Y0", process(&dxf));
+}
+
+#[test]
+fn dxf_hatch_boundary_test() {
+    let options = DxfOptions { hatches: HatchMode::Boundary, ..DxfOptions::default() };
+
+    test_dxf_with_options("hatch.dxf", "hatch_boundary.nc", &options);
+}
+
+#[test]
+fn dxf_solid_and_face_test() {
+    test_dxf("solids.dxf", "solids.nc");
+}
+
+#[test]
+fn dxf_solid_and_face_fill_test() {
+    let options = DxfOptions { fill: Some(FillOptions::new(1.0)), ..DxfOptions::default() };
+
+    test_dxf_with_options("solids.dxf", "solids_fill.nc", &options);
+}
+
+#[test]
+fn dxf_point_pulse_test() {
+    let options = DxfOptions { points: PointAction::Pulse(Pulse::new(0.1)), ..DxfOptions::default() };
+
+    test_dxf_with_options("points.dxf", "points_pulse.nc", &options);
+}
+
+#[test]
+fn dxf_points_ignored_by_default_test() {
+    let options = DxfOptions { points: PointAction::Ignore, ..DxfOptions::default() };
+
+    test_dxf_with_options("points.dxf", "points_outline.nc", &options);
+    test_dxf("points.dxf", "points_outline.nc");
+}
+
+#[test]
+fn dxf_point_peck_drill_test() {
+    let mut drill = Drill::new(3.0);
+    drill.peck = Some(1.25);
+
+    let options = DxfOptions { points: PointAction::Drill(drill), outline: false, ..DxfOptions::default() };
+
+    test_dxf_with_options("points.dxf", "points_drill.nc", &options);
+}
+
+#[test]
+fn dxf_point_drill_inches_test() {
+    let mut drill = Drill::new(2.0);
+    drill.peck = Some(0.5);
+    drill.spindle_speed = Some(12000.0);
+
+    let options = DxfOptions {
+        points: PointAction::Drill(drill),
+        outline: false,
+        output_units: OutputUnits::Inches,
+        unit_command: true,
+        ..DxfOptions::default()
+    };
+
+    test_dxf_with_options("points.dxf", "points_drill_inches.nc", &options);
+}
+
+#[test]
+fn dxf_point_expanded_peck_drill_test() {
+    let mut drill = Drill::new(3.0);
+    drill.peck = Some(1.25);
+    drill.retract = 1.0;
+    drill.canned = false;
+
+    let options = DxfOptions { points: PointAction::Drill(drill), outline: false, ..DxfOptions::default() };
+
+    test_dxf_with_options("points.dxf", "points_peck.nc", &options);
+}
+
+#[test]
+fn dxf_point_drill_then_outline_test() {
+    let options = DxfOptions { points: PointAction::Drill(Drill::new(3.0)), ..DxfOptions::default() };
+
+    test_dxf_with_options("points.dxf", "points_drill_outline.nc", &options);
+}
+
+#[test]
+fn dxf_inch_drawing_scaled_to_millimetres_test() {
+    let options = DxfOptions { points: PointAction::Pulse(Pulse::new(0.1)), ..DxfOptions::default() };
+
+    test_dxf_with_options("inches.dxf", "inches.nc", &options);
+}
+
+#[test]
+fn dxf_inch_output_test() {
+    let options = DxfOptions {
+        output_units: OutputUnits::Inches,
+        unit_command: true,
+        points: PointAction::Pulse(Pulse::new(0.1)),
+        ..DxfOptions::default()
+    };
+
+    test_dxf_with_options("inches.dxf", "inches_g20.nc", &options);
+}
+
+#[test]
+fn dxf_inch_curves_divided_like_millimetres_test() {
+    let circle = |insunits: u8, x: f64, radius: f64| format!(
+        "0\nSECTION\n2\nHEADER\n9\n$INSUNITS\n70\n{}\n0\nENDSEC\n0\nSECTION\n2\nENTITIES\n\
+        0\nCIRCLE\n10\n{}\n20\n{}\n40\n{}\n0\nENDSEC\n0\nEOF\n",
+        insunits, x, x, radius
+    );
+
+    let inches = process(&circle(1, 1.0, 0.5));
+
+    assert_eq!(process(&circle(4, 25.4, 12.7)), inches);
+    assert!(inches.lines().count() > 50);
+}
+
+#[test]
+fn dxf_unit_override_test() {
+    let options = DxfOptions { units: Some(Unit::Millimetres), points: PointAction::Pulse(Pulse::new(0.1)), ..DxfOptions::default() };
+
+    test_dxf_with_options("inches.dxf", "inches_unscaled.nc", &options);
+}
+
+#[test]
+fn dxf_binary_test() {
+    let options = DxfOptions { hatches: HatchMode::Boundary, ..DxfOptions::default() };
+
+    test_dxf_bytes("hatch_binary.dxf", "hatch_boundary.nc", &options);
+}
+
+#[test]
+fn dxf_binary_single_byte_group_code_test() {
+    test_dxf_bytes("shapes_r12_binary.dxf", "shapes.nc", &DxfOptions::default());
+}
+
+#[test]
+fn dxf_ascii_bytes_test() {
+    test_dxf_bytes("text.dxf", "text.nc", &DxfOptions::default());
+}
+
+#[test]
+fn dxf_crlf_reader_test() {
+    let dxf = read_to_string(DATA_PATH.to_owned() + "shapes.dxf").unwrap().replace('\r', "").replace('\n', "\r\n");
+    let gcode = read_to_string(DATA_PATH.to_owned() + "shapes.nc").unwrap().replace('\r', "");
+
+    assert_eq!(gcode.trim_end(), process_reader(dxf.as_bytes()).unwrap().trim_end());
+}
+
+#[test]
+fn dxf_binary_file_reader_test() {
+    let file = File::open(DATA_PATH.to_owned() + "hatch_binary.dxf").unwrap();
+    let gcode = read_to_string(DATA_PATH.to_owned() + "hatch_boundary.nc").unwrap().replace('\r', "");
+    let options = DxfOptions { hatches: HatchMode::Boundary, ..DxfOptions::default() };
+
+    assert_eq!(gcode.trim_end(), process_reader_with_options(BufReader::new(file), &options).unwrap().trim_end());
+}
+
+#[test]
+fn dxf_binary_small_buffer_reader_test() {
+    let file = File::open(DATA_PATH.to_owned() + "shapes_r12_binary.dxf").unwrap();
+    let gcode = read_to_string(DATA_PATH.to_owned() + "shapes.nc").unwrap().replace('\r', "");
+
+    assert_eq!(gcode.trim_end(), process_reader(BufReader::with_capacity(4, file)).unwrap().trim_end());
+}
+
+#[test]
+fn dxf_toolpath_export_test() {
+    let mut fill = FillOptions::new(2.0);
+    fill.angle = 45.0;
+
+    let options = DxfOptions { fill: Some(fill), ..DxfOptions::default() };
+
+    test_toolpath_export("shapes.dxf", "shapes_toolpaths.dxf", &options);
+}
+
+#[test]
+fn dxf_point_toolpath_export_test() {
+    let options = DxfOptions { points: PointAction::Pulse(Pulse::new(0.1)), ..DxfOptions::default() };
+
+    test_toolpath_export("points.dxf", "points_toolpaths.dxf", &options);
+}
+
+#[test]
+fn dxf_binary_toolpath_export_test() {
+    let mut fill = FillOptions::new(2.0);
+    fill.angle = 45.0;
+
+    let options = DxfOptions { fill: Some(fill), ..DxfOptions::default() };
+    let dxf = read(DATA_PATH.to_owned() + "shapes_r12_binary.dxf").unwrap();
+    let export = read_to_string(DATA_PATH.to_owned() + "shapes_toolpaths.dxf").unwrap().replace('\r', "");
+
+    assert_eq!(export.trim_end(), export_toolpaths_bytes(&dxf, &options).unwrap().trim_end());
+}
+
+#[test]
+fn dxf_toolpath_export_units_test() {
+    let dxf = read_to_string(DATA_PATH.to_owned() + "shapes.dxf").unwrap();
+    let inches = export_toolpaths(&dxf, &DxfOptions { output_units: OutputUnits::Inches, ..DxfOptions::default() });
+
+    // R12 drawings only say whether they are imperial or metric, and imperial is read back as inches
+    assert!(inches.contains("$MEASUREMENT\n 70\n0\n"));
+    assert_eq!(process(&export_toolpaths(&dxf, &DxfOptions::default())), process(&inches));
+}
+
+#[test]
+fn dxf_toolpath_export_reimport_test() {
+    let dxf = read_to_string(DATA_PATH.to_owned() + "shapes.dxf").unwrap();
+    let options = DxfOptions { points: PointAction::Ignore, ..DxfOptions::default() };
+    let reimported = process_with_options(&export_toolpaths(&dxf, &options), &options);
+
+    // Travel moves come back as extra lines and may be joined onto the cuts, but every cut position should still be there
+    let position = |line: &str| line.split(' ').filter(|word| word.starts_with('X') || word.starts_with('Y')).collect::<Vec<_>>().join(" ");
+    let reimported: Vec<String> = reimported.lines().map(position).collect();
+
+    for line in process_with_options(&dxf, &options).lines().filter(|line| line.starts_with("M03")) {
+        assert!(reimported.contains(&position(line)), "missing {}", line);
+    }
+}
+
 fn test_dxf(dxf_file: &str, nc_file: &str) {
     let dxf = read_to_string(DATA_PATH.to_owned() + dxf_file).unwrap();
     let gcode = read_to_string(DATA_PATH.to_owned() + nc_file).unwrap().replace('\r', "");
 
     assert_eq!(gcode.trim_end(), process(&dxf).trim_end());
 }
+
+fn test_dxf_with_options(dxf_file: &str, nc_file: &str, options: &DxfOptions) {
+    let dxf = read_to_string(DATA_PATH.to_owned() + dxf_file).unwrap();
+    let gcode = read_to_string(DATA_PATH.to_owned() + nc_file).unwrap().replace('\r', "");
+
+    assert_eq!(gcode.trim_end(), process_with_options(&dxf, options).trim_end());
+}
+
+fn test_dxf_bytes(dxf_file: &str, nc_file: &str, options: &DxfOptions) {
+    let dxf = read(DATA_PATH.to_owned() + dxf_file).unwrap();
+    let gcode = read_to_string(DATA_PATH.to_owned() + nc_file).unwrap().replace('\r', "");
+
+    assert_eq!(gcode.trim_end(), process_bytes_with_options(&dxf, options).unwrap().trim_end());
+}
+
+fn test_toolpath_export(dxf_file: &str, export_file: &str, options: &DxfOptions) {
+    let dxf = read_to_string(DATA_PATH.to_owned() + dxf_file).unwrap();
+    let export = read_to_string(DATA_PATH.to_owned() + export_file).unwrap().replace('\r', "");
+
+    assert_eq!(export.trim_end(), export_toolpaths(&dxf, options).trim_end());
+}
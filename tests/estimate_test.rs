@@ -0,0 +1,66 @@
+use std::fs::read_to_string;
+use rust_cnc::{estimate, process_with_options, DxfOptions, EstimateOptions, PointAction, Pulse};
+
+fn options(acceleration: f64) -> EstimateOptions {
+    EstimateOptions { acceleration, ..EstimateOptions::default() }
+}
+
+fn assert_close(expected: f64, actual: f64) {
+    assert!((expected - actual).abs() < 1e-3, "expected {}, got {}", expected, actual);
+}
+
+#[test]
+fn estimate_straight_line_test() {
+    let estimate = estimate("G01\nM03 F600 X100 Y0 S255\nM05", &options(100.0)).unwrap();
+
+    // A tenth of a second to get up to 10mm/s and another to stop, cruising the rest of the way
+    assert_close(100.0, estimate.cut_length);
+    assert_close(0.0, estimate.travel_length);
+    assert_eq!(1, estimate.laser_events);
+    assert_close(10.1, estimate.duration);
+}
+
+#[test]
+fn estimate_short_move_test() {
+    let estimate = estimate("G01 F6000 X1", &options(100.0)).unwrap();
+
+    assert_close(1.0, estimate.travel_length);
+    assert_close(0.2, estimate.duration);
+}
+
+#[test]
+fn estimate_corner_test() {
+    let estimate = estimate("G01\nM03 F600 X10 Y0 S255\nX10 Y10\nX0 Y10\nX0 Y0\nM05", &options(100.0)).unwrap();
+
+    // Square corners are taken at the speed the junction deviation allows rather than from a stop
+    let corner: f64 = (100.0 * 0.01 * 0.5f64.sqrt() / (1.0 - 0.5f64.sqrt())).sqrt();
+    let end = 0.1 + (10.0 - corner) / 100.0 + (10.0 - 0.5 - (100.0 - corner * corner) / 200.0) / 10.0;
+    let middle = 2.0 * (10.0 - corner) / 100.0 + (10.0 - (100.0 - corner * corner) / 100.0) / 10.0;
+
+    assert_close(40.0, estimate.cut_length);
+    assert_eq!(1, estimate.laser_events);
+    assert_close(2.0 * end + 2.0 * middle, estimate.duration);
+}
+
+#[test]
+fn estimate_rapid_and_inch_test() {
+    let rapid = estimate("G00 X100", &options(1e9)).unwrap();
+    let inches = estimate("G20 G01 X1 F10", &options(1e9)).unwrap();
+
+    assert_close(1.0, rapid.duration);
+    assert_close(25.4, inches.travel_length);
+    assert_close(6.0, inches.duration);
+}
+
+#[test]
+fn estimate_pulse_test() {
+    let dxf = read_to_string("tests/data/dxf/points.dxf").unwrap();
+    let gcode = process_with_options(&dxf, &DxfOptions { outline: false, points: PointAction::Pulse(Pulse::new(0.1)), ..DxfOptions::default() });
+    let pulses = gcode.matches("G04").count();
+    let estimate = estimate(&gcode, &EstimateOptions::default()).unwrap();
+
+    assert!(pulses > 0);
+    assert_eq!(pulses, estimate.laser_events);
+    assert_close(0.0, estimate.cut_length);
+    assert!(estimate.duration > 0.1 * pulses as f64);
+}
@@ -0,0 +1,41 @@
+use std::fs::read_to_string;
+use rust_cnc::{process_excellon_with_options, Drill, ExcellonOptions, HoleAction};
+
+const DATA_PATH: &str = "tests/data/excellon/";
+
+#[test]
+fn excellon_drill_test() {
+    test_excellon("board.drl", "board.nc", &ExcellonOptions::default());
+}
+
+#[test]
+fn excellon_cutout_test() {
+    let options = ExcellonOptions {
+        holes: HoleAction::Cutout,
+        ..ExcellonOptions::default()
+    };
+
+    test_excellon("board.drl", "board_cutout.nc", &options);
+}
+
+#[test]
+fn excellon_peck_drill_test() {
+    let options = ExcellonOptions {
+        holes: HoleAction::Drill(Drill {peck: Some(0.5), canned: false, ..Drill::new(1.6)}),
+        tool_change: false
+    };
+
+    test_excellon("board.drl", "board_peck.nc", &options);
+}
+
+#[test]
+fn excellon_inch_leading_zero_test() {
+    test_excellon("legacy.drl", "legacy.nc", &ExcellonOptions::default());
+}
+
+fn test_excellon(excellon_file: &str, nc_file: &str, options: &ExcellonOptions) {
+    let excellon = read_to_string(DATA_PATH.to_owned() + excellon_file).unwrap();
+    let gcode = read_to_string(DATA_PATH.to_owned() + nc_file).unwrap().replace('\r', "");
+
+    assert_eq!(gcode.trim_end(), process_excellon_with_options(&excellon, options).trim_end());
+}
@@ -0,0 +1,77 @@
+use std::fs::read_to_string;
+use rust_cnc::{process_gcode, process_gcode_with_options, process_hpgl, GcodeError, GcodeOptions, Motion, Program};
+
+const DATA_PATH: &str = "tests/data/gcode/";
+
+#[test]
+fn gcode_normalise_test() {
+    test_gcode("external.gcode", "external.nc", &GcodeOptions::default());
+}
+
+#[test]
+fn gcode_scale_test() {
+    let options = GcodeOptions {
+        power_scale: 0.5,
+        feed_scale: 1.5,
+        ..GcodeOptions::default()
+    };
+
+    test_gcode("external.gcode", "external_scaled.nc", &options);
+}
+
+#[test]
+fn gcode_offset_test() {
+    let options = GcodeOptions {
+        offset_x: 20.0,
+        offset_y: -2.5,
+        ..GcodeOptions::default()
+    };
+
+    test_gcode("external.gcode", "external_offset.nc", &options);
+}
+
+#[test]
+fn gcode_round_trip_test() {
+    let gcode = process_hpgl("IN;SP1;PU0,0;PD400,0,400,400;PU;CI100;");
+
+    assert_eq!(gcode.trim_end(), process_gcode(&gcode).unwrap().trim_end());
+}
+
+#[test]
+fn gcode_block_delete_test() {
+    let program = Program::parse("G1 X1\n/G1 X2 ; optional\n/ M5").unwrap();
+
+    assert!(!program.blocks[0].block_delete);
+    assert!(program.blocks[1].block_delete);
+    assert_eq!("G01 X1\n/G01 X2 ; optional\n/M05\n", program.to_string());
+}
+
+#[test]
+fn gcode_moves_test() {
+    let program = Program::parse("G20 G90\nM3 S500\nG1 X1 Y0 F10\nG3 X-1 Y0 R1\nM5\nG0 X0 Y0").unwrap();
+    let moves = program.moves();
+
+    assert_eq!(3, moves.len());
+    assert_eq!((25.4, 0.0), (moves[0].to.x, moves[0].to.y));
+    assert_eq!(Some(254.0), moves[0].feed);
+    assert_eq!(500.0, moves[0].power);
+
+    assert_eq!(Motion::AnticlockwiseArc, moves[1].motion);
+    assert_eq!(Some((0.0, 0.0)), moves[1].centre);
+
+    assert_eq!(Motion::Rapid, moves[2].motion);
+    assert_eq!(0.0, moves[2].power);
+}
+
+#[test]
+fn gcode_parse_error_test() {
+    assert_eq!(Err(GcodeError::Parse(2, "expected a number after X".to_string())), Program::parse("G1 X1\nG1 X Y2"));
+    assert!(process_gcode("G1 X1 # Y2").is_err());
+}
+
+fn test_gcode(gcode_file: &str, nc_file: &str, options: &GcodeOptions) {
+    let input = read_to_string(DATA_PATH.to_owned() + gcode_file).unwrap();
+    let gcode = read_to_string(DATA_PATH.to_owned() + nc_file).unwrap().replace('\r', "");
+
+    assert_eq!(gcode.trim_end(), process_gcode_with_options(&input, options).unwrap().trim_end());
+}
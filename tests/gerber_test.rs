@@ -0,0 +1,60 @@
+use std::fs::read_to_string;
+use rust_cnc::{process_gerber, process_gerber_with_options, FillOptions, GerberError, GerberOptions};
+
+const DATA_PATH: &str = "tests/data/gerber/";
+
+#[test]
+fn gerber_outline_test() {
+    test_gerber("board.gbr", "board.nc", &GerberOptions::default());
+}
+
+#[test]
+fn gerber_isolation_offset_test() {
+    let options = GerberOptions {
+        offset: 0.1,
+        ..GerberOptions::default()
+    };
+
+    test_gerber("board.gbr", "board_isolation.nc", &options);
+}
+
+#[test]
+fn gerber_etch_test() {
+    let options = GerberOptions {
+        fill: Some(FillOptions::new(0.2)),
+        outline: false,
+        ..GerberOptions::default()
+    };
+
+    test_gerber("board.gbr", "board_etch.nc", &options);
+}
+
+#[test]
+fn gerber_inch_trailing_zero_test() {
+    let millimetres = "%FSLAX24Y24*%%MOMM*%%ADD10C,12.7*%D10*X0Y0D02*X254000Y127000D01*M02*";
+    let inches = "%FSTAX24Y24*%%MOIN*%%ADD10C,0.5*%D10*X0Y0D02*X01Y005D01*M02*";
+
+    assert_eq!(process_gerber(millimetres).unwrap(), process_gerber(inches).unwrap());
+}
+
+#[test]
+fn gerber_single_quadrant_arc_test() {
+    let multi_quadrant = "%FSLAX24Y24*%%MOMM*%%ADD10C,0.5*%D10*G75*X100000Y0D02*G03X0Y100000I-100000J0D01*M02*";
+    let single_quadrant = "%FSLAX24Y24*%%MOMM*%%ADD10C,0.5*%D10*G74*X100000Y0D02*G03X0Y100000I100000J0D01*M02*";
+
+    assert_eq!(process_gerber(multi_quadrant).unwrap(), process_gerber(single_quadrant).unwrap());
+}
+
+#[test]
+fn gerber_isolation_too_large_test() {
+    let gerber = "%FSLAX24Y24*%%MOMM*%%ADD10C,0.5*%D10*X0Y0D02*X10000000Y10000000D01*M02*";
+
+    assert!(matches!(process_gerber(gerber), Err(GerberError::TooLarge(_, _))));
+}
+
+fn test_gerber(gerber_file: &str, nc_file: &str, options: &GerberOptions) {
+    let gerber = read_to_string(DATA_PATH.to_owned() + gerber_file).unwrap();
+    let gcode = read_to_string(DATA_PATH.to_owned() + nc_file).unwrap().replace('\r', "");
+
+    assert_eq!(gcode.trim_end(), process_gerber_with_options(&gerber, options).unwrap().trim_end());
+}
@@ -0,0 +1,30 @@
+use std::fs::read_to_string;
+use rust_cnc::{process_hpgl, process_hpgl_with_options, HpglOptions, PenPass};
+
+const DATA_PATH: &str = "tests/data/hpgl/";
+
+#[test]
+fn hpgl_shape_test() {
+    test_hpgl("shapes.plt", "shapes.nc", &HpglOptions::default());
+}
+
+#[test]
+fn hpgl_pen_pass_test() {
+    let options = HpglOptions {
+        pen_passes: vec![PenPass {pen: 2, power: 120}, PenPass {pen: 1, power: 255}]
+    };
+
+    test_hpgl("shapes.plt", "shapes_pens.nc", &options);
+}
+
+#[test]
+fn hpgl_without_separators_test() {
+    assert_eq!(process_hpgl("IN;SP1;PU0,0;PD400,0,400,400;PU;"), process_hpgl("INSP1PU0 0PD400 0 400 400PU"));
+}
+
+fn test_hpgl(hpgl_file: &str, nc_file: &str, options: &HpglOptions) {
+    let hpgl = read_to_string(DATA_PATH.to_owned() + hpgl_file).unwrap();
+    let gcode = read_to_string(DATA_PATH.to_owned() + nc_file).unwrap().replace('\r', "");
+
+    assert_eq!(gcode.trim_end(), process_hpgl_with_options(&hpgl, options).trim_end());
+}
@@ -0,0 +1,60 @@
+use rust_cnc::{fit_to_bed, process_hpgl, validate, MachineProfile, Origin, Violation};
+
+const JOB: &str = "G01\nM05 F2000 X-20.00 Y5.00\nM03 F1000 X30.00 Y5.00 S300\nM03 F9000 X30.00 Y40.00 S255\nM05 F2000 X0 Y0";
+
+#[test]
+fn machine_validation_test() {
+    let violations = validate(JOB, &MachineProfile::default()).unwrap();
+
+    assert_eq!(vec![
+        Violation::NegativeCoordinate {line: 2, x: -20.0, y: 5.0},
+        Violation::Power {line: 3, power: 300.0},
+        Violation::Feed {line: 4, feed: 9000.0}
+    ], violations);
+}
+
+#[test]
+fn machine_validation_source_lines_test() {
+    let gcode = "%\n(job)\nG01\n\nM05 F2000 X-20.00 Y5.00\n\nM03 F1000 X30.00 Y5.00 S300\n%";
+    let violations = validate(gcode, &MachineProfile::default()).unwrap();
+
+    // Tape markers and blank lines are dropped by the parser but still counted
+    assert_eq!(vec![
+        Violation::NegativeCoordinate {line: 5, x: -20.0, y: 5.0},
+        Violation::Power {line: 7, power: 300.0}
+    ], violations);
+}
+
+#[test]
+fn machine_validation_passes_test() {
+    let gcode = process_hpgl("IN;SP1;PU400,400;PD4000,400,4000,4000;PU;");
+
+    assert!(validate(&gcode, &MachineProfile::default()).unwrap().is_empty());
+}
+
+#[test]
+fn machine_top_left_origin_test() {
+    let profile = MachineProfile { origin: Origin::TopLeft, bed_width: 100.0, bed_height: 100.0, ..MachineProfile::default() };
+    let violations = validate("G01\nM05 F2000 X10 Y-50\nM03 F1000 X10 Y20 S255\nM03 F1000 X150 Y-10 S255", &profile).unwrap();
+
+    assert_eq!(vec![
+        Violation::OutOfBounds {line: 3, x: 10.0, y: 20.0},
+        Violation::OutOfBounds {line: 4, x: 150.0, y: -10.0}
+    ], violations);
+}
+
+#[test]
+fn machine_fit_to_bed_test() {
+    let profile = MachineProfile { max_power: 1000.0, max_feed: 10000.0, ..MachineProfile::default() };
+    let fitted = fit_to_bed(JOB, &profile).unwrap().unwrap();
+
+    assert_eq!("G01\nM05 F2000 X0.00 Y5.00\nM03 F1000 X50.00 Y5.00 S300\nM03 F9000 X50.00 Y40.00 S255\nM05 F2000 X20.00 Y0.00", fitted.trim_end());
+    assert!(validate(&fitted, &profile).unwrap().is_empty());
+}
+
+#[test]
+fn machine_fit_too_big_test() {
+    let profile = MachineProfile { bed_width: 40.0, ..MachineProfile::default() };
+
+    assert_eq!(None, fit_to_bed(JOB, &profile).unwrap());
+}
@@ -0,0 +1,103 @@
+use std::fs::read_to_string;
+use png::Decoder;
+use rust_cnc::{preview_program_svg, preview_svg, process_bmp, process_hpgl, simulate_png, GcodeError, PreviewOptions, Program, SimulationOptions};
+
+const DATA_PATH: &str = "tests/data/preview/";
+
+#[test]
+fn preview_svg_test() {
+    let options = PreviewOptions {
+        bed_width: 60.0,
+        bed_height: 50.0,
+        ..PreviewOptions::default()
+    };
+
+    test_preview_svg("../gcode/external.gcode", "external.svg", &options);
+}
+
+#[test]
+fn preview_svg_without_travel_test() {
+    let gcode = process_hpgl("IN;SP1;PU0,0;PD400,0,400,400;PU;PA800,800;PD1200,800;PU;");
+    let options = PreviewOptions { travel: false, arrows: false, ..PreviewOptions::default() };
+    let svg = preview_svg(&gcode, &options).unwrap();
+
+    assert!(!svg.contains("stroke-dasharray"));
+    assert_eq!(2, svg.matches("<circle").count());
+    assert_eq!(2, svg.matches("<path").count());
+}
+
+#[test]
+fn preview_svg_arc_direction_test() {
+    let options = PreviewOptions { arrows: false, ..PreviewOptions::default() };
+    let clockwise = preview_svg("G01 X0 Y0\nM03 S255\nG02 X20 Y0 I10 J0", &options).unwrap();
+    let anticlockwise = preview_svg("G01 X0 Y0\nM03 S255\nG03 X20 Y0 I10 J0", &options).unwrap();
+
+    // A clockwise arc from the left of the centre passes over the top, which is up the page at negative Y
+    assert!(clockwise.contains("A 10 10 0 0 1 10 -10 "), "{}", clockwise);
+    assert!(anticlockwise.contains("A 10 10 0 0 0 10 10 "), "{}", anticlockwise);
+}
+
+#[test]
+fn preview_program_svg_test() {
+    let gcode = read_to_string("tests/data/gcode/external.gcode").unwrap();
+    let program = Program::parse(&gcode).unwrap();
+
+    assert_eq!(preview_svg(&gcode, &PreviewOptions::default()).unwrap(), preview_program_svg(&program, &PreviewOptions::default()));
+}
+
+#[test]
+fn simulate_raster_test() {
+    let gcode = process_bmp(b"P1\n4 1\n0110", 254).unwrap();
+    let (width, height, pixels) = decode(&simulate_png(&gcode, &SimulationOptions::new(254)).unwrap());
+
+    // Burns start and stop at pixel centres, so the pixels either side of the dark run are half burned
+    assert_eq!((3, 1), (width, height));
+    assert_pixels(&[128, 0, 128], &pixels);
+}
+
+#[test]
+fn simulate_power_test() {
+    let gcode = "G01\nM05 F2000 X0 Y0\nM03 F1000 X0.8 Y0 S255\nM05 F2000 X0 Y0.2\nM03 F1000 X0.8 Y0.2 S63.75\nM05 F2000 X0 Y0";
+    let (width, height, pixels) = decode(&simulate_png(gcode, &SimulationOptions::new(127)).unwrap());
+
+    assert_eq!((5, 2), (width, height));
+    assert_pixels(&[223, 191, 191, 191, 223], &pixels[..5]);
+    assert_pixels(&[128, 0, 0, 0, 128], &pixels[5..]);
+}
+
+#[test]
+fn simulate_too_large_test() {
+    let gcode = "G01\nM05 F2000 X0 Y0\nM03 F1000 X10000 Y10000 S255\nM05 F2000 X0 Y0";
+
+    assert_eq!(Err(GcodeError::ImageTooLarge(393702, 393702)), simulate_png(gcode, &SimulationOptions::new(1000)));
+}
+
+#[test]
+fn simulate_dwell_test() {
+    let gcode = "G01\nM05 F2000 X2 Y2\nM03 S255\nG04 P0.003\nM05\nM05 F2000 X0 Y0";
+    let (width, height, pixels) = decode(&simulate_png(gcode, &SimulationOptions::new(127)).unwrap());
+
+    assert_eq!((1, 1), (width, height));
+    assert_pixels(&[191], &pixels);
+}
+
+// Exposures that land exactly halfway between two shades may round either way
+fn assert_pixels(expected: &[u8], actual: &[u8]) {
+    assert_eq!(expected.len(), actual.len());
+    assert!(expected.iter().zip(actual).all(|(a, b)| a.abs_diff(*b) <= 1), "expected {:?}, got {:?}", expected, actual);
+}
+
+fn decode(image: &[u8]) -> (u32, u32, Vec<u8>) {
+    let mut reader = Decoder::new(std::io::Cursor::new(image)).read_info().unwrap();
+    let mut pixels = vec![0; reader.output_buffer_size().unwrap()];
+    let info = reader.next_frame(&mut pixels).unwrap();
+
+    (info.width, info.height, pixels[..info.buffer_size()].to_vec())
+}
+
+fn test_preview_svg(gcode_file: &str, svg_file: &str, options: &PreviewOptions) {
+    let gcode = read_to_string(DATA_PATH.to_owned() + gcode_file).unwrap();
+    let svg = read_to_string(DATA_PATH.to_owned() + svg_file).unwrap().replace('\r', "");
+
+    assert_eq!(svg.trim_end(), preview_svg(&gcode, options).unwrap().trim_end());
+}
@@ -0,0 +1,39 @@
+use std::fs::read_to_string;
+use rust_cnc::{process_svg, process_svg_with_options, Colour, ColourPass, SvgError, SvgOptions};
+
+const DATA_PATH: &str = "tests/data/svg/";
+
+#[test]
+fn svg_shape_test() {
+    test_svg("shapes.svg", "shapes.nc", &SvgOptions::default());
+}
+
+#[test]
+fn svg_path_test() {
+    test_svg("paths.svg", "paths.nc", &SvgOptions::default());
+}
+
+#[test]
+fn svg_colour_pass_test() {
+    let options = SvgOptions {
+        colour_passes: vec![
+            ColourPass {colour: Colour {r: 255, g: 0, b: 0}, tolerance: 10, power: 200},
+            ColourPass {colour: Colour {r: 0, g: 0, b: 255}, tolerance: 10, power: 100}
+        ]
+    };
+
+    test_svg("shapes.svg", "shapes_passes.nc", &options);
+}
+
+#[test]
+fn invalid_svg_test() {
+    assert!(matches!(process_svg("<svg><rect></svg>"), Err(SvgError::Parse(_))));
+    assert!(matches!(process_svg("<html/>"), Err(SvgError::Parse(_))));
+}
+
+fn test_svg(svg_file: &str, nc_file: &str, options: &SvgOptions) {
+    let svg = read_to_string(DATA_PATH.to_owned() + svg_file).unwrap();
+    let gcode = read_to_string(DATA_PATH.to_owned() + nc_file).unwrap().replace('\r', "");
+
+    assert_eq!(gcode.trim_end(), process_svg_with_options(&svg, options).unwrap().trim_end());
+}